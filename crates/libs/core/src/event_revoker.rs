@@ -0,0 +1,75 @@
+/// A guard that revokes an event handler's registration when dropped.
+///
+/// Returned by the `on_` event methods generated with the `EVENT_REVOKERS` configuration value so that handlers
+/// can't be leaked by forgetting to call the matching `Remove` method.
+///
+/// The revoker is not `Send` since it holds a reference to the event source, which need not be agile, so it must be
+/// dropped on the thread that registered the handler.
+#[must_use = "the event handler is revoked as soon as the revoker is dropped"]
+pub struct EventRevoker(Option<Box<dyn FnOnce()>>);
+
+impl EventRevoker {
+    /// Creates a revoker that calls `revoke` when dropped.
+    pub fn new<F: FnOnce() + 'static>(revoke: F) -> Self {
+        Self(Some(Box::new(revoke)))
+    }
+
+    /// Revokes the event handler's registration now rather than waiting for the revoker to be dropped.
+    pub fn revoke(mut self) {
+        if let Some(revoke) = self.0.take() {
+            revoke();
+        }
+    }
+
+    /// Keeps the event handler registered for the lifetime of the event source.
+    pub fn forget(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for EventRevoker {
+    fn drop(&mut self) {
+        if let Some(revoke) = self.0.take() {
+            revoke();
+        }
+    }
+}
+
+impl std::fmt::Debug for EventRevoker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EventRevoker").field(&self.0.is_some()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn revokes_once() {
+        let count = Rc::new(Cell::new(0));
+
+        let revoker = {
+            let count = count.clone();
+            EventRevoker::new(move || count.set(count.get() + 1))
+        };
+        drop(revoker);
+        assert_eq!(count.get(), 1);
+
+        let revoker = {
+            let count = count.clone();
+            EventRevoker::new(move || count.set(count.get() + 1))
+        };
+        revoker.revoke();
+        assert_eq!(count.get(), 2);
+
+        let revoker = {
+            let count = count.clone();
+            EventRevoker::new(move || count.set(count.get() + 1))
+        };
+        revoker.forget();
+        assert_eq!(count.get(), 2);
+    }
+}
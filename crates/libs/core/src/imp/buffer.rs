@@ -0,0 +1,108 @@
+/// Returns `true` if the error reports that a buffer was too small to hold the result.
+pub fn is_buffer_too_small(error: &crate::Error) -> bool {
+    matches!(
+        error.code().0 as u32,
+        0x8007_007A // ERROR_INSUFFICIENT_BUFFER
+        | 0x8007_00EA // ERROR_MORE_DATA
+        | 0xD000_0004 // STATUS_INFO_LENGTH_MISMATCH
+        | 0x9000_0005 // STATUS_BUFFER_OVERFLOW
+        | 0xD000_0023 // STATUS_BUFFER_TOO_SMALL
+    )
+}
+
+/// Reads a buffer from a function that reports the required length, in elements, when the buffer is too small.
+///
+/// The function is first called with a null buffer and zero capacity and then called again with a buffer of the
+/// reported length, for as long as the buffer remains too small. The closure receives the buffer, its capacity, and
+/// the length it must set to either the number of elements written or the number required.
+pub fn read_sized_buffer<T: Clone + Default, F: FnMut(*mut T, u32, &mut u32) -> crate::Result<()>>(mut f: F) -> crate::Result<Vec<T>> {
+    let mut buffer = Vec::new();
+
+    loop {
+        let data = if buffer.is_empty() { std::ptr::null_mut() } else { buffer.as_mut_ptr() };
+        let mut len = 0;
+
+        match f(data, buffer.len() as u32, &mut len) {
+            Ok(()) if len as usize <= buffer.len() => {
+                buffer.truncate(len as usize);
+                return Ok(buffer);
+            }
+            Ok(()) => {}
+            Err(error) if is_buffer_too_small(&error) => {}
+            Err(error) => return Err(error),
+        }
+
+        // Some functions don't report the required length so the buffer is grown regardless.
+        let len = (len as usize).max(buffer.len() * 2).max(1);
+        buffer.resize(len, T::default());
+    }
+}
+
+/// Reads a buffer from a function that copies as much as fits and returns the number of elements copied, or the
+/// number required, setting the last error on failure.
+///
+/// The buffer starts at `MAX_PATH` elements and is grown until the returned length is less than its capacity. The last
+/// error is cleared before each call since a successful call returning zero needn't set it.
+pub fn read_truncated_buffer<T: Clone + Default, F: FnMut(*mut T, u32) -> u32>(mut f: F) -> crate::Result<Vec<T>> {
+    let mut buffer = vec![T::default(); 260];
+
+    loop {
+        unsafe { crate::imp::SetLastError(0) };
+        let len = f(buffer.as_mut_ptr(), buffer.len() as u32);
+
+        if len == 0 {
+            let error = crate::Error::from_win32();
+
+            if error.code().is_err() && !is_buffer_too_small(&error) {
+                return Err(error);
+            }
+        }
+
+        if (len as usize) < buffer.len() {
+            buffer.truncate(len as usize);
+            return Ok(buffer);
+        }
+
+        let len = (len as usize).max(buffer.len() * 2);
+        buffer.resize(len, T::default());
+    }
+}
+
+/// Converts a wide string buffer, which may include a null terminator, to a `String`.
+pub fn wide_to_string(wide: &[u16]) -> String {
+    let len = wide.iter().position(|c| *c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sized_buffer() {
+        let source = [1u8, 2, 3, 4, 5];
+        let mut calls = 0;
+
+        let result = read_sized_buffer(|data: *mut u8, capacity, len| {
+            calls += 1;
+            *len = source.len() as u32;
+
+            if (capacity as usize) < source.len() {
+                Err(crate::Error { code: crate::HRESULT(0x8007_00EA_u32 as i32), info: None })
+            } else {
+                unsafe { std::ptr::copy_nonoverlapping(source.as_ptr(), data, source.len()) };
+                Ok(())
+            }
+        });
+
+        assert_eq!(result.ok(), Some(source.to_vec()));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn wide_string() {
+        let wide: Vec<u16> = "hello\0world".encode_utf16().collect();
+        assert_eq!(wide_to_string(&wide), "hello");
+        assert_eq!(wide_to_string(&wide[..5]), "hello");
+    }
+}
@@ -1,4 +1,5 @@
 mod bindings;
+mod buffer;
 mod com_bindings;
 mod delay_load;
 mod factory_cache;
@@ -10,6 +11,7 @@ mod waiter;
 mod weak_ref_count;
 
 pub use bindings::*;
+pub use buffer::*;
 pub use com_bindings::*;
 pub use delay_load::*;
 pub use factory_cache::*;
@@ -17,6 +17,7 @@ mod as_impl;
 mod com_interface;
 mod error;
 mod event;
+mod event_revoker;
 mod guid;
 mod hresult;
 mod inspectable;
@@ -36,6 +37,7 @@ pub use as_impl::*;
 pub use com_interface::*;
 pub use error::*;
 pub use event::*;
+pub use event_revoker::*;
 pub use guid::*;
 pub use hresult::*;
 pub use inspectable::*;
@@ -40,6 +40,12 @@ macro_rules! flags {
     };
 }
 
+flags!(EventAttributes, u16);
+impl EventAttributes {
+    pub const SpecialName: Self = Self(0x200);
+    pub const RTSpecialName: Self = Self(0x400);
+}
+
 flags!(FieldAttributes, u16);
 impl FieldAttributes {
     pub const Private: Self = Self(0x1);
@@ -56,14 +62,19 @@ impl MethodAttributes {
     pub const Abstract: Self = Self(0x400);
     pub const HideBySig: Self = Self(0x80);
     pub const NewSlot: Self = Self(0x100);
+    pub const PinvokeImpl: Self = Self(0x2000);
+    pub const Private: Self = Self(0x1);
     pub const Public: Self = Self(0x6);
+    pub const RTSpecialName: Self = Self(0x1000);
     pub const SpecialName: Self = Self(0x800);
+    pub const Static: Self = Self(0x10);
     pub const Virtual: Self = Self(0x40);
 }
 
 flags!(MethodImplAttributes, usize);
 impl MethodImplAttributes {
     pub const PreserveSig: Self = Self(0x80);
+    pub const Runtime: Self = Self(0x3);
 }
 
 flags!(MethodCallAttributes, u8);
@@ -72,6 +83,16 @@ impl MethodCallAttributes {
     pub const VARARG: Self = Self(0x05);
 }
 
+flags!(MethodSemanticsAttributes, u16);
+impl MethodSemanticsAttributes {
+    pub const Setter: Self = Self(0x1);
+    pub const Getter: Self = Self(0x2);
+    pub const Other: Self = Self(0x4);
+    pub const AddOn: Self = Self(0x8);
+    pub const RemoveOn: Self = Self(0x10);
+    pub const Fire: Self = Self(0x20);
+}
+
 flags!(ParamAttributes, u16);
 impl ParamAttributes {
     pub const In: Self = Self(0x1);
@@ -81,6 +102,7 @@ impl ParamAttributes {
 
 flags!(PInvokeAttributes, usize);
 impl PInvokeAttributes {
+    pub const NoMangle: Self = Self(0x1);
     pub const SupportsLastError: Self = Self(0x40);
     pub const CallConvPlatformapi: Self = Self(0x100);
     pub const CallConvCdecl: Self = Self(0x200);
@@ -89,6 +111,13 @@ impl PInvokeAttributes {
     pub const CallConvFastcall: Self = Self(0x500);
 }
 
+flags!(PropertyAttributes, u16);
+impl PropertyAttributes {
+    pub const SpecialName: Self = Self(0x200);
+    pub const RTSpecialName: Self = Self(0x400);
+    pub const HasDefault: Self = Self(0x1000);
+}
+
 flags!(TypeAttributes, u32);
 impl TypeAttributes {
     pub const Public: Self = Self(0x1);
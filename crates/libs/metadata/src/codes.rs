@@ -26,6 +26,8 @@ pub enum HasAttribute {
     Param(Param),
     InterfaceImpl(InterfaceImpl),
     MemberRef(MemberRef),
+    Property(Property),
+    Event(Event),
     TypeSpec(TypeSpec),
     GenericParam(GenericParam),
 }
@@ -40,6 +42,8 @@ impl HasAttribute {
             Self::Param(row) => ((row.0.row + 1) << 5) | 4,
             Self::InterfaceImpl(row) => ((row.0.row + 1) << 5) | 5,
             Self::MemberRef(row) => ((row.0.row + 1) << 5) | 6,
+            Self::Property(row) => ((row.0.row + 1) << 5) | 9,
+            Self::Event(row) => ((row.0.row + 1) << 5) | 10,
             Self::TypeSpec(row) => ((row.0.row + 1) << 5) | 13,
             Self::GenericParam(row) => ((row.0.row + 1) << 5) | 19,
         }) as _
@@ -59,6 +63,21 @@ impl HasConstant {
     }
 }
 
+#[derive(Clone)]
+pub enum HasSemantics {
+    Event(Event),
+    Property(Property),
+}
+
+impl HasSemantics {
+    pub fn encode(&self) -> usize {
+        (match self {
+            Self::Event(row) => (row.0.row + 1) << 1,
+            Self::Property(row) => ((row.0.row + 1) << 1) | 1,
+        }) as _
+    }
+}
+
 #[derive(Clone)]
 pub enum MemberForwarded {
     MethodDef(MethodDef),
@@ -0,0 +1,515 @@
+use super::*;
+
+/// The structural differences between two sets of metadata, as returned by `diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+/// A single difference between two sets of metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Whether the change may break code written against the old metadata.
+    pub breaking: bool,
+    /// The full name of the changed item, such as `Windows.Foundation.IStringable.ToString`.
+    pub path: String,
+    /// A description of the change, which is empty for additions and removals.
+    pub message: String,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    pub fn non_breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| !change.breaking)
+    }
+
+    /// Writes the changes as a JSON object with a `changes` array of `{ "kind", "breaking", "path", "message" }` objects.
+    pub fn to_json(&self) -> String {
+        let changes = self.changes.iter().map(|change| Json::Object(vec![("kind", Json::String(change.kind.to_string())), ("breaking", Json::Bool(change.breaking)), ("path", Json::string(&change.path)), ("message", Json::string(&change.message))])).collect();
+        Json::Object(vec![("changes", Json::Array(changes))]).to_string()
+    }
+}
+
+impl std::fmt::Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        for (heading, breaking) in [("Breaking changes", true), ("Non-breaking changes", false)] {
+            let mut changes = self.changes.iter().filter(|change| change.breaking == breaking).peekable();
+
+            if changes.peek().is_some() {
+                writeln!(f, "{heading}:")?;
+
+                for change in changes {
+                    writeln!(f, "  {change}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} `{}`", self.kind, self.path)?;
+
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Changed => "changed",
+        })
+    }
+}
+
+/// Compares the types in `old` with those in `new`, reporting added, removed and changed types and members. Changes that
+/// may break code written against `old`, such as removed members or changed signatures, are marked as breaking.
+pub fn diff(old: &Reader, new: &Reader) -> Diff {
+    let mut diff = Differ { old, new, changes: vec![] };
+    let old_types = old.types_index().map(|def| (old.type_def_type_name(def).to_string(), def));
+    let new_types = new.types_index().map(|def| (new.type_def_type_name(def).to_string(), def));
+
+    for (name, old_def, new_def) in pair(old_types, new_types) {
+        diff.type_def(&name, old_def, new_def);
+    }
+
+    Diff { changes: diff.changes }
+}
+
+struct Differ<'a> {
+    old: &'a Reader<'a>,
+    new: &'a Reader<'a>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn push(&mut self, kind: ChangeKind, breaking: bool, path: &str, message: String) {
+        self.changes.push(Change { kind, breaking, path: path.to_string(), message });
+    }
+
+    // Reports an addition or removal, returning the pair if the item is present in both.
+    fn added_or_removed<T>(&mut self, path: &str, old: Option<T>, new: Option<T>, breaking_if_added: bool) -> Option<(T, T)> {
+        match (old, new) {
+            (Some(old), Some(new)) => Some((old, new)),
+            (None, Some(_)) => {
+                self.push(ChangeKind::Added, breaking_if_added, path, String::new());
+                None
+            }
+            _ => {
+                self.push(ChangeKind::Removed, true, path, String::new());
+                None
+            }
+        }
+    }
+
+    fn changed(&mut self, breaking: bool, path: &str, what: &str, old: String, new: String) {
+        if old != new {
+            self.push(ChangeKind::Changed, breaking, path, format!("the {what} changed from `{old}` to `{new}`"));
+        }
+    }
+
+    fn type_def(&mut self, path: &str, old: Option<TypeDef>, new: Option<TypeDef>) {
+        let Some((old, new)) = self.added_or_removed(path, old, new, false) else {
+            return;
+        };
+
+        let (old_kind, new_kind) = (self.old.type_def_kind(old), self.new.type_def_kind(new));
+
+        if old_kind != new_kind {
+            self.changed(true, path, "kind", format!("{old_kind:?}"), format!("{new_kind:?}"));
+            return;
+        }
+
+        self.changed(true, path, "GUID", self.old.type_def_guid(old).map_or_else(String::new, |guid| format!("{guid:?}")), self.new.type_def_guid(new).map_or_else(String::new, |guid| format!("{guid:?}")));
+        self.changed(true, path, "base type", self.old.type_def_extends(old).map_or_else(String::new, |name| name.to_string()), self.new.type_def_extends(new).map_or_else(String::new, |name| name.to_string()));
+        self.attributes(path, attribute_strings(self.old, self.old.type_def_attributes(old)), attribute_strings(self.new, self.new.type_def_attributes(new)));
+
+        let old_generics: Vec<Type> = self.old.type_def_generics(old).collect();
+        let new_generics: Vec<Type> = self.new.type_def_generics(new).collect();
+
+        // Adding a field changes the layout of a struct and adding a method changes the vtable of an interface.
+        let fields_are_breaking = old_kind == TypeKind::Struct;
+        let methods_are_breaking = matches!(old_kind, TypeKind::Interface | TypeKind::Delegate);
+
+        if fields_are_breaking {
+            let old_fields: Vec<&str> = self.old.type_def_fields(old).map(|field| self.old.field_name(field)).filter(|name| self.new.type_def_fields(new).any(|field| self.new.field_name(field) == *name)).collect();
+            let new_fields: Vec<&str> = self.new.type_def_fields(new).map(|field| self.new.field_name(field)).filter(|name| old_fields.contains(name)).collect();
+
+            if old_fields != new_fields {
+                self.push(ChangeKind::Changed, true, path, "the fields were reordered".to_string());
+            }
+        }
+
+        for (name, old_field, new_field) in pair(self.old.type_def_fields(old).map(|field| (self.old.field_name(field).to_string(), field)), self.new.type_def_fields(new).map(|field| (self.new.field_name(field).to_string(), field))) {
+            self.field(&format!("{path}.{name}"), old, old_field, new, new_field, fields_are_breaking);
+        }
+
+        for (name, old_method, new_method) in pair(self.old.type_def_methods(old).map(|method| (self.old.method_def_name(method).to_string(), method)), self.new.type_def_methods(new).map(|method| (self.new.method_def_name(method).to_string(), method))) {
+            self.method(&format!("{path}.{name}"), old_method, &old_generics, new_method, &new_generics, methods_are_breaking);
+        }
+
+        for (name, old_property, new_property) in pair(self.old.type_def_properties(old).map(|property| (self.old.property_name(property).to_string(), property)), self.new.type_def_properties(new).map(|property| (self.new.property_name(property).to_string(), property))) {
+            let path = format!("{path}.{name}");
+            if let Some((old_property, new_property)) = self.added_or_removed(&path, old_property, new_property, methods_are_breaking) {
+                self.changed(true, &path, "type", type_string(self.old, &self.old.property_type(old_property, &old_generics)), type_string(self.new, &self.new.property_type(new_property, &new_generics)));
+                self.attributes(&path, attribute_strings(self.old, self.old.property_attributes(old_property)), attribute_strings(self.new, self.new.property_attributes(new_property)));
+            }
+        }
+
+        for (name, old_event, new_event) in pair(self.old.type_def_events(old).map(|event| (self.old.event_name(event).to_string(), event)), self.new.type_def_events(new).map(|event| (self.new.event_name(event).to_string(), event))) {
+            let path = format!("{path}.{name}");
+            if let Some((old_event, new_event)) = self.added_or_removed(&path, old_event, new_event, methods_are_breaking) {
+                self.changed(true, &path, "type", type_string(self.old, &self.old.event_type(old_event, &old_generics)), type_string(self.new, &self.new.event_type(new_event, &new_generics)));
+                self.attributes(&path, attribute_strings(self.old, self.old.event_attributes(old_event)), attribute_strings(self.new, self.new.event_attributes(new_event)));
+            }
+        }
+
+        let old_interfaces: BTreeSet<String> = self.old.type_def_interface_impls(old).map(|row| type_string(self.old, &self.old.interface_impl_type(row, &old_generics).ty)).collect();
+        let new_interfaces: BTreeSet<String> = self.new.type_def_interface_impls(new).map(|row| type_string(self.new, &self.new.interface_impl_type(row, &new_generics).ty)).collect();
+
+        for interface in old_interfaces.difference(&new_interfaces) {
+            self.push(ChangeKind::Changed, true, path, format!("the interface `{interface}` is no longer implemented"));
+        }
+
+        for interface in new_interfaces.difference(&old_interfaces) {
+            self.push(ChangeKind::Changed, false, path, format!("the interface `{interface}` is now implemented"));
+        }
+
+        for (name, old_nested, new_nested) in pair(self.old.nested_types(old).map(|def| (self.old.type_def_name(def).to_string(), def)), self.new.nested_types(new).map(|def| (self.new.type_def_name(def).to_string(), def))) {
+            self.type_def(&format!("{path}.{name}"), old_nested, new_nested);
+        }
+    }
+
+    fn field(&mut self, path: &str, old_enclosing: TypeDef, old: Option<Field>, new_enclosing: TypeDef, new: Option<Field>, breaking_if_added: bool) {
+        let Some((old, new)) = self.added_or_removed(path, old, new, breaking_if_added) else {
+            return;
+        };
+
+        self.changed(true, path, "type", type_string(self.old, &self.old.field_type(old, Some(old_enclosing))), type_string(self.new, &self.new.field_type(new, Some(new_enclosing))));
+        self.changed(true, path, "value", self.old.field_constant(old).map_or_else(String::new, |constant| value_string(self.old, &self.old.constant_value(constant))), self.new.field_constant(new).map_or_else(String::new, |constant| value_string(self.new, &self.new.constant_value(constant))));
+        self.attributes(path, attribute_strings(self.old, self.old.field_attributes(old)), attribute_strings(self.new, self.new.field_attributes(new)));
+    }
+
+    fn method(&mut self, path: &str, old: Option<MethodDef>, old_generics: &[Type], new: Option<MethodDef>, new_generics: &[Type], breaking_if_added: bool) {
+        let Some((old, new)) = self.added_or_removed(path, old, new, breaking_if_added) else {
+            return;
+        };
+
+        let old_signature = self.old.method_def_signature(old, old_generics);
+        let new_signature = self.new.method_def_signature(new, new_generics);
+        let old_string = signature_string(self.old, &old_signature);
+        let new_string = signature_string(self.new, &new_signature);
+
+        if old_string != new_string {
+            self.changed(true, path, "signature", old_string, new_string);
+        } else {
+            for (old_param, new_param) in old_signature.params.iter().zip(new_signature.params.iter()) {
+                let (old_name, new_name) = (self.old.param_name(old_param.def), self.new.param_name(new_param.def));
+
+                if old_name != new_name {
+                    self.push(ChangeKind::Changed, false, path, format!("the parameter `{old_name}` was renamed to `{new_name}`"));
+                }
+
+                let param_path = format!("{path}.{new_name}");
+                self.changed(true, &param_path, "flags", format!("{:?}", self.old.param_flags(old_param.def)), format!("{:?}", self.new.param_flags(new_param.def)));
+                self.attributes(&param_path, attribute_strings(self.old, self.old.param_attributes(old_param.def)), attribute_strings(self.new, self.new.param_attributes(new_param.def)));
+            }
+        }
+
+        self.attributes(path, attribute_strings(self.old, self.old.method_def_attributes(old)), attribute_strings(self.new, self.new.method_def_attributes(new)));
+    }
+
+    fn attributes(&mut self, path: &str, old: BTreeSet<String>, new: BTreeSet<String>) {
+        for attribute in old.difference(&new) {
+            self.push(ChangeKind::Changed, false, path, format!("the attribute `{attribute}` was removed"));
+        }
+
+        for attribute in new.difference(&old) {
+            self.push(ChangeKind::Changed, false, path, format!("the attribute `{attribute}` was added"));
+        }
+    }
+}
+
+// Pairs up items from the old and new metadata by name. Items that share a name, such as overloaded methods or
+// architecture-specific types, are paired in their original order.
+fn pair<T>(old: impl Iterator<Item = (String, T)>, new: impl Iterator<Item = (String, T)>) -> Vec<(String, Option<T>, Option<T>)> {
+    let mut items = BTreeMap::<String, (Vec<T>, Vec<T>)>::new();
+    old.for_each(|(name, item)| items.entry(name).or_default().0.push(item));
+    new.for_each(|(name, item)| items.entry(name).or_default().1.push(item));
+    let mut pairs = vec![];
+
+    for (name, (old, new)) in items {
+        let mut old = old.into_iter();
+        let mut new = new.into_iter();
+
+        loop {
+            match (old.next(), new.next()) {
+                (None, None) => break,
+                (old, new) => pairs.push((name.clone(), old, new)),
+            }
+        }
+    }
+
+    pairs
+}
+
+// The GUID is compared separately so that a changed GUID is reported as breaking.
+fn attribute_strings(reader: &Reader, attributes: impl Iterator<Item = Attribute>) -> BTreeSet<String> {
+    attributes
+        .filter(|attribute| reader.attribute_name(*attribute) != "GuidAttribute")
+        .map(|attribute| {
+            let args: Vec<String> = reader.attribute_args(attribute).iter().map(|(name, value)| if name.is_empty() { value_string(reader, value) } else { format!("{name} = {}", value_string(reader, value)) }).collect();
+            format!("{}({})", reader.attribute_name(attribute), args.join(", "))
+        })
+        .collect()
+}
+
+fn signature_string(reader: &Reader, signature: &Signature) -> String {
+    let params: Vec<String> = signature.params.iter().map(|param| type_string(reader, &param.ty)).collect();
+    format!("({}) -> {}", params.join(", "), type_string(reader, &signature.return_type))
+}
+
+// Types are compared by name since the rows of the old and new metadata are unrelated.
+fn type_string(reader: &Reader, ty: &Type) -> String {
+    match ty {
+        Type::TypeDef(def, generics) if generics.is_empty() => reader.type_def_type_name(*def).to_string(),
+        Type::TypeDef(def, generics) => {
+            let generics: Vec<String> = generics.iter().map(|ty| type_string(reader, ty)).collect();
+            format!("{}.{}<{}>", reader.type_def_namespace(*def), trim_tick(reader.type_def_name(*def)), generics.join(", "))
+        }
+        Type::TypeRef(code) => reader.type_def_or_ref(*code).to_string(),
+        Type::GenericParam(param) => reader.generic_param_name(*param).to_string(),
+        Type::MutPtr(ty, pointers) => format!("{}{}", "*mut ".repeat(*pointers), type_string(reader, ty)),
+        Type::ConstPtr(ty, pointers) => format!("{}{}", "*const ".repeat(*pointers), type_string(reader, ty)),
+        Type::Win32Array(ty, len) => format!("[{}; {len}]", type_string(reader, ty)),
+        Type::WinrtArray(ty) => format!("[{}]", type_string(reader, ty)),
+        Type::WinrtArrayRef(ty) => format!("&mut [{}]", type_string(reader, ty)),
+        Type::ConstRef(ty) => format!("&{}", type_string(reader, ty)),
+        rest => format!("{rest:?}"),
+    }
+}
+
+fn value_string(reader: &Reader, value: &Value) -> String {
+    match value {
+        Value::TypeDef(def) => reader.type_def_type_name(*def).to_string(),
+        Value::TypeRef(code) => reader.type_def_or_ref(*code).to_string(),
+        Value::EnumDef(def, value) => format!("{}({})", reader.type_def_type_name(*def), value_string(reader, value)),
+        Value::EnumRef(code, value) => format!("{}({})", reader.type_def_or_ref(*code), value_string(reader, value)),
+        rest => format!("{rest:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read() -> File {
+        File::new(std::fs::read("default/Windows.Wdk.winmd").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let old = [read()];
+        let new = [read()];
+        let diff = diff(&Reader::new(&old), &Reader::new(&new));
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "No changes\n");
+        assert_eq!(diff.to_json(), "{\n  \"changes\": []\n}\n");
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let files = [read()];
+        let added = diff(&Reader::new(&[]), &Reader::new(&files));
+        let removed = diff(&Reader::new(&files), &Reader::new(&[]));
+
+        assert!(!added.is_breaking());
+        assert!(added.changes.iter().all(|change| change.kind == ChangeKind::Added));
+        assert!(added.changes.contains(&Change { kind: ChangeKind::Added, breaking: false, path: "Windows.Wdk.Foundation.DEVICE_OBJECT".to_string(), message: String::new() }));
+
+        assert!(removed.is_breaking());
+        assert_eq!(removed.changes.len(), added.changes.len());
+        assert!(removed.changes.iter().all(|change| change.kind == ChangeKind::Removed && change.breaking));
+        assert!(removed.to_string().starts_with("Breaking changes:\n  removed `"));
+        assert!(removed.to_json().contains("\"kind\": \"removed\",\n      \"breaking\": true,\n      \"path\": \"Windows.Wdk.Foundation.DEVICE_OBJECT\",\n"));
+    }
+
+    // A small model with an interface, a struct, an enum and a function so that tests can diff it against an edited copy.
+    fn model() -> model::Model {
+        let type_ref = |namespace: &str, name: &str| writer::TypeName { namespace: namespace.to_string(), name: name.to_string(), generics: vec![] };
+        let mut model = model::Model::default();
+        let namespace = model.namespace_mut("Test");
+
+        let mut def = model::TypeDef::new("IWidget", TypeAttributes::Public | TypeAttributes::Interface | TypeAttributes::Abstract | TypeAttributes::WindowsRuntime);
+        def.attributes.push(model::Attribute::new("Windows.Foundation.Metadata", "GuidAttribute", guid_args(1)));
+        def.methods.push(interface_method("Draw"));
+        namespace.types.push(def);
+
+        let mut def = model::TypeDef::new("POINT", TypeAttributes::Public | TypeAttributes::SequentialLayout);
+        def.extends = Some(type_ref("System", "ValueType"));
+        def.fields.push(model::Field::new("x", FieldAttributes::Public, writer::Type::I32));
+        def.fields.push(model::Field::new("y", FieldAttributes::Public, writer::Type::I32));
+        namespace.types.push(def);
+
+        let mut def = model::TypeDef::new("Colors", TypeAttributes::Public | TypeAttributes::Sealed);
+        def.extends = Some(type_ref("System", "Enum"));
+        def.fields.push(model::Field::new("value__", FieldAttributes::Private | FieldAttributes::SpecialName | FieldAttributes::RTSpecialName, writer::Type::I32));
+
+        for (name, value) in [("Red", 1), ("Green", 2)] {
+            let mut field = model::Field::new(name, FieldAttributes::Public | FieldAttributes::Static | FieldAttributes::Literal | FieldAttributes::HasDefault, writer::Type::TypeRef(type_ref("Test", "Colors")));
+            field.value = Some(writer::Value::I32(value));
+            def.fields.push(field);
+        }
+
+        namespace.types.push(def);
+
+        let mut def = model::TypeDef::new("Apis", TypeAttributes::Public | TypeAttributes::Abstract | TypeAttributes::Sealed);
+        def.extends = Some(type_ref("System", "Object"));
+        let mut method = model::Method::new("GetValue", MethodAttributes::Public | MethodAttributes::Static | MethodAttributes::PinvokeImpl, writer::Type::I32);
+        method.params.push(model::Param::new("index", ParamAttributes::In, writer::Type::U32));
+        method.import = Some(model::Import { module: "test.dll".to_string(), name: "GetValue".to_string(), flags: PInvokeAttributes::default() });
+        def.methods.push(method);
+        namespace.types.push(def);
+
+        model
+    }
+
+    fn guid_args(data1: u32) -> Vec<writer::Value> {
+        let mut args = vec![writer::Value::U32(data1), writer::Value::U16(2), writer::Value::U16(3)];
+        args.extend((4..12).map(writer::Value::U8));
+        args
+    }
+
+    fn interface_method(name: &str) -> model::Method {
+        let mut method = model::Method::new(name, MethodAttributes::Public | MethodAttributes::Abstract | MethodAttributes::Virtual | MethodAttributes::NewSlot | MethodAttributes::HideBySig, writer::Type::HRESULT);
+        method.call_flags = MethodCallAttributes::HASTHIS;
+        method
+    }
+
+    fn diff_models(old: &model::Model, new: &model::Model) -> Diff {
+        let old = [File::new(old.write("Test.winmd")).unwrap()];
+        let new = [File::new(new.write("Test.winmd")).unwrap()];
+        diff(&Reader::new(&old), &Reader::new(&new))
+    }
+
+    fn change(kind: ChangeKind, breaking: bool, path: &str, message: &str) -> Change {
+        Change { kind, breaking, path: path.to_string(), message: message.to_string() }
+    }
+
+    #[test]
+    fn test_changed_signature() {
+        let old = model();
+        let mut new = old.clone();
+        new.type_def_mut("Test", "Apis").unwrap().methods[0].params[0].ty = writer::Type::U64;
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Changed, true, "Test.Apis.GetValue", "the signature changed from `(U32) -> I32` to `(U64) -> I32`")]);
+
+        // Renaming a parameter doesn't change the signature.
+        let mut new = old.clone();
+        new.type_def_mut("Test", "Apis").unwrap().methods[0].params[0].name = "position".to_string();
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Changed, false, "Test.Apis.GetValue", "the parameter `index` was renamed to `position`")]);
+    }
+
+    #[test]
+    fn test_changed_guid() {
+        let old = model();
+        let mut new = old.clone();
+        new.type_def_mut("Test", "IWidget").unwrap().attributes[0].args = guid_args(9);
+
+        let diff = diff_models(&old, &new);
+        assert!(diff.is_breaking());
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].path, "Test.IWidget");
+        assert!(diff.changes[0].message.starts_with("the GUID changed from `00000001-"));
+    }
+
+    #[test]
+    fn test_changed_enum_value() {
+        let old = model();
+        let mut new = old.clone();
+        new.type_def_mut("Test", "Colors").unwrap().fields[1].value = Some(writer::Value::I32(4));
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Changed, true, "Test.Colors.Red", "the value changed from `I32(1)` to `I32(4)`")]);
+
+        // Adding a value to an enum is not breaking, unlike adding a field to a struct.
+        let mut new = old.clone();
+        let mut field = new.type_def_mut("Test", "Colors").unwrap().fields[2].clone();
+        field.name = "Blue".to_string();
+        field.value = Some(writer::Value::I32(3));
+        new.type_def_mut("Test", "Colors").unwrap().fields.push(field);
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Added, false, "Test.Colors.Blue", "")]);
+    }
+
+    #[test]
+    fn test_reordered_fields() {
+        let old = model();
+        let mut new = old.clone();
+        new.type_def_mut("Test", "POINT").unwrap().fields.reverse();
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Changed, true, "Test.POINT", "the fields were reordered")]);
+
+        let mut new = old.clone();
+        new.type_def_mut("Test", "POINT").unwrap().fields.push(model::Field::new("z", FieldAttributes::Public, writer::Type::I32));
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Added, true, "Test.POINT.z", "")]);
+    }
+
+    #[test]
+    fn test_added_interface_method() {
+        let old = model();
+        let mut new = old.clone();
+        new.type_def_mut("Test", "IWidget").unwrap().methods.push(interface_method("Resize"));
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Added, true, "Test.IWidget.Resize", "")]);
+
+        // Adding a function, unlike adding a method to an interface, doesn't break existing callers.
+        let mut new = old.clone();
+        let mut method = new.type_def_mut("Test", "Apis").unwrap().methods[0].clone();
+        method.name = "SetValue".to_string();
+        new.type_def_mut("Test", "Apis").unwrap().methods.push(method);
+
+        let diff = diff_models(&old, &new);
+        assert_eq!(diff.changes, [change(ChangeKind::Added, false, "Test.Apis.SetValue", "")]);
+    }
+}
@@ -3,12 +3,36 @@ use std::cmp::*;
 
 #[derive(Default)]
 pub struct File {
-    bytes: Vec<u8>,
+    bytes: Bytes,
     strings: usize,
     blobs: usize,
     pub(crate) tables: [Table; TABLE_LEN],
 }
 
+// The file contents are either owned or provided by some other source, such as a memory-mapped file, that avoids
+// reading the whole file into memory up front.
+enum Bytes {
+    Owned(Vec<u8>),
+    Mapped(Box<dyn AsRef<[u8]> + Send + Sync>),
+}
+
+impl Default for Bytes {
+    fn default() -> Self {
+        Self::Owned(Vec::new())
+    }
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Mapped(bytes) => (**bytes).as_ref(),
+        }
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct Table {
     offset: usize,
@@ -40,22 +64,41 @@ pub(crate) const TABLE_NESTEDCLASS: usize = 13;
 pub(crate) const TABLE_MODULE: usize = 14;
 pub(crate) const TABLE_ASSEMBLYREF: usize = 15;
 pub(crate) const TABLE_CLASSLAYOUT: usize = 16;
-pub(crate) const TABLE_LEN: usize = 17;
+pub(crate) const TABLE_PROPERTY: usize = 17;
+pub(crate) const TABLE_PROPERTYMAP: usize = 18;
+pub(crate) const TABLE_EVENT: usize = 19;
+pub(crate) const TABLE_EVENTMAP: usize = 20;
+pub(crate) const TABLE_METHODSEMANTICS: usize = 21;
+pub(crate) const TABLE_FIELDRVA: usize = 22;
+pub(crate) const TABLE_ASSEMBLY: usize = 23;
+pub(crate) const TABLE_LEN: usize = 24;
 
-type Result<T> = std::result::Result<T, ()>;
+type Result<T> = std::result::Result<T, ParseError>;
 
 impl File {
-    pub fn new(bytes: Vec<u8>) -> Option<Self> {
-        Self::ok(bytes).ok()
+    /// Parses the bytes of a .winmd file, returning a `ParseError` describing why the file was rejected if the bytes
+    /// don't contain valid metadata.
+    pub fn new(bytes: Vec<u8>) -> Result<Self> {
+        Self::parse(Bytes::Owned(bytes))
+    }
+
+    /// Like `new` but borrows the bytes from any source, such as a memory-mapped file, rather than requiring the whole
+    /// file to be read into a `Vec<u8>` first.
+    pub fn from_source(source: impl AsRef<[u8]> + Send + Sync + 'static) -> Result<Self> {
+        Self::parse(Bytes::Mapped(Box::new(source)))
     }
 
-    fn ok(bytes: Vec<u8>) -> Result<Self> {
+    fn parse(bytes: Bytes) -> Result<Self> {
         let mut result = File { bytes, ..Default::default() };
 
         let dos = result.bytes.view_as::<IMAGE_DOS_HEADER>(0)?;
 
-        if dos.e_magic != IMAGE_DOS_SIGNATURE as _ || result.bytes.copy_as::<u32>(dos.e_lfanew as _)? != IMAGE_NT_SIGNATURE {
-            return Err(());
+        if dos.e_magic != IMAGE_DOS_SIGNATURE as _ {
+            return Err(ParseError::InvalidDosSignature { offset: 0 });
+        }
+
+        if result.bytes.copy_as::<u32>(dos.e_lfanew as _)? != IMAGE_NT_SIGNATURE {
+            return Err(ParseError::InvalidNtSignature { offset: dos.e_lfanew as _ });
         }
 
         let file_offset = dos.e_lfanew as usize + std::mem::size_of::<u32>();
@@ -72,38 +115,58 @@ impl File {
                 let optional = result.bytes.view_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
                 (optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize].VirtualAddress, result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(optional_offset + std::mem::size_of::<IMAGE_OPTIONAL_HEADER64>(), file.NumberOfSections as usize)?)
             }
-            _ => return Err(()),
+            _ => return Err(ParseError::InvalidOptionalHeader { offset: optional_offset }),
         };
 
-        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(offset_from_rva(section_from_rva(sections, com_virtual_address)?, com_virtual_address) as _)?;
+        let clr_offset = offset_from_rva(section_from_rva(sections, com_virtual_address).ok_or(ParseError::MissingComDescriptor { offset: optional_offset })?, com_virtual_address);
+        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(clr_offset)?;
 
         if clr.cb != std::mem::size_of::<IMAGE_COR20_HEADER>() as _ {
-            return Err(());
+            return Err(ParseError::MissingComDescriptor { offset: clr_offset });
         }
 
-        let metadata_offset = offset_from_rva(section_from_rva(sections, clr.MetaData.VirtualAddress)?, clr.MetaData.VirtualAddress);
+        let metadata_offset = offset_from_rva(section_from_rva(sections, clr.MetaData.VirtualAddress).ok_or(ParseError::MissingComDescriptor { offset: clr_offset })?, clr.MetaData.VirtualAddress);
         let metadata = result.bytes.view_as::<METADATA_HEADER>(metadata_offset as _)?;
 
         if metadata.signature != METADATA_SIGNATURE {
-            return Err(());
+            return Err(ParseError::InvalidMetadataSignature { offset: metadata_offset });
         }
 
         // The METADATA_HEADER struct is not a fixed size so have to offset a little more carefully.
         let mut view = metadata_offset + metadata.length as usize + 20;
         let mut tables_data: (usize, usize) = (0, 0);
+        let mut has_strings = false;
+        let mut has_blobs = false;
 
         for _ in 0..result.bytes.copy_as::<u16>(metadata_offset + metadata.length as usize + 18)? {
             let stream_offset = result.bytes.copy_as::<u32>(view)? as usize;
             let stream_len = result.bytes.copy_as::<u32>(view + 4)? as usize;
             let stream_name = result.bytes.view_as_str(view + 8)?;
-            match stream_name {
-                b"#Strings" => result.strings = metadata_offset + stream_offset,
-                b"#Blob" => result.blobs = metadata_offset + stream_offset,
-                b"#~" => tables_data = (metadata_offset + stream_offset, stream_len),
-                b"#GUID" => {}
-                b"#US" => {}
-                rest => unimplemented!("{rest:?}"),
+
+            let name = match stream_name {
+                b"#Strings" => {
+                    result.strings = metadata_offset + stream_offset;
+                    has_strings = true;
+                    "#Strings"
+                }
+                b"#Blob" => {
+                    result.blobs = metadata_offset + stream_offset;
+                    has_blobs = true;
+                    "#Blob"
+                }
+                b"#~" => {
+                    tables_data = (metadata_offset + stream_offset, stream_len);
+                    "#~"
+                }
+                b"#GUID" => "#GUID",
+                b"#US" => "#US",
+                rest => return Err(ParseError::UnsupportedStream { offset: view, name: String::from_utf8_lossy(rest).into_owned() }),
+            };
+
+            if metadata_offset + stream_offset + stream_len > result.bytes.len() {
+                return Err(ParseError::TruncatedHeap { offset: metadata_offset + stream_offset, name });
             }
+
             let mut padding = 4 - stream_name.len() % 4;
             if padding == 0 {
                 padding = 4;
@@ -111,6 +174,18 @@ impl File {
             view += 8 + stream_name.len() + padding;
         }
 
+        if tables_data.1 == 0 {
+            return Err(ParseError::MissingStream { offset: metadata_offset, name: "#~" });
+        }
+
+        if !has_strings {
+            return Err(ParseError::MissingStream { offset: metadata_offset, name: "#Strings" });
+        }
+
+        if !has_blobs {
+            return Err(ParseError::MissingStream { offset: metadata_offset, name: "#Blob" });
+        }
+
         let heap_sizes = result.bytes.copy_as::<u8>(tables_data.0 + 6)?;
         let string_index_size = if (heap_sizes & 1) == 1 { 4 } else { 2 };
         let guid_index_size = if (heap_sizes >> 1 & 1) == 1 { 4 } else { 2 };
@@ -120,26 +195,19 @@ impl File {
 
         // These tables are unused by the reader, but needed temporarily to calculate sizes and offsets for subsequent tables.
         let unused_empty = Table::default();
-        let mut unused_assembly = Table::default();
         let mut unused_assembly_os = Table::default();
         let mut unused_assembly_processor = Table::default();
         let mut unused_assembly_ref_os = Table::default();
         let mut unused_assembly_ref_processor = Table::default();
         let mut unused_decl_security = Table::default();
-        let mut unused_event = Table::default();
-        let mut unused_event_map = Table::default();
         let mut unused_exported_type = Table::default();
         let mut unused_field_layout = Table::default();
         let mut unused_field_marshal = Table::default();
-        let mut unused_field_rva = Table::default();
         let mut unused_file = Table::default();
         let mut unused_generic_param_constraint = Table::default();
         let mut unused_manifest_resource = Table::default();
         let mut unused_method_impl = Table::default();
-        let mut unused_method_semantics = Table::default();
         let mut unused_method_spec = Table::default();
-        let mut unused_property = Table::default();
-        let mut unused_property_map = Table::default();
         let mut unused_standalone_sig = Table::default();
 
         for i in 0..64 {
@@ -148,6 +216,7 @@ impl File {
             }
 
             let len = result.bytes.copy_as::<u32>(view)? as _;
+            let len_offset = view;
             view += 4;
 
             match i {
@@ -166,17 +235,17 @@ impl File {
                 0x0f => result.tables[TABLE_CLASSLAYOUT].len = len,
                 0x10 => unused_field_layout.len = len,
                 0x11 => unused_standalone_sig.len = len,
-                0x12 => unused_event_map.len = len,
-                0x14 => unused_event.len = len,
-                0x15 => unused_property_map.len = len,
-                0x17 => unused_property.len = len,
-                0x18 => unused_method_semantics.len = len,
+                0x12 => result.tables[TABLE_EVENTMAP].len = len,
+                0x14 => result.tables[TABLE_EVENT].len = len,
+                0x15 => result.tables[TABLE_PROPERTYMAP].len = len,
+                0x17 => result.tables[TABLE_PROPERTY].len = len,
+                0x18 => result.tables[TABLE_METHODSEMANTICS].len = len,
                 0x19 => unused_method_impl.len = len,
                 0x1a => result.tables[TABLE_MODULEREF].len = len,
                 0x1b => result.tables[TABLE_TYPESPEC].len = len,
                 0x1c => result.tables[TABLE_IMPLMAP].len = len,
-                0x1d => unused_field_rva.len = len,
-                0x20 => unused_assembly.len = len,
+                0x1d => result.tables[TABLE_FIELDRVA].len = len,
+                0x20 => result.tables[TABLE_ASSEMBLY].len = len,
                 0x21 => unused_assembly_processor.len = len,
                 0x22 => unused_assembly_os.len = len,
                 0x23 => result.tables[TABLE_ASSEMBLYREF].len = len,
@@ -189,17 +258,17 @@ impl File {
                 0x2a => result.tables[TABLE_GENERICPARAM].len = len,
                 0x2b => unused_method_spec.len = len,
                 0x2c => unused_generic_param_constraint.len = len,
-                _ => unreachable!(),
+                _ => return Err(ParseError::UnsupportedTable { offset: len_offset, table: i }),
             };
         }
 
         let tables = &result.tables;
         let type_def_or_ref = coded_index_size(&[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_TYPESPEC].len]);
-        let has_constant = coded_index_size(&[tables[TABLE_FIELD].len, tables[TABLE_PARAM].len, unused_property.len]);
+        let has_constant = coded_index_size(&[tables[TABLE_FIELD].len, tables[TABLE_PARAM].len, tables[TABLE_PROPERTY].len]);
         let has_field_marshal = coded_index_size(&[tables[TABLE_FIELD].len, tables[TABLE_PARAM].len]);
-        let has_decl_security = coded_index_size(&[tables[TABLE_TYPEDEF].len, tables[TABLE_METHODDEF].len, unused_assembly.len]);
+        let has_decl_security = coded_index_size(&[tables[TABLE_TYPEDEF].len, tables[TABLE_METHODDEF].len, tables[TABLE_ASSEMBLY].len]);
         let member_ref_parent = coded_index_size(&[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_MODULEREF].len, tables[TABLE_METHODDEF].len, tables[TABLE_TYPESPEC].len]);
-        let has_semantics = coded_index_size(&[unused_event.len, unused_property.len]);
+        let has_semantics = coded_index_size(&[tables[TABLE_EVENT].len, tables[TABLE_PROPERTY].len]);
         let method_def_or_ref = coded_index_size(&[tables[TABLE_METHODDEF].len, tables[TABLE_MEMBERREF].len]);
         let member_forwarded = coded_index_size(&[tables[TABLE_FIELD].len, tables[TABLE_METHODDEF].len]);
         let implementation = coded_index_size(&[unused_file.len, tables[TABLE_ASSEMBLYREF].len, unused_exported_type.len]);
@@ -216,12 +285,12 @@ impl File {
             tables[TABLE_INTERFACEIMPL].len,
             tables[TABLE_MEMBERREF].len,
             tables[TABLE_MODULE].len,
-            unused_property.len,
-            unused_event.len,
+            tables[TABLE_PROPERTY].len,
+            tables[TABLE_EVENT].len,
             unused_standalone_sig.len,
             tables[TABLE_MODULEREF].len,
             tables[TABLE_TYPESPEC].len,
-            unused_assembly.len,
+            tables[TABLE_ASSEMBLY].len,
             tables[TABLE_ASSEMBLYREF].len,
             unused_file.len,
             unused_exported_type.len,
@@ -231,7 +300,7 @@ impl File {
             unused_method_spec.len,
         ]);
 
-        unused_assembly.set_columns(4, 8, 4, blob_index_size, string_index_size, string_index_size);
+        result.tables[TABLE_ASSEMBLY].set_columns(4, 8, 4, blob_index_size, string_index_size, string_index_size);
         unused_assembly_os.set_columns(4, 4, 4, 0, 0, 0);
         unused_assembly_processor.set_columns(4, 0, 0, 0, 0, 0);
         result.tables[TABLE_ASSEMBLYREF].set_columns(8, 4, blob_index_size, string_index_size, string_index_size, blob_index_size);
@@ -241,13 +310,13 @@ impl File {
         result.tables[TABLE_CONSTANT].set_columns(2, has_constant, blob_index_size, 0, 0, 0);
         result.tables[TABLE_CUSTOMATTRIBUTE].set_columns(has_custom_attribute, custom_attribute_type, blob_index_size, 0, 0, 0);
         unused_decl_security.set_columns(2, has_decl_security, blob_index_size, 0, 0, 0);
-        unused_event_map.set_columns(result.tables[TABLE_TYPEDEF].index_width(), unused_event.index_width(), 0, 0, 0, 0);
-        unused_event.set_columns(2, string_index_size, type_def_or_ref, 0, 0, 0);
+        result.tables[TABLE_EVENTMAP].set_columns(result.tables[TABLE_TYPEDEF].index_width(), result.tables[TABLE_EVENT].index_width(), 0, 0, 0, 0);
+        result.tables[TABLE_EVENT].set_columns(2, string_index_size, type_def_or_ref, 0, 0, 0);
         unused_exported_type.set_columns(4, 4, string_index_size, string_index_size, implementation, 0);
         result.tables[TABLE_FIELD].set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
         unused_field_layout.set_columns(4, result.tables[TABLE_FIELD].index_width(), 0, 0, 0, 0);
         unused_field_marshal.set_columns(has_field_marshal, blob_index_size, 0, 0, 0, 0);
-        unused_field_rva.set_columns(4, result.tables[TABLE_FIELD].index_width(), 0, 0, 0, 0);
+        result.tables[TABLE_FIELDRVA].set_columns(4, result.tables[TABLE_FIELD].index_width(), 0, 0, 0, 0);
         unused_file.set_columns(4, string_index_size, blob_index_size, 0, 0, 0);
         result.tables[TABLE_GENERICPARAM].set_columns(2, 2, type_or_method_def, string_index_size, 0, 0);
         unused_generic_param_constraint.set_columns(result.tables[TABLE_GENERICPARAM].index_width(), type_def_or_ref, 0, 0, 0, 0);
@@ -257,14 +326,14 @@ impl File {
         result.tables[TABLE_MEMBERREF].set_columns(member_ref_parent, string_index_size, blob_index_size, 0, 0, 0);
         result.tables[TABLE_METHODDEF].set_columns(4, 2, 2, string_index_size, blob_index_size, result.tables[TABLE_PARAM].index_width());
         unused_method_impl.set_columns(result.tables[TABLE_TYPEDEF].index_width(), method_def_or_ref, method_def_or_ref, 0, 0, 0);
-        unused_method_semantics.set_columns(2, result.tables[TABLE_METHODDEF].index_width(), has_semantics, 0, 0, 0);
+        result.tables[TABLE_METHODSEMANTICS].set_columns(2, result.tables[TABLE_METHODDEF].index_width(), has_semantics, 0, 0, 0);
         unused_method_spec.set_columns(method_def_or_ref, blob_index_size, 0, 0, 0, 0);
         result.tables[TABLE_MODULE].set_columns(2, string_index_size, guid_index_size, guid_index_size, guid_index_size, 0);
         result.tables[TABLE_MODULEREF].set_columns(string_index_size, 0, 0, 0, 0, 0);
         result.tables[TABLE_NESTEDCLASS].set_columns(result.tables[TABLE_TYPEDEF].index_width(), result.tables[TABLE_TYPEDEF].index_width(), 0, 0, 0, 0);
         result.tables[TABLE_PARAM].set_columns(2, 2, string_index_size, 0, 0, 0);
-        unused_property.set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
-        unused_property_map.set_columns(result.tables[TABLE_TYPEDEF].index_width(), unused_property.index_width(), 0, 0, 0, 0);
+        result.tables[TABLE_PROPERTY].set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
+        result.tables[TABLE_PROPERTYMAP].set_columns(result.tables[TABLE_TYPEDEF].index_width(), result.tables[TABLE_PROPERTY].index_width(), 0, 0, 0, 0);
         unused_standalone_sig.set_columns(blob_index_size, 0, 0, 0, 0, 0);
         result.tables[TABLE_TYPEDEF].set_columns(4, string_index_size, string_index_size, type_def_or_ref, result.tables[TABLE_FIELD].index_width(), result.tables[TABLE_METHODDEF].index_width());
         result.tables[TABLE_TYPEREF].set_columns(resolution_scope, string_index_size, string_index_size, 0, 0, 0);
@@ -285,17 +354,17 @@ impl File {
         result.tables[TABLE_CLASSLAYOUT].set_data(&mut view);
         unused_field_layout.set_data(&mut view);
         unused_standalone_sig.set_data(&mut view);
-        unused_event_map.set_data(&mut view);
-        unused_event.set_data(&mut view);
-        unused_property_map.set_data(&mut view);
-        unused_property.set_data(&mut view);
-        unused_method_semantics.set_data(&mut view);
+        result.tables[TABLE_EVENTMAP].set_data(&mut view);
+        result.tables[TABLE_EVENT].set_data(&mut view);
+        result.tables[TABLE_PROPERTYMAP].set_data(&mut view);
+        result.tables[TABLE_PROPERTY].set_data(&mut view);
+        result.tables[TABLE_METHODSEMANTICS].set_data(&mut view);
         unused_method_impl.set_data(&mut view);
         result.tables[TABLE_MODULEREF].set_data(&mut view);
         result.tables[TABLE_TYPESPEC].set_data(&mut view);
         result.tables[TABLE_IMPLMAP].set_data(&mut view);
-        unused_field_rva.set_data(&mut view);
-        unused_assembly.set_data(&mut view);
+        result.tables[TABLE_FIELDRVA].set_data(&mut view);
+        result.tables[TABLE_ASSEMBLY].set_data(&mut view);
         unused_assembly_processor.set_data(&mut view);
         unused_assembly_os.set_data(&mut view);
         result.tables[TABLE_ASSEMBLYREF].set_data(&mut view);
@@ -307,9 +376,47 @@ impl File {
         result.tables[TABLE_NESTEDCLASS].set_data(&mut view);
         result.tables[TABLE_GENERICPARAM].set_data(&mut view);
 
+        if view > tables_data.0 + tables_data.1 {
+            return Err(ParseError::TruncatedHeap { offset: tables_data.0, name: "#~" });
+        }
+
+        let tables = &result.tables;
+        let empty = 0;
+        result.check_coded_index(TABLE_TYPEREF, 0, "TypeRef", &[tables[TABLE_MODULE].len, tables[TABLE_MODULEREF].len, tables[TABLE_ASSEMBLYREF].len, tables[TABLE_TYPEREF].len])?;
+        result.check_coded_index(TABLE_TYPEDEF, 3, "TypeDef", &[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_TYPESPEC].len])?;
+        result.check_coded_index(TABLE_INTERFACEIMPL, 1, "InterfaceImpl", &[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_TYPESPEC].len])?;
+        result.check_coded_index(TABLE_MEMBERREF, 0, "MemberRef", &[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_MODULEREF].len, tables[TABLE_METHODDEF].len, tables[TABLE_TYPESPEC].len])?;
+        result.check_coded_index(TABLE_CONSTANT, 1, "Constant", &[tables[TABLE_FIELD].len, tables[TABLE_PARAM].len, tables[TABLE_PROPERTY].len])?;
+        result.check_coded_index(TABLE_CUSTOMATTRIBUTE, 1, "CustomAttribute", &[empty, empty, tables[TABLE_METHODDEF].len, tables[TABLE_MEMBERREF].len, empty])?;
+        result.check_coded_index(TABLE_GENERICPARAM, 2, "GenericParam", &[tables[TABLE_TYPEDEF].len, tables[TABLE_METHODDEF].len])?;
+        result.check_coded_index(TABLE_IMPLMAP, 1, "ImplMap", &[tables[TABLE_FIELD].len, tables[TABLE_METHODDEF].len])?;
+        result.check_coded_index(TABLE_EVENT, 2, "Event", &[tables[TABLE_TYPEDEF].len, tables[TABLE_TYPEREF].len, tables[TABLE_TYPESPEC].len])?;
+        result.check_coded_index(TABLE_METHODSEMANTICS, 2, "MethodSemantics", &[tables[TABLE_EVENT].len, tables[TABLE_PROPERTY].len])?;
+
         Ok(result)
     }
 
+    // Checks that every coded index in the given column refers to a row within one of the target tables, where the
+    // position of the target table is the tag of the coded index. A zero row is treated as a null reference.
+    fn check_coded_index(&self, table: usize, column: usize, name: &'static str, targets: &[usize]) -> Result<()> {
+        let bits = (usize::BITS - (targets.len() - 1).leading_zeros()) as usize;
+
+        for row in 0..self.tables[table].len {
+            let value = self.usize(row, table, column);
+            let index = value >> bits;
+
+            match targets.get(value & ((1 << bits) - 1)) {
+                Some(len) if index <= *len => {}
+                _ => {
+                    let table = &self.tables[table];
+                    return Err(ParseError::InvalidCodedIndex { offset: table.offset + row * table.width + table.columns[column].offset, table: name });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn usize(&self, row: usize, table: usize, column: usize) -> usize {
         let table = &self.tables[table];
         let column = &table.columns[column];
@@ -491,35 +598,99 @@ impl View for [u8] {
     }
 
     fn view_as_str(&self, offset: usize) -> Result<&[u8]> {
-        let buffer = &self[offset..];
-        let index = buffer.iter().position(|c| *c == b'\0').ok_or(())?;
+        let buffer = self.get(offset..).ok_or(ParseError::Truncated { offset })?;
+        let index = buffer.iter().position(|c| *c == b'\0').ok_or(ParseError::Truncated { offset })?;
         Ok(&self[offset..offset + index])
     }
 
     fn is_proper_length<T>(&self, offset: usize) -> Result<()> {
-        if offset + std::mem::size_of::<T>() <= self.len() {
-            Ok(())
-        } else {
-            Err(())
-        }
+        self.is_proper_length_and_alignment::<u8>(offset, std::mem::size_of::<T>()).map(|_| ())
     }
 
     fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Result<*const T> {
-        self.is_proper_length::<T>(offset * count)?;
-        let ptr = &self[offset] as *const u8 as *const T;
+        match offset.checked_add(std::mem::size_of::<T>() * count) {
+            Some(end) if end <= self.len() => {}
+            _ => return Err(ParseError::Truncated { offset }),
+        }
+
+        let ptr = self[offset..].as_ptr() as *const T;
 
         if ptr.align_offset(std::mem::align_of::<T>()) == 0 {
             Ok(ptr)
         } else {
-            Err(())
+            Err(ParseError::Misaligned { offset })
         }
     }
 }
 
-fn section_from_rva(sections: &[IMAGE_SECTION_HEADER], rva: u32) -> Result<&IMAGE_SECTION_HEADER> {
-    sections.iter().find(|&s| rva >= s.VirtualAddress && rva < s.VirtualAddress + unsafe { s.Misc.VirtualSize }).ok_or(())
+fn section_from_rva(sections: &[IMAGE_SECTION_HEADER], rva: u32) -> Option<&IMAGE_SECTION_HEADER> {
+    sections.iter().find(|&s| rva >= s.VirtualAddress && rva < s.VirtualAddress + unsafe { s.Misc.VirtualSize })
 }
 
 fn offset_from_rva(section: &IMAGE_SECTION_HEADER, rva: u32) -> usize {
     (rva - section.VirtualAddress + section.PointerToRawData) as usize
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read() -> Vec<u8> {
+        std::fs::read("default/Windows.Wdk.winmd").unwrap()
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(File::new(read()).is_ok());
+    }
+
+    #[test]
+    fn test_from_source() {
+        let files = [File::new(read()).unwrap(), File::from_source(read().into_boxed_slice()).unwrap()];
+        let reader = &Reader::new(&files);
+
+        // Both files define the same types so each is found twice, in file order.
+        let defs: Vec<TypeDef> = reader.get(TypeName::new("Windows.Wdk.Foundation", "DEVICE_OBJECT")).collect();
+        assert_eq!(defs.len(), 2);
+        assert_eq!(defs[0].0.file, 0);
+        assert_eq!(defs[1].0.file, 1);
+        assert_eq!(reader.type_def_fields(defs[0]).count(), reader.type_def_fields(defs[1]).count());
+
+        assert!(reader.namespaces().any(|namespace| namespace == "Windows.Wdk.Foundation"));
+        assert_eq!(reader.get(TypeName::new("Windows.Wdk.Foundation", "MISSING")).count(), 0);
+    }
+
+    #[test]
+    fn test_signatures() {
+        assert_eq!(File::new(vec![]).err(), Some(ParseError::Truncated { offset: 0 }));
+
+        let mut bytes = read();
+        bytes[0] = 0;
+        assert_eq!(File::new(bytes).err(), Some(ParseError::InvalidDosSignature { offset: 0 }));
+
+        let mut bytes = read();
+        let nt = bytes.copy_as::<u32>(0x3c).unwrap() as usize;
+        bytes[nt] = 0;
+        assert_eq!(File::new(bytes).err(), Some(ParseError::InvalidNtSignature { offset: nt }));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let mut bytes = read();
+        bytes.truncate(bytes.len() / 2);
+        assert!(matches!(File::new(bytes).err(), Some(ParseError::TruncatedHeap { .. })));
+    }
+
+    #[test]
+    fn test_coded_index() {
+        let mut bytes = read();
+        let file = File::new(bytes.clone()).unwrap();
+        let table = &file.tables[TABLE_TYPEDEF];
+        let offset = table.offset + table.width + table.columns[3].offset;
+        bytes[offset..offset + table.columns[3].width].iter_mut().for_each(|byte| *byte = 0xff);
+
+        let error = File::new(bytes).err().unwrap();
+        assert_eq!(error, ParseError::InvalidCodedIndex { offset, table: "TypeDef" });
+        assert_eq!(error.to_string(), format!("out-of-range coded index in the TypeDef table at offset 0x{offset:x}"));
+    }
+}
@@ -0,0 +1,322 @@
+use super::*;
+
+/// The version of the JSON schema written by `to_json`, which is incremented whenever existing keys change meaning.
+pub const JSON_SCHEMA_VERSION: usize = 1;
+
+/// Writes the types included by `filter` as JSON so that tools without an ECMA-335 parser can consume the metadata.
+///
+/// The document has the following shape, where optional keys are omitted rather than written as `null`:
+///
+/// - document: `{ "version": 1, "namespaces": [namespace] }`
+/// - namespace: `{ "name", "types": [type] }`
+/// - type: `{ "name", "kind", "flags", "guid"?, "extends"?, "generics": [string], "cfg", "attributes": [attribute],
+///   "interfaces": [type ref], "fields": [field], "methods": [method], "properties": [property], "events": [event],
+///   "nested": [type] }` where `kind` is one of `class`, `interface`, `enum`, `struct` or `delegate`
+/// - field: `{ "name", "flags", "type": type ref, "value"?: value, "attributes": [attribute] }`
+/// - method: `{ "name", "flags", "impl_flags", "import"?: { "module", "name" }, "signature", "cfg", "attributes": [attribute] }`
+/// - signature: `{ "call_flags", "return_type": type ref, "params": [{ "name", "flags", "type": type ref, "attributes": [attribute] }] }`
+/// - property, event: `{ "name", "type": type ref, "attributes": [attribute] }`
+/// - attribute: `{ "name", "args": [{ "name"?, "value": value }] }` where `name` is the full name of the attribute type
+/// - cfg: `{ "features": [string], "arches": [string] }`
+/// - type ref: `{ "kind": "named", "name", "generics"?: [type ref] }`, `{ "kind": "generic_param", "name" }`,
+///   `{ "kind": "mut_ptr" | "const_ptr", "pointers", "type" }`, `{ "kind": "array", "len", "type" }`,
+///   `{ "kind": "winrt_array" | "winrt_array_ref" | "const_ref", "type" }` or `{ "kind" }` for primitives, which are
+///   `void`, `bool`, `char`, `i8`, `u8`, `i16`, `u16`, `i32`, `u32`, `i64`, `u64`, `f32`, `f64`, `isize`, `usize`,
+///   `hstring`, `guid`, `iunknown`, `iinspectable`, `hresult`, `pstr`, `pwstr`, `pcstr`, `pcwstr`, `bstr` and
+///   `type_name`
+/// - value: `{ "type", "value" }` where `type` is a primitive such as `u32` or `string`, `type` for a type name, or
+///   `enum` along with an `enum` key holding the name of the enum type and a nested value
+///
+/// Flags are the raw ECMA-335 bit fields and names are written as `Namespace.Name`.
+pub fn to_json(reader: &Reader, filter: &Filter) -> String {
+    let namespaces = reader
+        .namespaces()
+        .filter(|namespace| filter.includes_namespace(namespace))
+        .filter_map(|namespace| {
+            let types: Vec<Json> = reader.namespace_types(namespace, filter).map(|def| type_def_json(reader, def)).collect();
+            (!types.is_empty()).then(|| Json::Object(vec![("name", Json::string(namespace)), ("types", Json::Array(types))]))
+        })
+        .collect();
+
+    Json::Object(vec![("version", Json::number(JSON_SCHEMA_VERSION)), ("namespaces", Json::Array(namespaces))]).to_string()
+}
+
+// A minimal JSON document model, written with two space indentation and keys in insertion order.
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub fn string(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+
+    pub fn number(value: impl ToString) -> Self {
+        Self::Number(value.to_string())
+    }
+
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Self::Number(value) => out.push_str(value),
+            Self::String(value) => write_string(out, value),
+            Self::Array(items) if items.is_empty() => out.push_str("[]"),
+            Self::Array(items) => {
+                out.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    out.push_str(if index == 0 { "\n" } else { ",\n" });
+                    out.push_str(&"  ".repeat(indent + 1));
+                    item.write(out, indent + 1);
+                }
+                out.push('\n');
+                out.push_str(&"  ".repeat(indent));
+                out.push(']');
+            }
+            Self::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Self::Object(fields) => {
+                out.push('{');
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    out.push_str(if index == 0 { "\n" } else { ",\n" });
+                    out.push_str(&"  ".repeat(indent + 1));
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                out.push('\n');
+                out.push_str(&"  ".repeat(indent));
+                out.push('}');
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+        out.push('\n');
+        f.write_str(&out)
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+}
+
+fn type_def_json(reader: &Reader, def: TypeDef) -> Json {
+    let generics: Vec<Type> = reader.type_def_generics(def).collect();
+    let kind = match reader.type_def_kind(def) {
+        TypeKind::Class => "class",
+        TypeKind::Interface => "interface",
+        TypeKind::Enum => "enum",
+        TypeKind::Struct => "struct",
+        TypeKind::Delegate => "delegate",
+    };
+
+    let mut fields = vec![("name", Json::string(reader.type_def_name(def))), ("kind", Json::string(kind)), ("flags", Json::number(reader.type_def_flags(def).0))];
+
+    if let Some(guid) = reader.type_def_guid(def) {
+        fields.push(("guid", Json::String(format!("{guid:?}"))));
+    }
+
+    if let Some(extends) = reader.type_def_extends(def) {
+        fields.push(("extends", Json::String(extends.to_string())));
+    }
+
+    fields.push(("generics", Json::Array(generics.iter().filter_map(|ty| if let Type::GenericParam(param) = ty { Some(Json::string(reader.generic_param_name(*param))) } else { None }).collect())));
+    fields.push(("cfg", cfg_json(&reader.type_def_cfg(def, &generics))));
+    fields.push(("attributes", attributes_json(reader, reader.type_def_attributes(def))));
+    fields.push(("interfaces", Json::Array(reader.type_def_interface_impls(def).map(|row| type_json(reader, &reader.interface_impl_type(row, &generics).ty)).collect())));
+    fields.push(("fields", Json::Array(reader.type_def_fields(def).map(|field| field_json(reader, field, def)).collect())));
+    fields.push(("methods", Json::Array(reader.type_def_methods(def).map(|method| method_json(reader, method, &generics)).collect())));
+    fields.push(("properties", Json::Array(reader.type_def_properties(def).map(|property| member_json(reader.property_name(property), type_json(reader, &reader.property_type(property, &generics)), attributes_json(reader, reader.property_attributes(property)))).collect())));
+    fields.push(("events", Json::Array(reader.type_def_events(def).map(|event| member_json(reader.event_name(event), type_json(reader, &reader.event_type(event, &generics)), attributes_json(reader, reader.event_attributes(event)))).collect())));
+    fields.push(("nested", Json::Array(reader.nested_types(def).map(|nested| type_def_json(reader, nested)).collect())));
+    Json::Object(fields)
+}
+
+fn field_json(reader: &Reader, field: Field, enclosing: TypeDef) -> Json {
+    let mut fields = vec![("name", Json::string(reader.field_name(field))), ("flags", Json::number(reader.field_flags(field).0)), ("type", type_json(reader, &reader.field_type(field, Some(enclosing))))];
+
+    if let Some(constant) = reader.field_constant(field) {
+        fields.push(("value", value_json(reader, &reader.constant_value(constant))));
+    }
+
+    fields.push(("attributes", attributes_json(reader, reader.field_attributes(field))));
+    Json::Object(fields)
+}
+
+fn method_json(reader: &Reader, method: MethodDef, generics: &[Type]) -> Json {
+    let signature = reader.method_def_signature(method, generics);
+    let mut fields = vec![("name", Json::string(reader.method_def_name(method))), ("flags", Json::number(reader.method_def_flags(method).0)), ("impl_flags", Json::number(reader.method_def_impl_flags(method).0))];
+
+    if let Some(impl_map) = reader.method_def_impl_map(method) {
+        fields.push(("import", Json::Object(vec![("module", Json::string(reader.module_ref_name(reader.impl_map_scope(impl_map)))), ("name", Json::string(reader.impl_map_import_name(impl_map)))])));
+    }
+
+    let params = signature.params.iter().map(|param| Json::Object(vec![("name", Json::string(reader.param_name(param.def))), ("flags", Json::number(reader.param_flags(param.def).0)), ("type", type_json(reader, &param.ty)), ("attributes", attributes_json(reader, reader.param_attributes(param.def)))])).collect();
+    fields.push(("signature", Json::Object(vec![("call_flags", Json::number(signature.call_flags.0)), ("return_type", type_json(reader, &signature.return_type)), ("params", Json::Array(params))])));
+    fields.push(("cfg", cfg_json(&reader.signature_cfg(&signature))));
+    fields.push(("attributes", attributes_json(reader, reader.method_def_attributes(method))));
+    Json::Object(fields)
+}
+
+fn member_json(name: &str, ty: Json, attributes: Json) -> Json {
+    Json::Object(vec![("name", Json::string(name)), ("type", ty), ("attributes", attributes)])
+}
+
+fn cfg_json(cfg: &Cfg) -> Json {
+    Json::Object(vec![("features", Json::Array(cfg.types.keys().map(|feature| Json::string(feature)).collect())), ("arches", Json::Array(cfg.arches.iter().map(|arch| Json::string(arch)).collect()))])
+}
+
+fn attributes_json(reader: &Reader, attributes: impl Iterator<Item = Attribute>) -> Json {
+    Json::Array(
+        attributes
+            .map(|attribute| {
+                let args = reader
+                    .attribute_args(attribute)
+                    .iter()
+                    .map(|(name, value)| {
+                        let mut fields = vec![];
+                        if !name.is_empty() {
+                            fields.push(("name", Json::string(name)));
+                        }
+                        fields.push(("value", value_json(reader, value)));
+                        Json::Object(fields)
+                    })
+                    .collect();
+
+                Json::Object(vec![("name", Json::String(reader.attribute_type_name(attribute).to_string())), ("args", Json::Array(args))])
+            })
+            .collect(),
+    )
+}
+
+fn type_json(reader: &Reader, ty: &Type) -> Json {
+    let kind = |kind: &str| ("kind", Json::string(kind));
+
+    match ty {
+        Type::TypeDef(def, generics) => {
+            let mut fields = vec![kind("named"), ("name", Json::String(format!("{}.{}", reader.type_def_namespace(*def), trim_tick(reader.type_def_name(*def)))))];
+            if !generics.is_empty() {
+                fields.push(("generics", Json::Array(generics.iter().map(|ty| type_json(reader, ty)).collect())));
+            }
+            Json::Object(fields)
+        }
+        Type::TypeRef(code) => Json::Object(vec![kind("named"), ("name", Json::String(reader.type_def_or_ref(*code).to_string()))]),
+        Type::GenericParam(param) => Json::Object(vec![kind("generic_param"), ("name", Json::string(reader.generic_param_name(*param)))]),
+        Type::MutPtr(ty, pointers) => Json::Object(vec![kind("mut_ptr"), ("pointers", Json::number(pointers)), ("type", type_json(reader, ty))]),
+        Type::ConstPtr(ty, pointers) => Json::Object(vec![kind("const_ptr"), ("pointers", Json::number(pointers)), ("type", type_json(reader, ty))]),
+        Type::Win32Array(ty, len) => Json::Object(vec![kind("array"), ("len", Json::number(len)), ("type", type_json(reader, ty))]),
+        Type::WinrtArray(ty) => Json::Object(vec![kind("winrt_array"), ("type", type_json(reader, ty))]),
+        Type::WinrtArrayRef(ty) => Json::Object(vec![kind("winrt_array_ref"), ("type", type_json(reader, ty))]),
+        Type::ConstRef(ty) => Json::Object(vec![kind("const_ref"), ("type", type_json(reader, ty))]),
+        Type::Void => Json::Object(vec![kind("void")]),
+        Type::Bool => Json::Object(vec![kind("bool")]),
+        Type::Char => Json::Object(vec![kind("char")]),
+        Type::I8 => Json::Object(vec![kind("i8")]),
+        Type::U8 => Json::Object(vec![kind("u8")]),
+        Type::I16 => Json::Object(vec![kind("i16")]),
+        Type::U16 => Json::Object(vec![kind("u16")]),
+        Type::I32 => Json::Object(vec![kind("i32")]),
+        Type::U32 => Json::Object(vec![kind("u32")]),
+        Type::I64 => Json::Object(vec![kind("i64")]),
+        Type::U64 => Json::Object(vec![kind("u64")]),
+        Type::F32 => Json::Object(vec![kind("f32")]),
+        Type::F64 => Json::Object(vec![kind("f64")]),
+        Type::ISize => Json::Object(vec![kind("isize")]),
+        Type::USize => Json::Object(vec![kind("usize")]),
+        Type::String => Json::Object(vec![kind("hstring")]),
+        Type::GUID => Json::Object(vec![kind("guid")]),
+        Type::IUnknown => Json::Object(vec![kind("iunknown")]),
+        Type::IInspectable => Json::Object(vec![kind("iinspectable")]),
+        Type::HRESULT => Json::Object(vec![kind("hresult")]),
+        Type::PSTR => Json::Object(vec![kind("pstr")]),
+        Type::PWSTR => Json::Object(vec![kind("pwstr")]),
+        Type::PCSTR => Json::Object(vec![kind("pcstr")]),
+        Type::PCWSTR => Json::Object(vec![kind("pcwstr")]),
+        Type::BSTR => Json::Object(vec![kind("bstr")]),
+        Type::TypeName => Json::Object(vec![kind("type_name")]),
+    }
+}
+
+fn value_json(reader: &Reader, value: &Value) -> Json {
+    let typed = |ty: &str, value: Json| Json::Object(vec![("type", Json::string(ty)), ("value", value)]);
+    // JSON has no representation for infinities or NaN.
+    let float = |value: f64| if value.is_finite() { Json::number(format!("{value:?}")) } else { Json::Null };
+
+    match value {
+        Value::Bool(value) => typed("bool", Json::Bool(*value)),
+        Value::U8(value) => typed("u8", Json::number(value)),
+        Value::I8(value) => typed("i8", Json::number(value)),
+        Value::U16(value) => typed("u16", Json::number(value)),
+        Value::I16(value) => typed("i16", Json::number(value)),
+        Value::U32(value) => typed("u32", Json::number(value)),
+        Value::I32(value) => typed("i32", Json::number(value)),
+        Value::U64(value) => typed("u64", Json::number(value)),
+        Value::I64(value) => typed("i64", Json::number(value)),
+        Value::F32(value) => typed("f32", float(*value as f64)),
+        Value::F64(value) => typed("f64", float(*value)),
+        Value::String(value) => typed("string", Json::string(value)),
+        Value::TypeDef(def) => typed("type", Json::String(reader.type_def_type_name(*def).to_string())),
+        Value::TypeRef(code) => typed("type", Json::String(reader.type_def_or_ref(*code).to_string())),
+        Value::EnumDef(def, value) => Json::Object(vec![("type", Json::string("enum")), ("enum", Json::String(reader.type_def_type_name(*def).to_string())), ("value", value_json(reader, value))]),
+        Value::EnumRef(code, value) => Json::Object(vec![("type", Json::string("enum")), ("enum", Json::String(reader.type_def_or_ref(*code).to_string())), ("value", value_json(reader, value))]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let json = Json::Object(vec![("name", Json::string("a \"b\"\n")), ("empty", Json::Array(vec![])), ("values", Json::Array(vec![Json::number(1), Json::Null, Json::Bool(true)]))]);
+        assert_eq!(json.to_string(), "{\n  \"name\": \"a \\\"b\\\"\\n\",\n  \"empty\": [],\n  \"values\": [\n    1,\n    null,\n    true\n  ]\n}\n");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let files = [File::new(std::fs::read("default/Windows.Wdk.winmd").unwrap()).unwrap()];
+        let reader = Reader::new(&files);
+        let json = to_json(&reader, &Filter::new(&["Windows.Wdk.Foundation"], &[]));
+
+        assert!(json.starts_with("{\n  \"version\": 1,\n  \"namespaces\": [\n    {\n      \"name\": \"Windows.Wdk.Foundation\",\n"));
+        assert!(json.contains("\"name\": \"DEVICE_OBJECT\",\n          \"kind\": \"struct\""));
+        assert!(!json.contains("\"name\": \"Windows.Wdk.Storage.FileSystem\""));
+    }
+
+    #[test]
+    fn test_primitive_kinds() {
+        let files = [];
+        let reader = Reader::new(&files);
+        let kind = |ty: Type| type_json(&reader, &ty).to_string();
+
+        assert_eq!(kind(Type::String), "{\n  \"kind\": \"hstring\"\n}\n");
+        assert_eq!(kind(Type::I32), "{\n  \"kind\": \"i32\"\n}\n");
+        assert_eq!(kind(Type::GUID), "{\n  \"kind\": \"guid\"\n}\n");
+        assert_eq!(kind(Type::IInspectable), "{\n  \"kind\": \"iinspectable\"\n}\n");
+        assert_eq!(kind(Type::TypeName), "{\n  \"kind\": \"type_name\"\n}\n");
+        assert_eq!(kind(Type::MutPtr(Box::new(Type::PCWSTR), 1)), "{\n  \"kind\": \"mut_ptr\",\n  \"pointers\": 1,\n  \"type\": {\n    \"kind\": \"pcwstr\"\n  }\n}\n");
+    }
+}
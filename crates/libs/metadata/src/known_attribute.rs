@@ -0,0 +1,520 @@
+use super::*;
+
+/// An attribute type that can be decoded from the arguments of a custom attribute.
+pub trait FromAttribute: Sized {
+    /// The name of the attribute type, such as `GuidAttribute`.
+    const NAME: &'static str;
+
+    /// Decodes the attribute's fixed and named arguments, returning `None` if they don't have the expected shape.
+    fn from_args(args: &[(String, Value)]) -> Option<Self>;
+}
+
+macro_rules! known_attributes {
+    ($($variant:ident($name:ident),)*) => {
+        /// A custom attribute decoded into one of the attribute types defined by the Win32 or WinRT metadata.
+        ///
+        /// Attributes that aren't known, or whose arguments don't match the expected shape, are preserved as `Unknown`
+        /// along with their generically decoded arguments.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum KnownAttribute {
+            $($variant($name),)*
+            Unknown(String, Vec<(String, Value)>),
+        }
+
+        impl KnownAttribute {
+            pub fn decode(name: &str, args: Vec<(String, Value)>) -> Self {
+                $(
+                    if name == $name::NAME {
+                        if let Some(attribute) = $name::from_args(&args) {
+                            return Self::$variant(attribute);
+                        }
+                    }
+                )*
+                Self::Unknown(name.to_string(), args)
+            }
+        }
+    };
+}
+
+known_attributes! {
+    Activatable(ActivatableAttribute),
+    Agile(AgileAttribute),
+    AlsoUsableFor(AlsoUsableForAttribute),
+    Ansi(AnsiAttribute),
+    ApiContract(ApiContractAttribute),
+    AssociatedEnum(AssociatedEnumAttribute),
+    CanReturnErrorsAsSuccess(CanReturnErrorsAsSuccessAttribute),
+    CanReturnMultipleSuccessValues(CanReturnMultipleSuccessValuesAttribute),
+    ComOutPtr(ComOutPtrAttribute),
+    Composable(ComposableAttribute),
+    Const(ConstAttribute),
+    Constant(ConstantAttribute),
+    ContractVersion(ContractVersionAttribute),
+    Default(DefaultAttribute),
+    DefaultOverload(DefaultOverloadAttribute),
+    Deprecated(DeprecatedAttribute),
+    DoNotRelease(DoNotReleaseAttribute),
+    Documentation(DocumentationAttribute),
+    DoesNotReturn(DoesNotReturnAttribute),
+    ExclusiveTo(ExclusiveToAttribute),
+    Experimental(ExperimentalAttribute),
+    Flags(FlagsAttribute),
+    FlexibleArray(FlexibleArrayAttribute),
+    FreeWith(FreeWithAttribute),
+    Guid(GuidAttribute),
+    IgnoreIfReturn(IgnoreIfReturnAttribute),
+    InvalidHandleValue(InvalidHandleValueAttribute),
+    MarshalingBehavior(MarshalingBehaviorAttribute),
+    MemorySize(MemorySizeAttribute),
+    MetadataTypedef(MetadataTypedefAttribute),
+    NativeArrayInfo(NativeArrayInfoAttribute),
+    NativeBitfield(NativeBitfieldAttribute),
+    NativeEncoding(NativeEncodingAttribute),
+    NativeTypedef(NativeTypedefAttribute),
+    NotNullTerminated(NotNullTerminatedAttribute),
+    NullNullTerminated(NullNullTerminatedAttribute),
+    Obsolete(ObsoleteAttribute),
+    Overload(OverloadAttribute),
+    Overridable(OverridableAttribute),
+    Protected(ProtectedAttribute),
+    RAIIFree(RAIIFreeAttribute),
+    Reserved(ReservedAttribute),
+    RetVal(RetValAttribute),
+    ScopedEnum(ScopedEnumAttribute),
+    Static(StaticAttribute),
+    StaticLibrary(StaticLibraryAttribute),
+    StructSizeField(StructSizeFieldAttribute),
+    SupportedArchitecture(SupportedArchitectureAttribute),
+    SupportedOSPlatform(SupportedOSPlatformAttribute),
+    Threading(ThreadingAttribute),
+    Unicode(UnicodeAttribute),
+    Version(VersionAttribute),
+    WebHostHidden(WebHostHiddenAttribute),
+}
+
+macro_rules! marker_attributes {
+    ($($name:ident,)*) => ($(
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name;
+        impl FromAttribute for $name {
+            const NAME: &'static str = stringify!($name);
+            fn from_args(_args: &[(String, Value)]) -> Option<Self> {
+                Some(Self)
+            }
+        }
+    )*)
+}
+
+marker_attributes! {
+    AgileAttribute,
+    AnsiAttribute,
+    ApiContractAttribute,
+    CanReturnErrorsAsSuccessAttribute,
+    CanReturnMultipleSuccessValuesAttribute,
+    ComOutPtrAttribute,
+    ConstAttribute,
+    DefaultAttribute,
+    DefaultOverloadAttribute,
+    DoNotReleaseAttribute,
+    DoesNotReturnAttribute,
+    ExperimentalAttribute,
+    FlagsAttribute,
+    FlexibleArrayAttribute,
+    MetadataTypedefAttribute,
+    NativeTypedefAttribute,
+    NotNullTerminatedAttribute,
+    NullNullTerminatedAttribute,
+    OverridableAttribute,
+    ProtectedAttribute,
+    ReservedAttribute,
+    RetValAttribute,
+    ScopedEnumAttribute,
+    UnicodeAttribute,
+    WebHostHiddenAttribute,
+}
+
+macro_rules! string_attributes {
+    ($($name:ident,)*) => ($(
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name(pub String);
+        impl FromAttribute for $name {
+            const NAME: &'static str = stringify!($name);
+            fn from_args(args: &[(String, Value)]) -> Option<Self> {
+                find_string(args).map(Self)
+            }
+        }
+    )*)
+}
+
+string_attributes! {
+    AlsoUsableForAttribute,
+    AssociatedEnumAttribute,
+    ConstantAttribute,
+    DocumentationAttribute,
+    FreeWithAttribute,
+    IgnoreIfReturnAttribute,
+    NativeEncodingAttribute,
+    ObsoleteAttribute,
+    OverloadAttribute,
+    RAIIFreeAttribute,
+    StaticLibraryAttribute,
+    StructSizeFieldAttribute,
+    SupportedOSPlatformAttribute,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuidAttribute(pub GUID);
+
+impl FromAttribute for GuidAttribute {
+    const NAME: &'static str = "GuidAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        if args.len() == 11 {
+            Some(Self(GUID::from_args(args)))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidHandleValueAttribute(pub i64);
+
+impl FromAttribute for InvalidHandleValueAttribute {
+    const NAME: &'static str = "InvalidHandleValueAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        args.iter().find_map(|(_, value)| if let Value::I64(value) = value { Some(Self(*value)) } else { None })
+    }
+}
+
+/// The architectures a Win32 API is available on, as a combination of `X86`, `X64` and `ARM64`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupportedArchitectureAttribute(pub i32);
+
+impl SupportedArchitectureAttribute {
+    pub const X86: i32 = 1;
+    pub const X64: i32 = 2;
+    pub const ARM64: i32 = 4;
+
+    pub fn contains(&self, arch: i32) -> bool {
+        self.0 & arch == arch
+    }
+}
+
+impl FromAttribute for SupportedArchitectureAttribute {
+    const NAME: &'static str = "SupportedArchitectureAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        find_enum(args).map(Self)
+    }
+}
+
+/// The `MarshalingType` of a WinRT class, where `2` means the class is agile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarshalingBehaviorAttribute(pub i32);
+
+impl MarshalingBehaviorAttribute {
+    pub fn is_agile(&self) -> bool {
+        self.0 == 2
+    }
+}
+
+impl FromAttribute for MarshalingBehaviorAttribute {
+    const NAME: &'static str = "MarshalingBehaviorAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        find_enum(args).map(Self)
+    }
+}
+
+/// The `ThreadingModel` of a WinRT class.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThreadingAttribute(pub i32);
+
+impl FromAttribute for ThreadingAttribute {
+    const NAME: &'static str = "ThreadingAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        find_enum(args).map(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionAttribute(pub u32);
+
+impl FromAttribute for VersionAttribute {
+    const NAME: &'static str = "VersionAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        find_u32(args).map(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExclusiveToAttribute(pub TypeDef);
+
+impl FromAttribute for ExclusiveToAttribute {
+    const NAME: &'static str = "ExclusiveToAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        find_type_def(args).map(Self)
+    }
+}
+
+/// Win32 array parameters and fields are either of a fixed length, or their length is given by another parameter or field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeArrayInfoAttribute {
+    pub count_const: Option<i32>,
+    pub count_param_index: Option<i16>,
+    pub count_field_name: Option<String>,
+}
+
+impl FromAttribute for NativeArrayInfoAttribute {
+    const NAME: &'static str = "NativeArrayInfoAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        let mut attribute = Self { count_const: None, count_param_index: None, count_field_name: None };
+        for (_, value) in args {
+            match value {
+                Value::I32(value) => attribute.count_const = Some(*value),
+                Value::I16(value) => attribute.count_param_index = Some(*value),
+                Value::String(value) => attribute.count_field_name = Some(value.clone()),
+                _ => {}
+            }
+        }
+        Some(attribute)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemorySizeAttribute {
+    pub bytes_param_index: i16,
+}
+
+impl FromAttribute for MemorySizeAttribute {
+    const NAME: &'static str = "MemorySizeAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        args.iter().find_map(|(_, value)| if let Value::I16(value) = value { Some(Self { bytes_param_index: *value }) } else { None })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeBitfieldAttribute {
+    pub name: String,
+    pub offset: i64,
+    pub length: i64,
+}
+
+impl FromAttribute for NativeBitfieldAttribute {
+    const NAME: &'static str = "NativeBitfieldAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        match args {
+            [(_, Value::String(name)), (_, Value::I64(offset)), (_, Value::I64(length))] => Some(Self { name: name.clone(), offset: *offset, length: *length }),
+            _ => None,
+        }
+    }
+}
+
+/// A WinRT activation factory. Without a `factory` interface, the class has a default constructor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActivatableAttribute {
+    pub factory: Option<TypeDef>,
+    pub version: Option<u32>,
+    pub contract: Option<String>,
+}
+
+impl FromAttribute for ActivatableAttribute {
+    const NAME: &'static str = "ActivatableAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        Some(Self { factory: find_type_def(args), version: find_u32(args), contract: find_string(args) })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaticAttribute {
+    pub statics: TypeDef,
+    pub version: Option<u32>,
+    pub contract: Option<String>,
+}
+
+impl FromAttribute for StaticAttribute {
+    const NAME: &'static str = "StaticAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        Some(Self { statics: find_type_def(args)?, version: find_u32(args), contract: find_string(args) })
+    }
+}
+
+/// A WinRT composition factory, where `kind` is the `CompositionType` (`1` for protected and `2` for public).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComposableAttribute {
+    pub factory: TypeDef,
+    pub kind: i32,
+    pub version: Option<u32>,
+    pub contract: Option<String>,
+}
+
+impl FromAttribute for ComposableAttribute {
+    const NAME: &'static str = "ComposableAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        Some(Self { factory: find_type_def(args)?, kind: find_enum(args)?, version: find_u32(args), contract: find_string(args) })
+    }
+}
+
+/// A WinRT deprecation, where `kind` is the `DeprecationType` (`0` to deprecate and `1` to remove).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedAttribute {
+    pub message: String,
+    pub kind: i32,
+    pub version: Option<u32>,
+    pub contract: Option<String>,
+}
+
+impl FromAttribute for DeprecatedAttribute {
+    const NAME: &'static str = "DeprecatedAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        let mut strings = args.iter().filter_map(|(_, value)| if let Value::String(value) = value { Some(value.clone()) } else { None });
+        Some(Self { message: strings.next()?, kind: find_enum(args)?, version: find_u32(args), contract: strings.next() })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractVersionAttribute {
+    pub contract: Option<String>,
+    pub version: u32,
+}
+
+impl FromAttribute for ContractVersionAttribute {
+    const NAME: &'static str = "ContractVersionAttribute";
+    fn from_args(args: &[(String, Value)]) -> Option<Self> {
+        Some(Self { contract: find_string(args), version: find_u32(args)? })
+    }
+}
+
+fn find_string(args: &[(String, Value)]) -> Option<String> {
+    args.iter().find_map(|(_, value)| if let Value::String(value) = value { Some(value.clone()) } else { None })
+}
+
+fn find_u32(args: &[(String, Value)]) -> Option<u32> {
+    args.iter().find_map(|(_, value)| if let Value::U32(value) = value { Some(*value) } else { None })
+}
+
+fn find_type_def(args: &[(String, Value)]) -> Option<TypeDef> {
+    args.iter().find_map(|(_, value)| if let Value::TypeDef(value) = value { Some(*value) } else { None })
+}
+
+fn find_enum(args: &[(String, Value)]) -> Option<i32> {
+    args.iter().find_map(|(_, value)| match value {
+        Value::EnumDef(_, value) | Value::EnumRef(_, value) => match **value {
+            Value::I32(value) => Some(value),
+            Value::U32(value) => Some(value as _),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read() -> File {
+        File::new(std::fs::read("default/Windows.Wdk.winmd").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_typed() {
+        let files = [read()];
+        let reader = Reader::new(&files);
+        let def = reader.get(TypeName::new("Windows.Wdk.System.OfflineRegistry", "ORHKEY")).next().unwrap();
+
+        assert!(reader.attribute::<NativeTypedefAttribute>(reader.type_def_attributes(def)).is_some());
+        assert_eq!(reader.attribute::<RAIIFreeAttribute>(reader.type_def_attributes(def)), Some(RAIIFreeAttribute("ORCloseKey".to_string())));
+        assert_eq!(reader.attribute::<FlagsAttribute>(reader.type_def_attributes(def)), None);
+        assert_eq!(reader.type_def_invalid_values(def), [-1, 0]);
+
+        let (namespace, free) = reader.type_def_free_function(def).unwrap();
+        assert_eq!(namespace, "Windows.Wdk.System.OfflineRegistry");
+        assert_eq!(reader.method_def_name(free), "ORCloseKey");
+    }
+
+    #[test]
+    fn test_known() {
+        let files = [read()];
+        let reader = Reader::new(&files);
+        let def = reader.get(TypeName::new("Windows.Wdk.System.OfflineRegistry", "ORHKEY")).next().unwrap();
+        let attributes: Vec<KnownAttribute> = reader.type_def_attributes(def).map(|attribute| reader.attribute_known(attribute)).collect();
+
+        assert!(attributes.contains(&KnownAttribute::NativeTypedef(NativeTypedefAttribute)));
+        assert!(attributes.contains(&KnownAttribute::InvalidHandleValue(InvalidHandleValueAttribute(-1))));
+        assert!(attributes.contains(&KnownAttribute::RAIIFree(RAIIFreeAttribute("ORCloseKey".to_string()))));
+
+        // Attributes that aren't part of the Win32 or WinRT metadata schema are still decoded generically.
+        let def = reader.get(TypeName::new("Windows.Wdk.Foundation", "PDRIVER_ADD_DEVICE")).next().unwrap();
+        let attribute = reader.type_def_attributes(def).map(|attribute| reader.attribute_known(attribute)).find(|attribute| matches!(attribute, KnownAttribute::Unknown(..))).unwrap();
+        let KnownAttribute::Unknown(name, args) = attribute else { unreachable!() };
+        assert_eq!(name, "UnmanagedFunctionPointerAttribute");
+        assert!(matches!(args[..], [(_, Value::EnumRef(_, _))]));
+    }
+
+    #[test]
+    fn test_documentation() {
+        use crate::model::*;
+
+        let url = "https://learn.microsoft.com/windows/win32/api/test/nf-test-getdata";
+        let mut model = Model::default();
+        let mut def = TypeDef::new("Apis", TypeAttributes::Public | TypeAttributes::Abstract | TypeAttributes::Sealed);
+        let mut method = Method::new("GetData", MethodAttributes::Public | MethodAttributes::Static, writer::Type::I32);
+        method.attributes.push(Attribute::new("Windows.Win32.Foundation.Metadata", "DocumentationAttribute", vec![writer::Value::String(url.to_string())]));
+        def.methods.push(method);
+        model.namespace_mut("Test").types.push(def);
+
+        let files = [File::new(model.write("Test.winmd")).unwrap()];
+        let reader = Reader::new(&files);
+        let def = reader.get(TypeName::new("Test", "Apis")).next().unwrap();
+        let method = reader.type_def_methods(def).next().unwrap();
+
+        assert_eq!(reader.method_def_documentation(method).as_deref(), Some(url));
+        assert_eq!(reader.type_def_documentation(def), None);
+    }
+
+    // Handle types whose free functions are defined in one or more namespaces.
+    fn free_function_file() -> File {
+        use crate::model::*;
+
+        let mut model = Model::default();
+
+        for (namespace, functions) in [("Test.Handles", &["CloseA"][..]), ("Windows.Win32.Foundation", &["CloseA", "CloseB"]), ("Test.Other", &["CloseB", "CloseC", "CloseD"]), ("Test.More", &["CloseD"])] {
+            let mut def = TypeDef::new("Apis", TypeAttributes::Public | TypeAttributes::Abstract | TypeAttributes::Sealed);
+
+            for name in functions {
+                let mut method = Method::new(name, MethodAttributes::Public | MethodAttributes::Static | MethodAttributes::PinvokeImpl, writer::Type::I32);
+                method.import = Some(Import { module: "test.dll".to_string(), name: name.to_string(), flags: PInvokeAttributes::default() });
+                def.methods.push(method);
+            }
+
+            model.namespace_mut(namespace).types.push(def);
+        }
+
+        for name in ["A", "B", "C", "D"] {
+            let mut def = TypeDef::new(&format!("HANDLE_{name}"), TypeAttributes::Public | TypeAttributes::Sealed);
+            def.attributes.push(Attribute::new("Windows.Win32.Foundation.Metadata", "RAIIFreeAttribute", vec![writer::Value::String(format!("Close{name}"))]));
+            model.namespace_mut("Test.Handles").types.push(def);
+        }
+
+        File::new(model.write("Test.winmd")).unwrap()
+    }
+
+    fn free_function_namespace<'a>(reader: &'a Reader, name: &str) -> Option<&'a str> {
+        reader.type_def_free_function(reader.get(TypeName::new("Test.Handles", name)).next().unwrap()).map(|(namespace, _)| namespace)
+    }
+
+    #[test]
+    fn test_free_function() {
+        let files = [free_function_file()];
+        let reader = &Reader::new(&files);
+
+        // The namespace of the handle type comes first, then `Windows.Win32.Foundation`, then any other namespace.
+        assert_eq!(free_function_namespace(reader, "HANDLE_A"), Some("Test.Handles"));
+        assert_eq!(free_function_namespace(reader, "HANDLE_B"), Some("Windows.Win32.Foundation"));
+        assert_eq!(free_function_namespace(reader, "HANDLE_C"), Some("Test.Other"));
+    }
+
+    #[test]
+    fn test_free_function_ambiguous() {
+        let files = [free_function_file()];
+        assert_eq!(free_function_namespace(&Reader::new(&files), "HANDLE_D"), None);
+    }
+}
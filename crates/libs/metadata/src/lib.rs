@@ -4,24 +4,38 @@ pub mod imp;
 mod attributes;
 mod blob;
 mod codes;
+mod diff;
 mod file;
 mod filter;
 mod guid;
+mod json;
+mod known_attribute;
+pub mod model;
+mod parse_error;
 mod row;
 mod r#type;
 mod type_name;
+mod validate;
+pub mod writer;
 
 pub use attributes::*;
 pub use blob::Blob;
 pub use codes::*;
+pub use diff::{diff, Change, ChangeKind, Diff};
 pub use file::*;
 pub use filter::Filter;
 pub use guid::GUID;
 use imp::*;
+use json::Json;
+pub use json::{to_json, JSON_SCHEMA_VERSION};
+pub use known_attribute::*;
+pub use parse_error::ParseError;
 pub use r#type::Type;
 use row::Row;
 use std::collections::*;
+use std::sync::OnceLock;
 pub use type_name::TypeName;
+pub use validate::{validate, Diagnostic};
 
 macro_rules! tables {
     ($($name:ident,)*) => ($(
@@ -34,16 +48,19 @@ tables! {
     Attribute,
     ClassLayout,
     Constant,
+    Event,
     Field,
     GenericParam,
     ImplMap,
     InterfaceImpl,
     MemberRef,
     MethodDef,
+    MethodSemantics,
     Module,
     ModuleRef,
     AssemblyRef,
     Param,
+    Property,
     TypeDef,
     TypeRef,
     TypeSpec,
@@ -120,7 +137,7 @@ pub enum TypeKind {
     Delegate,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     Bool(bool),
     U8(u8),
@@ -185,33 +202,92 @@ impl<'a> Cfg<'a> {
 
 pub struct Reader<'a> {
     files: &'a [File],
-    types: BTreeMap<&'a str, BTreeMap<&'a str, Vec<TypeDef>>>,
-    nested: HashMap<TypeDef, BTreeMap<&'a str, TypeDef>>,
+    // The indexes are built on first use so that creating a `Reader` is cheap when only a few types are needed. They
+    // only hold rows, rather than names borrowed from the files, so that `Reader` remains covariant over `'a`.
+    namespaces: OnceLock<BTreeMap<String, NamespaceIndex>>,
+    nested: OnceLock<HashMap<TypeDef, Vec<TypeDef>>>,
+    functions: OnceLock<HashMap<String, Vec<(String, MethodDef)>>>,
+}
+
+// The non-nested TypeDefs in a namespace. They are only sorted by name once the namespace is first searched so that a
+// lookup doesn't pay to sort every other namespace. The sort is stable so that definitions with the same name, such as
+// architecture-specific structs, remain in file and row order.
+struct NamespaceIndex {
+    rows: Vec<TypeDef>,
+    sorted: OnceLock<Vec<TypeDef>>,
 }
 
 impl<'a> Reader<'a> {
     pub fn new(files: &'a [File]) -> Self {
-        let mut types = BTreeMap::<&'a str, BTreeMap<&'a str, Vec<TypeDef>>>::new();
-        let mut nested = HashMap::<TypeDef, BTreeMap<&'a str, TypeDef>>::new();
-        for (file_index, file) in files.iter().enumerate() {
-            for row in 0..file.tables[TABLE_TYPEDEF].len {
-                let key = Row::new(row, TABLE_TYPEDEF, file_index);
-                let namespace = file.str(key.row as _, key.table as _, 2);
-                if namespace.is_empty() {
-                    continue;
+        Self { files, namespaces: OnceLock::new(), nested: OnceLock::new(), functions: OnceLock::new() }
+    }
+
+    //
+    // Lazily built indexes
+    //
+
+    // Every non-nested TypeDef grouped by namespace. This only reads each namespace name once, leaving the type names
+    // to be compared when a namespace is searched.
+    fn namespaces_index(&self) -> &BTreeMap<String, NamespaceIndex> {
+        self.namespaces.get_or_init(|| {
+            let mut namespaces = BTreeMap::<String, NamespaceIndex>::new();
+            for (file_index, file) in self.files.iter().enumerate() {
+                for row in 0..file.tables[TABLE_TYPEDEF].len {
+                    let namespace = file.str(row, TABLE_TYPEDEF, 2);
+                    if namespace.is_empty() {
+                        continue;
+                    }
+                    let def = TypeDef(Row::new(row, TABLE_TYPEDEF, file_index));
+                    if let Some(index) = namespaces.get_mut(namespace) {
+                        index.rows.push(def);
+                    } else {
+                        namespaces.insert(namespace.to_string(), NamespaceIndex { rows: vec![def], sorted: OnceLock::new() });
+                    }
+                }
+            }
+            namespaces
+        })
+    }
+    fn sorted_types<'b>(&self, index: &'b NamespaceIndex) -> &'b [TypeDef] {
+        index.sorted.get_or_init(|| {
+            let mut types = index.rows.clone();
+            types.sort_by_cached_key(|def| trim_tick(self.type_def_name(*def)));
+            types
+        })
+    }
+    fn namespace_index(&self, namespace: &str) -> &[TypeDef] {
+        self.namespaces_index().get(namespace).map_or(&[], |index| self.sorted_types(index))
+    }
+    // Every non-nested TypeDef sorted by namespace and name.
+    fn types_index(&self) -> impl Iterator<Item = TypeDef> + '_ {
+        self.namespaces_index().values().flat_map(move |index| self.sorted_types(index).iter().copied())
+    }
+    // The nested types of each enclosing type sorted by name.
+    fn nested_index(&self) -> &HashMap<TypeDef, Vec<TypeDef>> {
+        self.nested.get_or_init(|| {
+            let mut nested = HashMap::<TypeDef, Vec<TypeDef>>::new();
+            for (file_index, file) in self.files.iter().enumerate() {
+                for row in 0..file.tables[TABLE_NESTEDCLASS].len {
+                    let inner = Row::new(file.usize(row, TABLE_NESTEDCLASS, 0) - 1, TABLE_TYPEDEF, file_index);
+                    let outer = Row::new(file.usize(row, TABLE_NESTEDCLASS, 1) - 1, TABLE_TYPEDEF, file_index);
+                    nested.entry(TypeDef(outer)).or_default().push(TypeDef(inner));
                 }
-                let name = trim_tick(file.str(key.row as _, key.table as _, 1));
-                types.entry(namespace).or_default().entry(name).or_default().push(TypeDef(key));
             }
-            for row in 0..file.tables[TABLE_NESTEDCLASS].len {
-                let key = Row::new(row, TABLE_NESTEDCLASS, file_index);
-                let inner = Row::new(file.usize(key.row as _, key.table as _, 0) - 1, TABLE_TYPEDEF, file_index);
-                let outer = Row::new(file.usize(key.row as _, key.table as _, 1) - 1, TABLE_TYPEDEF, file_index);
-                let name = file.str(inner.row as _, inner.table as _, 1);
-                nested.entry(TypeDef(outer)).or_default().insert(name, TypeDef(inner));
+            nested.values_mut().for_each(|inner| inner.sort_by_key(|def| self.type_def_name(*def)));
+            nested
+        })
+    }
+    // The functions of every namespace grouped by name, along with the namespace defining them.
+    fn functions_index(&self) -> &HashMap<String, Vec<(String, MethodDef)>> {
+        self.functions.get_or_init(|| {
+            let mut functions = HashMap::<String, Vec<(String, MethodDef)>>::new();
+            for namespace in self.namespaces() {
+                for method in self.namespace_functions(namespace) {
+                    functions.entry(self.method_def_name(method).to_string()).or_default().push((namespace.to_string(), method));
+                }
             }
-        }
-        Self { files, types, nested }
+            functions
+        })
     }
 
     //
@@ -219,24 +295,22 @@ impl<'a> Reader<'a> {
     //
 
     pub fn namespaces(&self) -> impl Iterator<Item = &str> + '_ {
-        self.types.keys().copied()
+        self.namespaces_index().keys().map(|namespace| namespace.as_str())
     }
     pub fn types(&'a self, filter: &'a Filter) -> impl Iterator<Item = TypeDef> + '_ {
-        self.types.iter().filter(move |(namespace, _)| filter.includes_namespace(namespace)).flat_map(move |(_, types)| types.values().flatten().copied().filter(move |def| filter.includes_type(self, *def)))
+        self.namespaces_index().iter().filter(move |(namespace, _)| filter.includes_namespace(namespace)).flat_map(move |(_, index)| self.sorted_types(index).iter().copied().filter(move |def| filter.includes_type(self, *def)))
     }
     pub fn namespace_types(&'a self, namespace: &str, filter: &'a Filter) -> impl Iterator<Item = TypeDef> + '_ {
-        self.types.get(namespace).map(move |types| types.values().flatten().copied().filter(move |ty| filter.includes_type(self, *ty))).into_iter().flatten()
+        self.namespace_index(namespace).iter().copied().filter(move |def| filter.includes_type(self, *def))
     }
     pub fn nested_types(&self, type_def: TypeDef) -> impl Iterator<Item = TypeDef> + '_ {
-        self.nested.get(&type_def).map(|map| map.values().copied()).into_iter().flatten()
+        self.nested_index().get(&type_def).into_iter().flatten().copied()
     }
     pub fn get(&self, type_name: TypeName) -> impl Iterator<Item = TypeDef> + '_ {
-        if let Some(types) = self.types.get(type_name.namespace) {
-            if let Some(definitions) = types.get(type_name.name) {
-                return Some(definitions.iter().copied()).into_iter().flatten();
-            }
-        }
-        None.into_iter().flatten()
+        let types = self.namespace_index(type_name.namespace);
+        let first = types.partition_point(|def| trim_tick(self.type_def_name(*def)) < type_name.name);
+        let last = first + types[first..].partition_point(|def| trim_tick(self.type_def_name(*def)) == type_name.name);
+        types[first..last].iter().copied()
     }
     pub fn namespace_functions(&self, namespace: &str) -> impl Iterator<Item = MethodDef> + '_ {
         self.get(TypeName::new(namespace, "Apis")).flat_map(move |apis| self.type_def_methods(apis)).filter(move |method| {
@@ -285,6 +359,16 @@ impl<'a> Reader<'a> {
         let last = if key.row + 1 < self.files[file].tables[key.table as usize].len as _ { self.row_usize(key.next(), column) - 1 } else { self.files[file].tables[table].len };
         (first..last).map(move |row| Row::new(row, table, file))
     }
+    // The PropertyMap and EventMap tables are not required to be sorted so the map row owned by the given TypeDef is
+    // found with a linear search before returning the run of rows in the target table.
+    fn row_map_list(&self, key: Row, map: usize, table: usize) -> impl Iterator<Item = Row> + '_ {
+        let file = &self.files[key.file as usize];
+        let map = (0..file.tables[map].len).find(|row| file.usize(*row, map, 0) == key.row as usize + 1).map(|row| Row::new(row, map, key.file as _));
+        map.into_iter().flat_map(move |map| self.row_list(map, table, 1))
+    }
+    fn row_semantics(&self, key: Row, association: HasSemantics) -> impl Iterator<Item = MethodSemantics> {
+        self.row_equal_range(key, TABLE_METHODSEMANTICS, 2, association.encode()).map(MethodSemantics)
+    }
     fn row_decode<T: Decode>(&self, key: Row, column: usize) -> T {
         T::decode(key.file as _, self.row_usize(key, column))
     }
@@ -365,6 +449,16 @@ impl<'a> Reader<'a> {
 
         args
     }
+    pub fn attribute_known(&self, row: Attribute) -> KnownAttribute {
+        KnownAttribute::decode(self.attribute_name(row), self.attribute_args(row))
+    }
+    /// Returns the first of the given attributes of type `T`, such as `reader.attribute::<GuidAttribute>(reader.type_def_attributes(row))`.
+    pub fn attribute<T: FromAttribute>(&self, attributes: impl Iterator<Item = Attribute>) -> Option<T> {
+        self.attributes(attributes).next()
+    }
+    pub fn attributes<'b, T: FromAttribute + 'b>(&'b self, attributes: impl Iterator<Item = Attribute> + 'b) -> impl Iterator<Item = T> + 'b {
+        attributes.filter(move |attribute| self.attribute_name(*attribute) == T::NAME).filter_map(move |attribute| T::from_args(&self.attribute_args(attribute)))
+    }
 
     //
     // ClassLayout table queries
@@ -373,6 +467,9 @@ impl<'a> Reader<'a> {
     pub fn class_layout_packing_size(&self, row: ClassLayout) -> usize {
         self.row_usize(row.0, 0)
     }
+    pub fn class_layout_size(&self, row: ClassLayout) -> usize {
+        self.row_usize(row.0, 1)
+    }
 
     //
     // Constant table queries
@@ -400,6 +497,32 @@ impl<'a> Reader<'a> {
         }
     }
 
+    //
+    // Event table queries
+    //
+
+    pub fn event_flags(&self, row: Event) -> EventAttributes {
+        EventAttributes(self.row_usize(row.0, 0) as _)
+    }
+    pub fn event_name(&self, row: Event) -> &str {
+        self.row_str(row.0, 1)
+    }
+    pub fn event_type(&self, row: Event, generics: &[Type]) -> Type {
+        self.type_from_ref(self.row_decode(row.0, 2), None, generics)
+    }
+    pub fn event_attributes(&self, row: Event) -> impl Iterator<Item = Attribute> {
+        self.row_attributes(row.0, HasAttribute::Event(row))
+    }
+    pub fn event_semantics(&self, row: Event) -> impl Iterator<Item = MethodSemantics> {
+        self.row_semantics(row.0, HasSemantics::Event(row))
+    }
+    pub fn event_add(&self, row: Event) -> Option<MethodDef> {
+        self.event_semantics(row).find(|semantics| self.method_semantics_flags(*semantics).contains(MethodSemanticsAttributes::AddOn)).map(|semantics| self.method_semantics_method(semantics))
+    }
+    pub fn event_remove(&self, row: Event) -> Option<MethodDef> {
+        self.event_semantics(row).find(|semantics| self.method_semantics_flags(*semantics).contains(MethodSemanticsAttributes::RemoveOn)).map(|semantics| self.method_semantics_method(semantics))
+    }
+
     //
     // Field table queries
     //
@@ -438,12 +561,7 @@ impl<'a> Reader<'a> {
         self.type_is_copyable(&self.field_type(row, Some(enclosing)))
     }
     pub fn field_guid(&self, row: Field) -> Option<GUID> {
-        for attribute in self.field_attributes(row) {
-            if self.attribute_name(attribute) == "GuidAttribute" {
-                return Some(GUID::from_args(&self.attribute_args(attribute)));
-            }
-        }
-        None
+        self.attribute::<GuidAttribute>(self.field_attributes(row)).map(|attribute| attribute.0)
     }
     pub fn field_cfg(&self, row: Field) -> Cfg {
         let mut cfg = Cfg::default();
@@ -454,22 +572,16 @@ impl<'a> Reader<'a> {
         self.type_cfg_combine(&self.field_type(row, enclosing), cfg)
     }
     pub fn field_is_ansi(&self, row: Field) -> bool {
-        for attribute in self.field_attributes(row) {
-            if self.attribute_name(attribute) == "NativeEncodingAttribute" {
-                if let Some((_, Value::String(encoding))) = self.attribute_args(attribute).get(0) {
-                    if encoding == "ansi" {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
+        self.attributes::<NativeEncodingAttribute>(self.field_attributes(row)).any(|attribute| attribute.0 == "ansi")
     }
 
     //
     // GenericParam table queries
     //
 
+    pub fn generic_param_number(&self, row: GenericParam) -> u16 {
+        self.row_usize(row.0, 0) as _
+    }
     pub fn generic_param_name(&self, row: GenericParam) -> &str {
         self.row_str(row.0, 3)
     }
@@ -580,15 +692,10 @@ impl<'a> Reader<'a> {
         }
     }
     pub fn method_def_static_lib(&self, row: MethodDef) -> Option<String> {
-        for attribute in self.method_def_attributes(row) {
-            if self.attribute_name(attribute) == "StaticLibraryAttribute" {
-                let args = self.attribute_args(attribute);
-                if let Value::String(value) = &args[0].1 {
-                    return Some(value.clone());
-                }
-            }
-        }
-        None
+        self.attribute::<StaticLibraryAttribute>(self.method_def_attributes(row)).map(|attribute| attribute.0)
+    }
+    pub fn method_def_documentation(&self, row: MethodDef) -> Option<String> {
+        self.attribute::<DocumentationAttribute>(self.method_def_attributes(row)).map(|attribute| attribute.0)
     }
     pub fn method_def_impl_map(&self, row: MethodDef) -> Option<ImplMap> {
         self.row_equal_range(row.0, TABLE_IMPLMAP, 1, MemberForwarded::MethodDef(row).encode()).map(ImplMap).next()
@@ -776,11 +883,22 @@ impl<'a> Reader<'a> {
         }
     }
 
+    //
+    // MethodSemantics table queries
+    //
+
+    pub fn method_semantics_flags(&self, row: MethodSemantics) -> MethodSemanticsAttributes {
+        MethodSemanticsAttributes(self.row_usize(row.0, 0) as _)
+    }
+    pub fn method_semantics_method(&self, row: MethodSemantics) -> MethodDef {
+        MethodDef(Row::new(self.row_usize(row.0, 1) - 1, TABLE_METHODDEF, row.0.file as _))
+    }
+
     //
     // ModuleRef table queries
     //
 
-    fn module_ref_name(&self, row: ModuleRef) -> &str {
+    pub fn module_ref_name(&self, row: ModuleRef) -> &str {
         self.row_str(row.0, 0)
     }
 
@@ -803,25 +921,12 @@ impl<'a> Reader<'a> {
     pub fn param_is_com_out_ptr(&self, row: Param) -> bool {
         self.param_attributes(row).any(|attribute| self.attribute_name(attribute) == "ComOutPtrAttribute")
     }
-    fn param_kind(&self, row: Param) -> SignatureParamKind {
+    pub fn param_kind(&self, row: Param) -> SignatureParamKind {
         for attribute in self.param_attributes(row) {
-            match self.attribute_name(attribute) {
-                "NativeArrayInfoAttribute" => {
-                    for (_, value) in self.attribute_args(attribute) {
-                        match value {
-                            Value::I16(value) => return SignatureParamKind::ArrayRelativeLen(value as _),
-                            Value::I32(value) => return SignatureParamKind::ArrayFixed(value as _),
-                            _ => {}
-                        }
-                    }
-                }
-                "MemorySizeAttribute" => {
-                    for (_, value) in self.attribute_args(attribute) {
-                        if let Value::I16(value) = value {
-                            return SignatureParamKind::ArrayRelativeByteLen(value as _);
-                        }
-                    }
-                }
+            match self.attribute_known(attribute) {
+                KnownAttribute::NativeArrayInfo(NativeArrayInfoAttribute { count_param_index: Some(index), .. }) => return SignatureParamKind::ArrayRelativeLen(index as _),
+                KnownAttribute::NativeArrayInfo(NativeArrayInfoAttribute { count_const: Some(len), .. }) => return SignatureParamKind::ArrayFixed(len as _),
+                KnownAttribute::MemorySize(attribute) => return SignatureParamKind::ArrayRelativeByteLen(attribute.bytes_param_index as _),
                 _ => {}
             }
         }
@@ -834,21 +939,41 @@ impl<'a> Reader<'a> {
         self.param_attributes(row).any(|attribute| self.attribute_name(attribute) == "ReservedAttribute")
     }
     pub fn param_free_with(&self, row: Param) -> Option<String> {
-        for attribute in self.param_attributes(row) {
-            if self.attribute_name(attribute) == "FreeWithAttribute" {
-                for (_, arg) in self.attribute_args(attribute) {
-                    if let Value::String(name) = arg {
-                        return Some(name);
-                    }
-                }
-            }
-        }
-        None
+        self.attribute::<FreeWithAttribute>(self.param_attributes(row)).map(|attribute| attribute.0)
     }
     pub fn param_is_const(&self, row: Param) -> bool {
         self.param_attributes(row).any(|attribute| self.attribute_name(attribute) == "ConstAttribute")
     }
 
+    //
+    // Property table queries
+    //
+
+    pub fn property_flags(&self, row: Property) -> PropertyAttributes {
+        PropertyAttributes(self.row_usize(row.0, 0) as _)
+    }
+    pub fn property_name(&self, row: Property) -> &str {
+        self.row_str(row.0, 1)
+    }
+    pub fn property_type(&self, row: Property, generics: &[Type]) -> Type {
+        let mut blob = self.row_blob(row.0, 2);
+        let _call_flags = blob.read_usize();
+        let _param_count = blob.read_usize();
+        self.type_from_blob(&mut blob, None, generics)
+    }
+    pub fn property_attributes(&self, row: Property) -> impl Iterator<Item = Attribute> {
+        self.row_attributes(row.0, HasAttribute::Property(row))
+    }
+    pub fn property_semantics(&self, row: Property) -> impl Iterator<Item = MethodSemantics> {
+        self.row_semantics(row.0, HasSemantics::Property(row))
+    }
+    pub fn property_getter(&self, row: Property) -> Option<MethodDef> {
+        self.property_semantics(row).find(|semantics| self.method_semantics_flags(*semantics).contains(MethodSemanticsAttributes::Getter)).map(|semantics| self.method_semantics_method(semantics))
+    }
+    pub fn property_setter(&self, row: Property) -> Option<MethodDef> {
+        self.property_semantics(row).find(|semantics| self.method_semantics_flags(*semantics).contains(MethodSemanticsAttributes::Setter)).map(|semantics| self.method_semantics_method(semantics))
+    }
+
     //
     // TypeDef table queries
     //
@@ -877,6 +1002,12 @@ impl<'a> Reader<'a> {
     pub fn type_def_methods(&self, row: TypeDef) -> impl Iterator<Item = MethodDef> {
         self.row_list(row.0, TABLE_METHODDEF, 5).map(MethodDef)
     }
+    pub fn type_def_properties(&self, row: TypeDef) -> impl Iterator<Item = Property> + '_ {
+        self.row_map_list(row.0, TABLE_PROPERTYMAP, TABLE_PROPERTY).map(Property)
+    }
+    pub fn type_def_events(&self, row: TypeDef) -> impl Iterator<Item = Event> + '_ {
+        self.row_map_list(row.0, TABLE_EVENTMAP, TABLE_EVENT).map(Event)
+    }
     pub fn type_def_attributes(&self, row: TypeDef) -> impl Iterator<Item = Attribute> {
         self.row_attributes(row.0, HasAttribute::TypeDef(row))
     }
@@ -946,16 +1077,7 @@ impl<'a> Reader<'a> {
         !self.type_def_flags(row).contains(TypeAttributes::WindowsRuntime) && self.type_def_kind(row) == TypeKind::Delegate
     }
     pub fn type_def_has_default_constructor(&self, row: TypeDef) -> bool {
-        for attribute in self.type_def_attributes(row) {
-            if self.attribute_name(attribute) == "ActivatableAttribute" {
-                if self.attribute_args(attribute).iter().any(|arg| matches!(arg.1, Value::TypeDef(_))) {
-                    continue;
-                } else {
-                    return true;
-                }
-            }
-        }
-        false
+        self.attributes::<ActivatableAttribute>(self.type_def_attributes(row)).any(|attribute| attribute.factory.is_none())
     }
     // TODO: consider removing all the expects and just return Option<T> and let the caller expect it
     // that way the metadata reader is a little more schema-agnostic...
@@ -1085,12 +1207,10 @@ impl<'a> Reader<'a> {
         }
     }
     pub fn type_def_guid(&self, row: TypeDef) -> Option<GUID> {
-        for attribute in self.type_def_attributes(row) {
-            if self.attribute_name(attribute) == "GuidAttribute" {
-                return Some(GUID::from_args(&self.attribute_args(attribute)));
-            }
-        }
-        None
+        self.attribute::<GuidAttribute>(self.type_def_attributes(row)).map(|attribute| attribute.0)
+    }
+    pub fn type_def_documentation(&self, row: TypeDef) -> Option<String> {
+        self.attribute::<DocumentationAttribute>(self.type_def_attributes(row)).map(|attribute| attribute.0)
     }
     pub fn type_def_bases(&self, mut row: TypeDef) -> Vec<TypeDef> {
         let mut bases = Vec::new();
@@ -1112,40 +1232,38 @@ impl<'a> Reader<'a> {
     }
     pub fn type_def_is_agile(&self, row: TypeDef) -> bool {
         for attribute in self.type_def_attributes(row) {
-            match self.attribute_name(attribute) {
-                "AgileAttribute" => return true,
-                "MarshalingBehaviorAttribute" => {
-                    if let Some((_, Value::EnumDef(_, value))) = self.attribute_args(attribute).get(0) {
-                        if let Value::I32(2) = **value {
-                            return true;
-                        }
-                    }
-                }
+            match self.attribute_known(attribute) {
+                KnownAttribute::Agile(_) => return true,
+                KnownAttribute::MarshalingBehavior(attribute) if attribute.is_agile() => return true,
                 _ => {}
             }
         }
         matches!(self.type_def_type_name(row), TypeName::IAsyncAction | TypeName::IAsyncActionWithProgress | TypeName::IAsyncOperation | TypeName::IAsyncOperationWithProgress)
     }
     pub fn type_def_invalid_values(&self, row: TypeDef) -> Vec<i64> {
-        let mut values = Vec::new();
-        for attribute in self.type_def_attributes(row) {
-            if self.attribute_name(attribute) == "InvalidHandleValueAttribute" {
-                if let Some((_, Value::I64(value))) = self.attribute_args(attribute).get(0) {
-                    values.push(*value);
-                }
-            }
-        }
-        values
+        self.attributes::<InvalidHandleValueAttribute>(self.type_def_attributes(row)).map(|attribute| attribute.0).collect()
     }
     pub fn type_def_usable_for(&self, row: TypeDef) -> Option<TypeDef> {
-        for attribute in self.type_def_attributes(row) {
-            if self.attribute_name(attribute) == "AlsoUsableForAttribute" {
-                if let Some((_, Value::String(name))) = self.attribute_args(attribute).get(0) {
-                    return self.get(TypeName::new(self.type_def_namespace(row), name.as_str())).next();
-                }
+        let attribute = self.attribute::<AlsoUsableForAttribute>(self.type_def_attributes(row))?;
+        self.get(TypeName::new(self.type_def_namespace(row), &attribute.0)).next()
+    }
+    /// Returns the function named by the `RAIIFreeAttribute` of a handle type along with its namespace. The function is
+    /// looked up in the namespace of the handle type and then in `Windows.Win32.Foundation`, which defines general
+    /// purpose functions such as `CloseHandle`, before falling back to any other namespace that uniquely defines it.
+    /// Returns `None` if the function isn't defined or is defined by more than one other namespace.
+    pub fn type_def_free_function(&self, row: TypeDef) -> Option<(&str, MethodDef)> {
+        let name = self.attribute::<RAIIFreeAttribute>(self.type_def_attributes(row))?.0;
+
+        for namespace in [self.type_def_namespace(row), "Windows.Win32.Foundation"] {
+            if let Some(method) = self.namespace_functions(namespace).find(|method| self.method_def_name(*method) == name) {
+                return Some((namespace, method));
             }
         }
-        None
+
+        match self.functions_index().get(&name)?.as_slice() {
+            [(namespace, method)] => Some((namespace, *method)),
+            _ => None,
+        }
     }
     pub fn type_def_is_nullable(&self, row: TypeDef) -> bool {
         match self.type_def_kind(row) {
@@ -1157,23 +1275,17 @@ impl<'a> Reader<'a> {
         }
     }
     pub fn type_def_can_implement(&self, row: TypeDef) -> bool {
-        for attribute in self.type_def_attributes(row) {
-            if self.attribute_name(attribute) == "ExclusiveToAttribute" {
-                for (_, arg) in self.attribute_args(attribute) {
-                    if let Value::TypeDef(def) = arg {
-                        for child in self.type_def_interfaces(def, &[]) {
-                            if child.kind == InterfaceKind::Overridable {
-                                if let Type::TypeDef(def, _) = child.ty {
-                                    if self.type_def_type_name(def) == self.type_def_type_name(row) {
-                                        return true;
-                                    }
-                                }
-                            }
+        if let Some(ExclusiveToAttribute(def)) = self.attribute(self.type_def_attributes(row)) {
+            for child in self.type_def_interfaces(def, &[]) {
+                if child.kind == InterfaceKind::Overridable {
+                    if let Type::TypeDef(def, _) = child.ty {
+                        if self.type_def_type_name(def) == self.type_def_type_name(row) {
+                            return true;
                         }
                     }
                 }
-                return false;
             }
+            return false;
         }
         true
     }
@@ -1465,18 +1577,16 @@ impl<'a> Reader<'a> {
     fn cfg_add_attributes(&self, cfg: &mut Cfg, attributes: impl Iterator<Item = Attribute>) {
         for attribute in attributes {
             match self.attribute_name(attribute) {
-                "SupportedArchitectureAttribute" => {
-                    if let Some((_, Value::EnumDef(_, value))) = self.attribute_args(attribute).get(0) {
-                        if let Value::I32(value) = **value {
-                            if value & 1 == 1 {
-                                cfg.arches.insert("x86");
-                            }
-                            if value & 2 == 2 {
-                                cfg.arches.insert("x86_64");
-                            }
-                            if value & 4 == 4 {
-                                cfg.arches.insert("aarch64");
-                            }
+                SupportedArchitectureAttribute::NAME => {
+                    if let Some(arches) = SupportedArchitectureAttribute::from_args(&self.attribute_args(attribute)) {
+                        if arches.contains(SupportedArchitectureAttribute::X86) {
+                            cfg.arches.insert("x86");
+                        }
+                        if arches.contains(SupportedArchitectureAttribute::X64) {
+                            cfg.arches.insert("x86_64");
+                        }
+                        if arches.contains(SupportedArchitectureAttribute::ARM64) {
+                            cfg.arches.insert("aarch64");
                         }
                     }
                 }
@@ -1493,7 +1603,9 @@ impl<'a> Reader<'a> {
             return;
         }
 
-        let Type::TypeDef(def, generics) = &ty else { return; };
+        let Type::TypeDef(def, generics) = &ty else {
+            return;
+        };
         let def = *def;
 
         // Ensure that we collect all the typedefs of the same name. We need to
@@ -1719,8 +1831,8 @@ impl<'a> Reader<'a> {
 
         if let Some(outer) = enclosing {
             if full_name.namespace.is_empty() {
-                let nested = &self.nested[&outer];
-                let Some(inner) = nested.get(full_name.name) else {
+                let nested = &self.nested_index()[&outer];
+                let Some(inner) = nested.iter().find(|inner| self.type_def_name(**inner) == full_name.name) else {
                     panic!("Nested type not found: {}.{}", self.type_def_type_name(outer), full_name.name);
                 };
                 return Type::TypeDef(*inner, Vec::new());
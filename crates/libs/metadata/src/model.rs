@@ -0,0 +1,863 @@
+//! An owned and mutable model of metadata. A `Model` can be loaded from a `Reader`, edited or built up from scratch, and
+//! then written out as a winmd file without having to deal with tables, heaps or coded indexes.
+//!
+//! Types are referenced by name using `writer::Type::TypeRef`. A nested type is referenced by a `TypeName` whose
+//! namespace is the full name of the enclosing type, such as `Windows.Wdk.Foundation.DEVICE_OBJECT` for the
+//! `_Queue_e__Union` type nested within it.
+
+use crate::writer::{self, Type, TypeName, Value, Writer};
+use crate::{EventAttributes, FieldAttributes, Filter, MethodAttributes, MethodCallAttributes, MethodImplAttributes, MethodSemanticsAttributes, PInvokeAttributes, ParamAttributes, PropertyAttributes, Reader, TypeAttributes};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Model {
+    pub namespaces: Vec<Namespace>,
+    /// The assembly that resolves references to types in each namespace not defined by the model. Namespaces not
+    /// listed here are resolved by an assembly with the same name as the namespace.
+    pub references: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Namespace {
+    pub name: String,
+    pub types: Vec<TypeDef>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypeDef {
+    pub name: String,
+    pub flags: TypeAttributes,
+    pub extends: Option<TypeName>,
+    /// The names of the generic parameters, in order.
+    pub generics: Vec<String>,
+    pub class_layout: Option<ClassLayout>,
+    pub fields: Vec<Field>,
+    pub methods: Vec<Method>,
+    pub properties: Vec<Property>,
+    pub events: Vec<Event>,
+    pub interfaces: Vec<InterfaceImpl>,
+    pub attributes: Vec<Attribute>,
+    pub nested: Vec<TypeDef>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClassLayout {
+    pub packing_size: u16,
+    pub class_size: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub flags: FieldAttributes,
+    pub ty: Type,
+    pub value: Option<Value>,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Method {
+    pub name: String,
+    pub flags: MethodAttributes,
+    pub impl_flags: MethodImplAttributes,
+    pub call_flags: MethodCallAttributes,
+    pub return_type: Type,
+    /// Describes the return value, when it has a name, flags or attributes of its own.
+    pub return_param: Option<ReturnParam>,
+    pub params: Vec<Param>,
+    pub import: Option<Import>,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub flags: ParamAttributes,
+    /// The type of the parameter. Output parameters that aren't pointers or arrays are written by reference.
+    pub ty: Type,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReturnParam {
+    pub name: String,
+    pub flags: ParamAttributes,
+    pub attributes: Vec<Attribute>,
+}
+
+/// The function imported by a method, for methods of the `Apis` class of a Win32 namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Import {
+    pub module: String,
+    pub name: String,
+    pub flags: PInvokeAttributes,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub flags: PropertyAttributes,
+    pub ty: Type,
+    pub semantics: Vec<Semantics>,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    pub name: String,
+    pub flags: EventAttributes,
+    pub ty: Type,
+    pub semantics: Vec<Semantics>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Associates a property or event with one of its accessor methods.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Semantics {
+    pub flags: MethodSemanticsAttributes,
+    /// The position of the accessor within the `methods` of the enclosing type.
+    pub method: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterfaceImpl {
+    pub ty: Type,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Attribute {
+    pub namespace: String,
+    pub name: String,
+    /// The fixed arguments passed to the attribute's constructor.
+    pub args: Vec<Value>,
+    /// The named arguments, which set fields of the attribute.
+    pub named_args: Vec<(String, Value)>,
+}
+
+impl Model {
+    /// Loads the types included by `filter`.
+    pub fn from_reader(reader: &Reader, filter: &Filter) -> Self {
+        let mut model = Self::default();
+
+        // Types are returned sorted by namespace.
+        for def in reader.types(filter) {
+            let namespace = reader.type_def_namespace(def);
+
+            if !matches!(model.namespaces.last(), Some(last) if last.name == namespace) {
+                model.namespaces.push(Namespace::new(namespace));
+            }
+
+            model.namespaces.last_mut().unwrap().types.push(TypeDef::from_reader(reader, def));
+        }
+
+        model
+    }
+
+    /// Returns the namespace with the given name, adding it if necessary.
+    pub fn namespace_mut(&mut self, name: &str) -> &mut Namespace {
+        if let Some(index) = self.namespaces.iter().position(|namespace| namespace.name == name) {
+            &mut self.namespaces[index]
+        } else {
+            self.namespaces.push(Namespace::new(name));
+            self.namespaces.last_mut().unwrap()
+        }
+    }
+
+    pub fn type_def_mut(&mut self, namespace: &str, name: &str) -> Option<&mut TypeDef> {
+        self.namespaces.iter_mut().filter(|candidate| candidate.name == namespace).flat_map(|namespace| namespace.types.iter_mut()).find(|def| def.name == name)
+    }
+
+    /// Renames a type defined by the model, within its namespace, along with every reference to it. Returns `false` if
+    /// the model doesn't define the type. Generic types may be named with or without their arity, such as `IVector` or
+    /// `` IVector`1 ``, and keep their arity when renamed.
+    pub fn rename_type(&mut self, namespace: &str, name: &str, new_name: &str) -> bool {
+        let Some(def) = self.namespaces.iter_mut().filter(|candidate| candidate.name == namespace).flat_map(|namespace| namespace.types.iter_mut()).find(|def| crate::trim_tick(&def.name) == crate::trim_tick(name)) else {
+            return false;
+        };
+
+        let arity = &def.name[crate::trim_tick(&def.name).len()..];
+        let new_name = format!("{}{arity}", crate::trim_tick(new_name));
+        let name = std::mem::replace(&mut def.name, new_name.clone());
+        let (name, new_name) = (name.as_str(), new_name.as_str());
+
+        // Types nested within the renamed type are referenced by a namespace starting with its full name.
+        let full_name = format!("{namespace}.{name}");
+        let new_full_name = format!("{namespace}.{new_name}");
+
+        let mut rename = |type_name: &mut TypeName| {
+            if type_name.namespace == namespace && type_name.name == name {
+                type_name.name = new_name.to_string();
+            } else if let Some(rest) = type_name.namespace.strip_prefix(&full_name) {
+                if rest.is_empty() || rest.starts_with('.') {
+                    type_name.namespace = format!("{new_full_name}{rest}");
+                }
+            }
+        };
+
+        for namespace in &mut self.namespaces {
+            for def in &mut namespace.types {
+                def.visit_type_names(&mut rename);
+            }
+        }
+
+        true
+    }
+
+    /// Writes the model as a winmd file. The `name` is used for the module and, without its extension, the assembly.
+    pub fn write(&self, name: &str) -> Vec<u8> {
+        let mut writer = Writer::new(name);
+
+        for (namespace, assembly) in &self.references {
+            let scope = writer.insert_assembly_ref(assembly);
+            writer.scopes.insert(namespace.clone(), scope);
+        }
+
+        // Types defined by the model are resolved by the current module so that the file is self-contained.
+        for namespace in &self.namespaces {
+            writer.scopes.insert(namespace.name.clone(), writer::ResolutionScope::Module(0).encode());
+        }
+
+        // Nested types are referenced by row rather than by name, and may be referenced before they are written, so
+        // their rows are assigned up front. Types are written depth first so that nested types follow their enclosing
+        // type.
+        let mut rows = HashMap::new();
+        let mut next = writer.tables.TypeDef.len() as u32;
+
+        for namespace in &self.namespaces {
+            for def in &namespace.types {
+                collect_nested_rows(&format!("{}.{}", namespace.name, def.name), def, &mut next, &mut rows);
+            }
+        }
+
+        for namespace in &self.namespaces {
+            for def in &namespace.types {
+                def.write(&mut writer, &rows, &namespace.name, None);
+            }
+        }
+
+        writer.into_stream()
+    }
+}
+
+impl Namespace {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), types: vec![] }
+    }
+}
+
+impl TypeDef {
+    pub fn new(name: &str, flags: TypeAttributes) -> Self {
+        Self { name: name.to_string(), flags, ..Default::default() }
+    }
+
+    fn visit_type_names(&mut self, f: &mut dyn FnMut(&mut TypeName)) {
+        if let Some(extends) = &mut self.extends {
+            visit_type_name(extends, f);
+        }
+
+        for field in &mut self.fields {
+            visit_type(&mut field.ty, f);
+            visit_attributes(&mut field.attributes, f);
+        }
+
+        for method in &mut self.methods {
+            visit_type(&mut method.return_type, f);
+
+            if let Some(param) = &mut method.return_param {
+                visit_attributes(&mut param.attributes, f);
+            }
+
+            for param in &mut method.params {
+                visit_type(&mut param.ty, f);
+                visit_attributes(&mut param.attributes, f);
+            }
+
+            visit_attributes(&mut method.attributes, f);
+        }
+
+        for property in &mut self.properties {
+            visit_type(&mut property.ty, f);
+            visit_attributes(&mut property.attributes, f);
+        }
+
+        for event in &mut self.events {
+            visit_type(&mut event.ty, f);
+            visit_attributes(&mut event.attributes, f);
+        }
+
+        for interface in &mut self.interfaces {
+            visit_type(&mut interface.ty, f);
+            visit_attributes(&mut interface.attributes, f);
+        }
+
+        visit_attributes(&mut self.attributes, f);
+
+        for nested in &mut self.nested {
+            nested.visit_type_names(f);
+        }
+    }
+
+    fn from_reader(reader: &Reader, def: crate::TypeDef) -> Self {
+        let generics: Vec<crate::Type> = reader.type_def_generics(def).collect();
+
+        let generic_names = generics
+            .iter()
+            .map(|generic| match generic {
+                crate::Type::GenericParam(param) => reader.generic_param_name(*param).to_string(),
+                rest => unimplemented!("{rest:?}"),
+            })
+            .collect();
+
+        let methods: Vec<crate::MethodDef> = reader.type_def_methods(def).collect();
+        let semantics = |semantics: crate::MethodSemantics| Semantics {
+            flags: reader.method_semantics_flags(semantics),
+            method: methods.iter().position(|method| *method == reader.method_semantics_method(semantics)).unwrap(),
+        };
+
+        Self {
+            name: reader.type_def_name(def).to_string(),
+            flags: reader.type_def_flags(def),
+            extends: reader.type_def_extends(def).map(|extends| TypeName { namespace: extends.namespace.to_string(), name: extends.name.to_string(), generics: vec![] }),
+            generics: generic_names,
+            class_layout: reader.type_def_class_layout(def).map(|layout| ClassLayout { packing_size: reader.class_layout_packing_size(layout) as _, class_size: reader.class_layout_size(layout) as _ }),
+            fields: reader
+                .type_def_fields(def)
+                .map(|field| Field {
+                    name: reader.field_name(field).to_string(),
+                    flags: reader.field_flags(field),
+                    ty: load_type(reader, &reader.field_type(field, Some(def))),
+                    value: reader.field_constant(field).map(|constant| load_value(reader, &reader.constant_value(constant))),
+                    attributes: load_attributes(reader, reader.field_attributes(field)),
+                })
+                .collect(),
+            methods: methods.iter().map(|method| Method::from_reader(reader, *method, &generics)).collect(),
+            properties: reader
+                .type_def_properties(def)
+                .map(|property| Property {
+                    name: reader.property_name(property).to_string(),
+                    flags: reader.property_flags(property),
+                    ty: load_type(reader, &reader.property_type(property, &generics)),
+                    semantics: reader.property_semantics(property).map(semantics).collect(),
+                    attributes: load_attributes(reader, reader.property_attributes(property)),
+                })
+                .collect(),
+            events: reader
+                .type_def_events(def)
+                .map(|event| Event {
+                    name: reader.event_name(event).to_string(),
+                    flags: reader.event_flags(event),
+                    ty: load_type(reader, &reader.event_type(event, &generics)),
+                    semantics: reader.event_semantics(event).map(semantics).collect(),
+                    attributes: load_attributes(reader, reader.event_attributes(event)),
+                })
+                .collect(),
+            interfaces: reader
+                .type_def_interface_impls(def)
+                .map(|interface_impl| InterfaceImpl {
+                    ty: load_type(reader, &reader.interface_impl_type(interface_impl, &generics).ty),
+                    attributes: load_attributes(reader, reader.interface_impl_attributes(interface_impl)),
+                })
+                .collect(),
+            attributes: load_attributes(reader, reader.type_def_attributes(def)),
+            nested: reader.nested_types(def).map(|nested| Self::from_reader(reader, nested)).collect(),
+        }
+    }
+
+    fn write(&self, writer: &mut Writer, rows: &HashMap<String, u32>, namespace: &str, enclosing: Option<u32>) {
+        let extends = if let Some(extends) = &self.extends { writer.insert_type_ref(&extends.namespace, &extends.name) } else { 0 };
+        let type_def = writer.tables.TypeDef.len() as u32;
+
+        writer.tables.TypeDef.push(writer::TypeDef {
+            Extends: extends,
+            FieldList: writer.tables.Field.len() as _,
+            Flags: self.flags.0,
+            MethodList: writer.tables.MethodDef.len() as _,
+            TypeName: writer.strings.insert(&self.name),
+            TypeNamespace: writer.strings.insert(namespace),
+        });
+
+        for (number, name) in self.generics.iter().enumerate() {
+            writer.tables.GenericParam.push(writer::GenericParam { Number: number as _, Flags: 0, Owner: writer::TypeOrMethodDef::TypeDef(type_def).encode(), Name: writer.strings.insert(name) });
+        }
+
+        if let Some(enclosing) = enclosing {
+            writer.tables.NestedClass.push(writer::NestedClass { NestedClass: type_def, EnclosingClass: enclosing });
+        }
+
+        if let Some(layout) = &self.class_layout {
+            writer.tables.ClassLayout.push(writer::ClassLayout { PackingSize: layout.packing_size, ClassSize: layout.class_size, Parent: type_def });
+        }
+
+        for field in &self.fields {
+            let signature = writer.insert_field_sig(&resolve_type(rows, &field.ty));
+            let row = writer.tables.Field.len() as u32;
+            writer.tables.Field.push(writer::Field { Flags: field.flags.0, Name: writer.strings.insert(&field.name), Signature: signature });
+
+            if let Some(value) = &field.value {
+                let blob = writer.insert_value_blob(value);
+                writer.tables.Constant.push(writer::Constant { Type: value.to_code(), Parent: writer::HasConstant::Field(row).encode(), Value: blob });
+            }
+
+            write_attributes(writer, writer::HasAttribute::Field(row), &field.attributes);
+        }
+
+        // Properties and events refer to their accessor methods by row.
+        let first_method = writer.tables.MethodDef.len() as u32;
+
+        for method in &self.methods {
+            method.write(writer, rows);
+        }
+
+        if !self.properties.is_empty() {
+            writer.tables.PropertyMap.push(writer::PropertyMap { Parent: type_def, PropertyList: writer.tables.Property.len() as _ });
+        }
+
+        for property in &self.properties {
+            let signature = writer.insert_property_sig(&resolve_type(rows, &property.ty));
+            let row = writer.tables.Property.len() as u32;
+            writer.tables.Property.push(writer::Property { Flags: property.flags.0, Name: writer.strings.insert(&property.name), Type: signature });
+
+            for semantics in &property.semantics {
+                writer.tables.MethodSemantics.push(writer::MethodSemantics { Semantics: semantics.flags.0, Method: first_method + semantics.method as u32, Association: writer::HasSemantics::Property(row).encode() });
+            }
+
+            write_attributes(writer, writer::HasAttribute::Property(row), &property.attributes);
+        }
+
+        if !self.events.is_empty() {
+            writer.tables.EventMap.push(writer::EventMap { Parent: type_def, EventList: writer.tables.Event.len() as _ });
+        }
+
+        for event in &self.events {
+            let event_type = writer.insert_type_def_or_ref(&resolve_type(rows, &event.ty));
+            let row = writer.tables.Event.len() as u32;
+            writer.tables.Event.push(writer::Event { EventFlags: event.flags.0, Name: writer.strings.insert(&event.name), EventType: event_type });
+
+            for semantics in &event.semantics {
+                writer.tables.MethodSemantics.push(writer::MethodSemantics { Semantics: semantics.flags.0, Method: first_method + semantics.method as u32, Association: writer::HasSemantics::Event(row).encode() });
+            }
+
+            write_attributes(writer, writer::HasAttribute::Event(row), &event.attributes);
+        }
+
+        write_attributes(writer, writer::HasAttribute::TypeDef(type_def), &self.attributes);
+
+        for interface in &self.interfaces {
+            let row = writer.tables.InterfaceImpl.len() as u32;
+            let ty = writer.insert_type_def_or_ref(&resolve_type(rows, &interface.ty));
+            writer.tables.InterfaceImpl.push(writer::InterfaceImpl { Class: type_def, Interface: ty });
+            write_attributes(writer, writer::HasAttribute::InterfaceImpl(row), &interface.attributes);
+        }
+
+        for nested in &self.nested {
+            nested.write(writer, rows, "", Some(type_def));
+        }
+    }
+}
+
+impl Field {
+    pub fn new(name: &str, flags: FieldAttributes, ty: Type) -> Self {
+        Self { name: name.to_string(), flags, ty, value: None, attributes: vec![] }
+    }
+}
+
+impl Method {
+    pub fn new(name: &str, flags: MethodAttributes, return_type: Type) -> Self {
+        Self {
+            name: name.to_string(),
+            flags,
+            impl_flags: MethodImplAttributes::default(),
+            call_flags: MethodCallAttributes::default(),
+            return_type,
+            return_param: None,
+            params: vec![],
+            import: None,
+            attributes: vec![],
+        }
+    }
+
+    fn from_reader(reader: &Reader, method: crate::MethodDef, generics: &[crate::Type]) -> Self {
+        let signature = reader.method_def_signature(method, generics);
+
+        Self {
+            name: reader.method_def_name(method).to_string(),
+            flags: reader.method_def_flags(method),
+            impl_flags: reader.method_def_impl_flags(method),
+            call_flags: signature.call_flags,
+            return_type: load_type(reader, &signature.return_type),
+            return_param: reader.method_def_params(method).find(|param| reader.param_sequence(*param) == 0).map(|param| ReturnParam {
+                name: reader.param_name(param).to_string(),
+                flags: reader.param_flags(param),
+                attributes: load_attributes(reader, reader.param_attributes(param)),
+            }),
+            params: signature
+                .params
+                .iter()
+                .map(|param| Param {
+                    name: reader.param_name(param.def).to_string(),
+                    flags: reader.param_flags(param.def),
+                    ty: load_type(reader, &param.ty),
+                    attributes: load_attributes(reader, reader.param_attributes(param.def)),
+                })
+                .collect(),
+            import: reader.method_def_impl_map(method).map(|impl_map| Import {
+                module: reader.module_ref_name(reader.impl_map_scope(impl_map)).to_string(),
+                name: reader.impl_map_import_name(impl_map).to_string(),
+                flags: reader.impl_map_flags(impl_map),
+            }),
+            attributes: load_attributes(reader, reader.method_def_attributes(method)),
+        }
+    }
+
+    fn write(&self, writer: &mut Writer, rows: &HashMap<String, u32>) {
+        let return_type = resolve_type(rows, &self.return_type);
+
+        let param_types: Vec<Type> = self
+            .params
+            .iter()
+            .map(|param| {
+                let ty = resolve_type(rows, &param.ty);
+
+                // WinRT output parameters are passed by reference. Win32 output parameters are pointers instead.
+                if param.flags.contains(ParamAttributes::Out) && !matches!(ty, Type::WinrtArray(_) | Type::WinrtArrayRef(_) | Type::MutPtr(_, _) | Type::ConstPtr(_, _) | Type::RefMut(_)) {
+                    Type::RefMut(Box::new(ty))
+                } else {
+                    ty
+                }
+            })
+            .collect();
+
+        let signature = writer.insert_method_sig(self.call_flags, &return_type, &param_types);
+        let row = writer.tables.MethodDef.len() as u32;
+        writer.tables.MethodDef.push(writer::MethodDef {
+            RVA: 0,
+            ImplFlags: self.impl_flags.0 as _,
+            Flags: self.flags.0,
+            Name: writer.strings.insert(&self.name),
+            Signature: signature,
+            ParamList: writer.tables.Param.len() as _,
+        });
+
+        if let Some(param) = &self.return_param {
+            let row = writer.tables.Param.len() as u32;
+            writer.tables.Param.push(writer::Param { Flags: param.flags.0, Sequence: 0, Name: writer.strings.insert(&param.name) });
+            write_attributes(writer, writer::HasAttribute::Param(row), &param.attributes);
+        }
+
+        for (sequence, param) in self.params.iter().enumerate() {
+            let row = writer.tables.Param.len() as u32;
+            writer.tables.Param.push(writer::Param { Flags: param.flags.0, Sequence: (sequence + 1) as _, Name: writer.strings.insert(&param.name) });
+            write_attributes(writer, writer::HasAttribute::Param(row), &param.attributes);
+        }
+
+        write_attributes(writer, writer::HasAttribute::MethodDef(row), &self.attributes);
+
+        if let Some(import) = &self.import {
+            let scope = writer.insert_module_ref(&import.module);
+            writer.tables.ImplMap.push(writer::ImplMap {
+                MappingFlags: import.flags.0 as _,
+                MemberForwarded: writer::MemberForwarded::MethodDef(row).encode(),
+                ImportName: writer.strings.insert(&import.name),
+                ImportScope: scope,
+            });
+        }
+    }
+}
+
+impl Param {
+    pub fn new(name: &str, flags: ParamAttributes, ty: Type) -> Self {
+        Self { name: name.to_string(), flags, ty, attributes: vec![] }
+    }
+}
+
+impl Attribute {
+    pub fn new(namespace: &str, name: &str, args: Vec<Value>) -> Self {
+        Self { namespace: namespace.to_string(), name: name.to_string(), args, named_args: vec![] }
+    }
+}
+
+fn collect_nested_rows(name: &str, def: &TypeDef, next: &mut u32, rows: &mut HashMap<String, u32>) {
+    *next += 1;
+
+    for nested in &def.nested {
+        let nested_name = format!("{name}.{}", nested.name);
+        rows.insert(nested_name.clone(), *next);
+        collect_nested_rows(&nested_name, nested, next, rows);
+    }
+}
+
+// Replaces references to nested types by name with references by row.
+fn resolve_type(rows: &HashMap<String, u32>, ty: &Type) -> Type {
+    match ty {
+        Type::TypeRef(name) if name.generics.is_empty() => match rows.get(&format!("{}.{}", name.namespace, name.name)) {
+            Some(row) => Type::TypeDef(*row),
+            None => ty.clone(),
+        },
+        Type::TypeRef(name) => Type::TypeRef(TypeName { namespace: name.namespace.clone(), name: name.name.clone(), generics: name.generics.iter().map(|ty| resolve_type(rows, ty)).collect() }),
+        Type::MutPtr(ty, pointers) => Type::MutPtr(Box::new(resolve_type(rows, ty)), *pointers),
+        Type::ConstPtr(ty, pointers) => Type::ConstPtr(Box::new(resolve_type(rows, ty)), *pointers),
+        Type::Win32Array(ty, len) => Type::Win32Array(Box::new(resolve_type(rows, ty)), *len),
+        Type::WinrtArray(ty) => Type::WinrtArray(Box::new(resolve_type(rows, ty))),
+        Type::WinrtArrayRef(ty) => Type::WinrtArrayRef(Box::new(resolve_type(rows, ty))),
+        Type::ConstRef(ty) => Type::ConstRef(Box::new(resolve_type(rows, ty))),
+        Type::RefMut(ty) => Type::RefMut(Box::new(resolve_type(rows, ty))),
+        rest => rest.clone(),
+    }
+}
+
+fn visit_type(ty: &mut Type, f: &mut dyn FnMut(&mut TypeName)) {
+    match ty {
+        Type::TypeRef(name) => visit_type_name(name, f),
+        Type::MutPtr(ty, _) | Type::ConstPtr(ty, _) | Type::Win32Array(ty, _) | Type::WinrtArray(ty) | Type::WinrtArrayRef(ty) | Type::ConstRef(ty) | Type::RefMut(ty) => visit_type(ty, f),
+        _ => {}
+    }
+}
+
+fn visit_type_name(name: &mut TypeName, f: &mut dyn FnMut(&mut TypeName)) {
+    f(name);
+
+    for ty in &mut name.generics {
+        visit_type(ty, f);
+    }
+}
+
+fn visit_attributes(attributes: &mut [Attribute], f: &mut dyn FnMut(&mut TypeName)) {
+    for attribute in attributes {
+        let mut name = TypeName { namespace: std::mem::take(&mut attribute.namespace), name: std::mem::take(&mut attribute.name), generics: vec![] };
+        f(&mut name);
+        attribute.namespace = name.namespace;
+        attribute.name = name.name;
+
+        for value in attribute.args.iter_mut().chain(attribute.named_args.iter_mut().map(|(_, value)| value)) {
+            match value {
+                Value::TypeName(full_name) => {
+                    if let Some((namespace, name)) = full_name.rsplit_once('.') {
+                        let mut name = TypeName { namespace: namespace.to_string(), name: name.to_string(), generics: vec![] };
+                        f(&mut name);
+                        *full_name = format!("{}.{}", name.namespace, name.name);
+                    }
+                }
+                Value::EnumRef(name, _) => visit_type_name(name, f),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn write_attributes(writer: &mut Writer, parent: writer::HasAttribute, attributes: &[Attribute]) {
+    for attribute in attributes {
+        writer.insert_attribute_with_named_args(parent.clone(), &attribute.namespace, &attribute.name, &attribute.args, &attribute.named_args);
+    }
+}
+
+fn load_attributes(reader: &Reader, attributes: impl Iterator<Item = crate::Attribute>) -> Vec<Attribute> {
+    attributes
+        .map(|attribute| {
+            let name = reader.attribute_type_name(attribute);
+            let mut result = Attribute::new(name.namespace, name.name, vec![]);
+
+            // The reader returns fixed arguments with empty names, followed by any named arguments.
+            for (arg_name, value) in reader.attribute_args(attribute) {
+                let value = load_value(reader, &value);
+
+                if arg_name.is_empty() {
+                    result.args.push(value);
+                } else {
+                    result.named_args.push((arg_name, value));
+                }
+            }
+
+            result
+        })
+        .collect()
+}
+
+// The full name of a type definition, including the names of any enclosing types.
+fn full_name(reader: &Reader, def: crate::TypeDef) -> String {
+    match reader.type_def_enclosing_type(def) {
+        Some(enclosing) => format!("{}.{}", full_name(reader, enclosing), reader.type_def_name(def)),
+        None => format!("{}.{}", reader.type_def_namespace(def), reader.type_def_name(def)),
+    }
+}
+
+fn load_type(reader: &Reader, ty: &crate::Type) -> Type {
+    match ty {
+        crate::Type::Void => Type::Void,
+        crate::Type::Bool => Type::Bool,
+        crate::Type::Char => Type::Char,
+        crate::Type::I8 => Type::I8,
+        crate::Type::U8 => Type::U8,
+        crate::Type::I16 => Type::I16,
+        crate::Type::U16 => Type::U16,
+        crate::Type::I32 => Type::I32,
+        crate::Type::U32 => Type::U32,
+        crate::Type::I64 => Type::I64,
+        crate::Type::U64 => Type::U64,
+        crate::Type::F32 => Type::F32,
+        crate::Type::F64 => Type::F64,
+        crate::Type::ISize => Type::ISize,
+        crate::Type::USize => Type::USize,
+        crate::Type::String => Type::String,
+        crate::Type::GUID => Type::GUID,
+        crate::Type::IUnknown => Type::IUnknown,
+        crate::Type::IInspectable => Type::IInspectable,
+        crate::Type::HRESULT => Type::HRESULT,
+        crate::Type::PSTR => Type::PSTR,
+        crate::Type::PWSTR => Type::PWSTR,
+        crate::Type::PCSTR => Type::PCSTR,
+        crate::Type::PCWSTR => Type::PCWSTR,
+        crate::Type::BSTR => Type::BSTR,
+        crate::Type::TypeName => Type::TypeName,
+        crate::Type::TypeDef(def, _) if reader.type_def_namespace(*def).is_empty() => {
+            let enclosing = reader.type_def_enclosing_type(*def).expect("nested type without an enclosing type");
+            Type::TypeRef(TypeName { namespace: full_name(reader, enclosing), name: reader.type_def_name(*def).to_string(), generics: vec![] })
+        }
+        crate::Type::TypeDef(def, generics) => Type::TypeRef(TypeName {
+            namespace: reader.type_def_namespace(*def).to_string(),
+            name: reader.type_def_name(*def).to_string(),
+            generics: generics.iter().map(|ty| load_type(reader, ty)).collect(),
+        }),
+        crate::Type::ConstRef(ty) => Type::ConstRef(Box::new(load_type(reader, ty))),
+        crate::Type::WinrtArray(ty) => Type::WinrtArray(Box::new(load_type(reader, ty))),
+        crate::Type::WinrtArrayRef(ty) => Type::WinrtArrayRef(Box::new(load_type(reader, ty))),
+        crate::Type::MutPtr(ty, pointers) => Type::MutPtr(Box::new(load_type(reader, ty)), *pointers),
+        crate::Type::ConstPtr(ty, pointers) => Type::ConstPtr(Box::new(load_type(reader, ty)), *pointers),
+        crate::Type::Win32Array(ty, len) => Type::Win32Array(Box::new(load_type(reader, ty)), *len),
+        crate::Type::TypeRef(code) => {
+            let name = reader.type_def_or_ref(*code);
+            Type::TypeRef(TypeName { namespace: name.namespace.to_string(), name: name.name.to_string(), generics: vec![] })
+        }
+        crate::Type::GenericParam(param) => Type::GenericParam(reader.generic_param_number(*param)),
+    }
+}
+
+fn load_value(reader: &Reader, value: &crate::Value) -> Value {
+    match value {
+        crate::Value::Bool(value) => Value::Bool(*value),
+        crate::Value::U8(value) => Value::U8(*value),
+        crate::Value::I8(value) => Value::I8(*value),
+        crate::Value::U16(value) => Value::U16(*value),
+        crate::Value::I16(value) => Value::I16(*value),
+        crate::Value::U32(value) => Value::U32(*value),
+        crate::Value::I32(value) => Value::I32(*value),
+        crate::Value::U64(value) => Value::U64(*value),
+        crate::Value::I64(value) => Value::I64(*value),
+        crate::Value::F32(value) => Value::F32(*value),
+        crate::Value::F64(value) => Value::F64(*value),
+        crate::Value::String(value) => Value::String(value.clone()),
+        crate::Value::TypeDef(def) => Value::TypeName(format!("{}.{}", reader.type_def_namespace(*def), reader.type_def_name(*def))),
+        crate::Value::TypeRef(code) => Value::TypeName(reader.type_def_or_ref(*code).to_string()),
+        crate::Value::EnumDef(def, value) => load_enum(crate::TypeName::new(reader.type_def_namespace(*def), reader.type_def_name(*def)), value),
+        crate::Value::EnumRef(code, value) => load_enum(reader.type_def_or_ref(*code), value),
+    }
+}
+
+fn load_enum(name: crate::TypeName, value: &crate::Value) -> Value {
+    let name = TypeName { namespace: name.namespace.to_string(), name: name.name.to_string(), generics: vec![] };
+
+    match value {
+        crate::Value::I32(value) => Value::EnumRef(name, *value),
+        crate::Value::U32(value) => Value::EnumRef(name, *value as _),
+        rest => unimplemented!("{rest:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(bytes: Vec<u8>) -> [crate::File; 1] {
+        [crate::File::new(bytes).unwrap()]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let files = read(std::fs::read("default/Windows.Wdk.winmd").unwrap());
+        let filter = Filter::new(&["Windows.Wdk"], &[]);
+        let model = Model::from_reader(&Reader::new(&files), &filter);
+
+        let files = read(model.write("Windows.Wdk.winmd"));
+        assert!(model == Model::from_reader(&Reader::new(&files), &filter));
+    }
+
+    #[test]
+    fn test_edit() {
+        let mut model = Model::default();
+        let namespace = model.namespace_mut("Test");
+
+        let mut def = TypeDef::new("Colors", TypeAttributes::Public | TypeAttributes::Sealed);
+        def.extends = Some(TypeName { namespace: "System".to_string(), name: "Enum".to_string(), generics: vec![] });
+        def.fields.push(Field::new("value__", FieldAttributes::Private | FieldAttributes::SpecialName | FieldAttributes::RTSpecialName, Type::U32));
+
+        for (name, value) in [("Red", 1), ("Green", 2)] {
+            let mut field = Field::new(name, FieldAttributes::Public | FieldAttributes::Static | FieldAttributes::Literal | FieldAttributes::HasDefault, Type::TypeRef(TypeName { namespace: "Test".to_string(), name: "Colors".to_string(), generics: vec![] }));
+            field.value = Some(Value::U32(value));
+            def.fields.push(field);
+        }
+
+        namespace.types.push(def);
+
+        // Add the missing `[Flags]` attribute after the fact.
+        model.type_def_mut("Test", "Colors").unwrap().attributes.push(Attribute::new("System", "FlagsAttribute", vec![]));
+
+        let files = read(model.write("Test.winmd"));
+        let reader = &Reader::new(&files);
+        let def = reader.get(crate::TypeName::new("Test", "Colors")).next().unwrap();
+
+        assert!(reader.type_def_is_flags(def));
+        assert_eq!(reader.type_def_kind(def), crate::TypeKind::Enum);
+
+        let values: Vec<(&str, crate::Value)> = reader.type_def_fields(def).filter_map(|field| reader.field_constant(field).map(|constant| (reader.field_name(field), reader.constant_value(constant)))).collect();
+        assert!(matches!(values.as_slice(), [("Red", crate::Value::U32(1)), ("Green", crate::Value::U32(2))]));
+    }
+
+    #[test]
+    fn test_rename() {
+        let files = read(std::fs::read("default/Windows.Wdk.winmd").unwrap());
+        let mut model = Model::from_reader(&Reader::new(&files), &Filter::new(&["Windows.Wdk.Foundation"], &[]));
+
+        assert!(model.rename_type("Windows.Wdk.Foundation", "DEVICE_OBJECT", "DeviceObject"));
+        assert!(!model.rename_type("Windows.Wdk.Foundation", "DEVICE_OBJECT", "DeviceObject"));
+
+        let files = read(model.write("Test.winmd"));
+        let reader = &Reader::new(&files);
+        assert!(reader.get(crate::TypeName::new("Windows.Wdk.Foundation", "DEVICE_OBJECT")).next().is_none());
+        let def = reader.get(crate::TypeName::new("Windows.Wdk.Foundation", "DeviceObject")).next().unwrap();
+        assert!(reader.nested_types(def).count() > 0);
+
+        // References to the renamed type, such as the `DRIVER_OBJECT::DeviceObject` field, follow the new name.
+        let driver = reader.get(crate::TypeName::new("Windows.Wdk.Foundation", "DRIVER_OBJECT")).next().unwrap();
+        let field = reader.type_def_fields(driver).find(|field| reader.field_name(*field) == "DeviceObject").unwrap();
+        assert_eq!(reader.type_name(&reader.field_type(field, Some(driver)).deref()), "DeviceObject");
+    }
+
+    #[test]
+    fn test_rename_generic() {
+        let mut model = Model::default();
+        let namespace = model.namespace_mut("Test");
+
+        let mut def = TypeDef::new("IVector`1", TypeAttributes::Public | TypeAttributes::Interface | TypeAttributes::Abstract | TypeAttributes::WindowsRuntime);
+        def.generics.push("T".to_string());
+        namespace.types.push(def);
+
+        let mut def = TypeDef::new("Numbers", TypeAttributes::Public | TypeAttributes::Sealed | TypeAttributes::WindowsRuntime);
+        def.extends = Some(TypeName { namespace: "System".to_string(), name: "Object".to_string(), generics: vec![] });
+        def.interfaces.push(InterfaceImpl { ty: Type::TypeRef(TypeName { namespace: "Test".to_string(), name: "IVector`1".to_string(), generics: vec![Type::U32] }), attributes: vec![] });
+        namespace.types.push(def);
+
+        // The arity is appended when omitted and preserved when given.
+        assert!(model.rename_type("Test", "IVector", "IRenamed"));
+        assert_eq!(model.type_def_mut("Test", "IRenamed`1").unwrap().generics, ["T"]);
+        assert!(model.rename_type("Test", "IRenamed`1", "IOther`1"));
+        assert!(model.type_def_mut("Test", "IOther`1").is_some());
+        assert!(!model.rename_type("Test", "IVector`1", "IRenamed"));
+
+        let files = read(model.write("Test.winmd"));
+        let reader = &Reader::new(&files);
+        let def = reader.get(crate::TypeName::new("Test", "Numbers")).next().unwrap();
+        let interface = reader.type_def_interfaces(def, &[]).next().unwrap();
+        assert_eq!(reader.type_name(&interface.ty), "IOther`1");
+        assert!(matches!(interface.ty, crate::Type::TypeDef(_, ref generics) if generics == &[crate::Type::U32]));
+    }
+}
@@ -0,0 +1,60 @@
+/// The reason that a `File` could not be parsed, along with the byte offset within the file at which parsing failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The file ends before the end of a header or structure that it describes.
+    Truncated { offset: usize },
+    /// A header is not suitably aligned within the file.
+    Misaligned { offset: usize },
+    /// The DOS header does not start with the `MZ` signature.
+    InvalidDosSignature { offset: usize },
+    /// The NT headers do not start with the `PE\0\0` signature.
+    InvalidNtSignature { offset: usize },
+    /// The optional header is neither a PE32 nor a PE32+ header.
+    InvalidOptionalHeader { offset: usize },
+    /// The COM descriptor (CLI header) is missing or does not fit the expected layout.
+    MissingComDescriptor { offset: usize },
+    /// The metadata root does not start with the `BSJB` signature.
+    InvalidMetadataSignature { offset: usize },
+    /// The metadata root includes a stream other than `#~`, `#Strings`, `#Blob`, `#GUID`, or `#US`.
+    UnsupportedStream { offset: usize, name: String },
+    /// The metadata root does not include a stream that is required to read the file.
+    MissingStream { offset: usize, name: &'static str },
+    /// The tables stream includes a table that is not defined by ECMA-335.
+    UnsupportedTable { offset: usize, table: usize },
+    /// A stream or the tables within it extend past the end of the file.
+    TruncatedHeap { offset: usize, name: &'static str },
+    /// A coded index in the given table refers to a table or row that does not exist.
+    InvalidCodedIndex { offset: usize, table: &'static str },
+}
+
+impl ParseError {
+    /// The byte offset within the file at which parsing failed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Truncated { offset } | Self::Misaligned { offset } | Self::InvalidDosSignature { offset } | Self::InvalidNtSignature { offset } | Self::InvalidOptionalHeader { offset } | Self::MissingComDescriptor { offset } | Self::InvalidMetadataSignature { offset } | Self::UnsupportedStream { offset, .. } | Self::MissingStream { offset, .. } | Self::UnsupportedTable { offset, .. } | Self::TruncatedHeap { offset, .. } | Self::InvalidCodedIndex { offset, .. } => *offset,
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated { .. } => write!(f, "unexpected end of file")?,
+            Self::Misaligned { .. } => write!(f, "misaligned header")?,
+            Self::InvalidDosSignature { .. } => write!(f, "invalid DOS signature")?,
+            Self::InvalidNtSignature { .. } => write!(f, "invalid NT signature")?,
+            Self::InvalidOptionalHeader { .. } => write!(f, "invalid optional header")?,
+            Self::MissingComDescriptor { .. } => write!(f, "missing COM descriptor")?,
+            Self::InvalidMetadataSignature { .. } => write!(f, "invalid metadata signature")?,
+            Self::UnsupportedStream { name, .. } => write!(f, "unsupported stream `{name}`")?,
+            Self::MissingStream { name, .. } => write!(f, "missing stream `{name}`")?,
+            Self::UnsupportedTable { table, .. } => write!(f, "unsupported table 0x{table:02x}")?,
+            Self::TruncatedHeap { name, .. } => write!(f, "truncated `{name}` stream")?,
+            Self::InvalidCodedIndex { table, .. } => write!(f, "out-of-range coded index in the {table} table")?,
+        }
+
+        write!(f, " at offset 0x{:x}", self.offset())
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,265 @@
+use super::*;
+
+/// A problem found by `validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The index, within the files given to the `Reader`, of the file containing the problem.
+    pub file: usize,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(file: usize, message: String) -> Self {
+        Self { file, message }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Checks the internal consistency of the metadata, returning a `Diagnostic` for each problem found. Metadata that
+/// passes validation can be read without the `Reader` panicking on dangling indexes or types that cannot be found.
+pub fn validate(reader: &Reader) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for (file_index, file) in reader.files.iter().enumerate() {
+        validate_tables(file_index, file, &mut diagnostics);
+    }
+
+    // Reading the types themselves may panic if the tables are inconsistent.
+    if !diagnostics.is_empty() {
+        return diagnostics;
+    }
+
+    validate_duplicates(reader, &mut diagnostics);
+
+    for (file_index, file) in reader.files.iter().enumerate() {
+        // The first row is the `<Module>` pseudo-type.
+        for row in 1..file.tables[TABLE_TYPEDEF].len {
+            validate_type_def(reader, TypeDef(Row::new(row, TABLE_TYPEDEF, file_index)), &mut diagnostics);
+        }
+    }
+
+    diagnostics
+}
+
+fn validate_tables(file_index: usize, file: &File, diagnostics: &mut Vec<Diagnostic>) {
+    let tables = &file.tables;
+    let mut check = |result: Option<String>| diagnostics.extend(result.map(|message| Diagnostic::new(file_index, message)));
+
+    // Rows in these tables own the run of rows in another table from their own list index up to that of the next row.
+    check(validate_list(file, TABLE_TYPEDEF, 4, TABLE_FIELD, "TypeDef", "field"));
+    check(validate_list(file, TABLE_TYPEDEF, 5, TABLE_METHODDEF, "TypeDef", "method"));
+    check(validate_list(file, TABLE_METHODDEF, 5, TABLE_PARAM, "MethodDef", "param"));
+    check(validate_list(file, TABLE_PROPERTYMAP, 1, TABLE_PROPERTY, "PropertyMap", "property"));
+    check(validate_list(file, TABLE_EVENTMAP, 1, TABLE_EVENT, "EventMap", "event"));
+
+    check(validate_index(file, TABLE_NESTEDCLASS, 0, "NestedClass", &[tables[TABLE_TYPEDEF].len]));
+    check(validate_index(file, TABLE_NESTEDCLASS, 1, "NestedClass", &[tables[TABLE_TYPEDEF].len]));
+    check(validate_index(file, TABLE_INTERFACEIMPL, 0, "InterfaceImpl", &[tables[TABLE_TYPEDEF].len]));
+    check(validate_index(file, TABLE_CLASSLAYOUT, 2, "ClassLayout", &[tables[TABLE_TYPEDEF].len]));
+    check(validate_index(file, TABLE_IMPLMAP, 3, "ImplMap", &[tables[TABLE_MODULEREF].len]));
+    check(validate_index(file, TABLE_METHODSEMANTICS, 1, "MethodSemantics", &[tables[TABLE_METHODDEF].len]));
+    check(validate_index(file, TABLE_PROPERTYMAP, 0, "PropertyMap", &[tables[TABLE_TYPEDEF].len]));
+    check(validate_index(file, TABLE_EVENTMAP, 0, "EventMap", &[tables[TABLE_TYPEDEF].len]));
+
+    // Tables that aren't read have an unknown length so any row is accepted.
+    let unknown = usize::MAX;
+    check(validate_index(
+        file,
+        TABLE_CUSTOMATTRIBUTE,
+        0,
+        "CustomAttribute",
+        &[
+            tables[TABLE_METHODDEF].len,
+            tables[TABLE_FIELD].len,
+            tables[TABLE_TYPEREF].len,
+            tables[TABLE_TYPEDEF].len,
+            tables[TABLE_PARAM].len,
+            tables[TABLE_INTERFACEIMPL].len,
+            tables[TABLE_MEMBERREF].len,
+            tables[TABLE_MODULE].len,
+            unknown,
+            tables[TABLE_PROPERTY].len,
+            tables[TABLE_EVENT].len,
+            unknown,
+            tables[TABLE_MODULEREF].len,
+            tables[TABLE_TYPESPEC].len,
+            tables[TABLE_ASSEMBLY].len,
+            tables[TABLE_ASSEMBLYREF].len,
+            unknown,
+            unknown,
+            unknown,
+            tables[TABLE_GENERICPARAM].len,
+            unknown,
+            unknown,
+        ],
+    ));
+
+    // These tables are searched by binary search so must be sorted by their parent column (see II.22 in ECMA-335).
+    check(validate_sorted(file, TABLE_CONSTANT, 1, "Constant"));
+    check(validate_sorted(file, TABLE_CUSTOMATTRIBUTE, 0, "CustomAttribute"));
+    check(validate_sorted(file, TABLE_CLASSLAYOUT, 2, "ClassLayout"));
+    check(validate_sorted(file, TABLE_FIELDRVA, 1, "FieldRVA"));
+    check(validate_sorted(file, TABLE_GENERICPARAM, 2, "GenericParam"));
+    check(validate_sorted(file, TABLE_IMPLMAP, 1, "ImplMap"));
+    check(validate_sorted(file, TABLE_INTERFACEIMPL, 0, "InterfaceImpl"));
+    check(validate_sorted(file, TABLE_METHODSEMANTICS, 2, "MethodSemantics"));
+    check(validate_sorted(file, TABLE_NESTEDCLASS, 0, "NestedClass"));
+}
+
+fn validate_list(file: &File, table: usize, column: usize, target: usize, name: &str, list: &str) -> Option<String> {
+    let len = file.tables[target].len;
+    let mut previous = 1;
+
+    for row in 0..file.tables[table].len {
+        let index = file.usize(row, table, column);
+
+        if index > len + 1 {
+            return Some(format!("the {list} list of row {} in the {name} table is out of range", row + 1));
+        }
+
+        if index < previous {
+            return Some(format!("the {list} list of row {} in the {name} table is out of order", row + 1));
+        }
+
+        previous = index;
+    }
+
+    None
+}
+
+// Checks a simple index, when there is a single target, or a coded index where the position of the target table is
+// the tag of the coded index.
+fn validate_index(file: &File, table: usize, column: usize, name: &str, targets: &[usize]) -> Option<String> {
+    let bits = (usize::BITS - (targets.len() - 1).leading_zeros()) as usize;
+
+    for row in 0..file.tables[table].len {
+        let value = file.usize(row, table, column);
+        let index = value >> bits;
+
+        match targets.get(value & ((1 << bits) - 1)) {
+            Some(len) if index > 0 && index <= *len => {}
+            _ => return Some(format!("row {} in the {name} table refers to a missing row", row + 1)),
+        }
+    }
+
+    None
+}
+
+fn validate_sorted(file: &File, table: usize, column: usize, name: &str) -> Option<String> {
+    let mut previous = 0;
+
+    for row in 0..file.tables[table].len {
+        let value = file.usize(row, table, column);
+
+        if value < previous {
+            return Some(format!("the {name} table is not sorted"));
+        }
+
+        previous = value;
+    }
+
+    None
+}
+
+fn validate_duplicates(reader: &Reader, diagnostics: &mut Vec<Diagnostic>) {
+    for namespace in reader.namespaces() {
+        let types = reader.namespace_index(namespace);
+        let mut first = 0;
+
+        while first < types.len() {
+            let name = trim_tick(reader.type_def_name(types[first]));
+            let last = first + types[first..].partition_point(|def| trim_tick(reader.type_def_name(*def)) == name);
+
+            // Architecture-specific definitions of the same type are expected.
+            if last - first > 1 && !types[first..last].iter().all(|def| reader.type_def_attributes(*def).any(|attribute| reader.attribute_name(attribute) == "SupportedArchitectureAttribute")) {
+                for def in &types[first + 1..last] {
+                    diagnostics.push(Diagnostic::new(def.0.file as _, format!("the type `{}` is defined more than once", reader.type_def_type_name(*def))));
+                }
+            }
+
+            first = last;
+        }
+    }
+}
+
+fn validate_type_def(reader: &Reader, def: TypeDef, diagnostics: &mut Vec<Diagnostic>) {
+    let file = def.0.file as usize;
+    let name = if reader.type_def_namespace(def).is_empty() { reader.type_def_name(def).to_string() } else { reader.type_def_type_name(def).to_string() };
+
+    if reader.type_def_flags(def).contains(TypeAttributes::WindowsRuntime) && reader.type_def_guid(def).is_none() {
+        match reader.type_def_kind(def) {
+            TypeKind::Interface => diagnostics.push(Diagnostic::new(file, format!("the interface `{name}` has no `GuidAttribute`"))),
+            TypeKind::Delegate => diagnostics.push(Diagnostic::new(file, format!("the delegate `{name}` has no `GuidAttribute`"))),
+            _ => {}
+        }
+    }
+
+    // Types that cannot be found are returned as `Type::TypeRef`, which code generation cannot use.
+    let generics: Vec<Type> = reader.type_def_generics(def).collect();
+    let mut types = vec![];
+
+    types.extend(reader.type_def_fields(def).map(|field| reader.field_type(field, Some(def))));
+
+    for method in reader.type_def_methods(def) {
+        let signature = reader.method_def_signature(method, &generics);
+        types.push(signature.return_type);
+        types.extend(signature.params.into_iter().map(|param| param.ty));
+    }
+
+    types.extend(reader.type_def_interface_impls(def).map(|interface_impl| reader.interface_impl_type(interface_impl, &generics).ty));
+
+    let mut missing = BTreeSet::new();
+
+    for ty in &types {
+        collect_missing(reader, ty, &mut missing);
+    }
+
+    for missing in missing {
+        diagnostics.push(Diagnostic::new(file, format!("the type `{missing}` used by `{name}` could not be found")));
+    }
+}
+
+fn collect_missing(reader: &Reader, ty: &Type, missing: &mut BTreeSet<String>) {
+    match ty {
+        Type::TypeRef(code) => _ = missing.insert(reader.type_def_or_ref(*code).to_string()),
+        Type::TypeDef(_, generics) => generics.iter().for_each(|ty| collect_missing(reader, ty, missing)),
+        Type::MutPtr(ty, _) | Type::ConstPtr(ty, _) | Type::Win32Array(ty, _) | Type::WinrtArray(ty) | Type::WinrtArrayRef(ty) | Type::ConstRef(ty) => collect_missing(reader, ty, missing),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read() -> File {
+        File::new(std::fs::read("default/Windows.Wdk.winmd").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_missing_types() {
+        let files = [read()];
+        let diagnostics = validate(&Reader::new(&files));
+
+        // The Wdk metadata depends on the Win32 metadata, which isn't included here.
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.file == 0 && diagnostic.message.contains("could not be found")));
+        assert!(diagnostics.contains(&Diagnostic::new(0, "the type `Windows.Win32.Foundation.BOOLEAN` used by `Windows.Wdk.Foundation.ACCESS_STATE` could not be found".to_string())));
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let files = [read(), read()];
+        let diagnostics: Vec<Diagnostic> = validate(&Reader::new(&files)).into_iter().filter(|diagnostic| !diagnostic.message.contains("could not be found")).collect();
+
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics.iter().all(|diagnostic| diagnostic.file == 1 && diagnostic.message.ends_with("is defined more than once")));
+        assert!(diagnostics.contains(&Diagnostic::new(1, "the type `Windows.Wdk.Foundation.DEVICE_OBJECT` is defined more than once".to_string())));
+    }
+}
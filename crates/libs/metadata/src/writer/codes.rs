@@ -0,0 +1,178 @@
+#![allow(dead_code, clippy::enum_variant_names)]
+
+/// A `ResolutionScope` is an index into a certain table indicating the scope in which a TypeRef can be resolved.
+#[derive(Clone)]
+pub enum ResolutionScope {
+    Module(u32),
+    ModuleRef(u32),
+    AssemblyRef(u32),
+    TypeRef(u32),
+}
+
+impl ResolutionScope {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Module(row) => (row + 1) << 2,
+            Self::ModuleRef(row) => ((row + 1) << 2) + 1,
+            Self::AssemblyRef(row) => ((row + 1) << 2) + 2,
+            Self::TypeRef(row) => ((row + 1) << 2) + 3,
+        }
+    }
+}
+
+/// A `TypeDefOrRef` is an index into a certain table used to locate a type definition.
+#[derive(Clone)]
+pub enum TypeDefOrRef {
+    TypeDef(u32),
+    TypeRef(u32),
+    TypeSpec(u32),
+}
+
+impl TypeDefOrRef {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::TypeDef(row) => (row + 1) << 2,
+            Self::TypeRef(row) => ((row + 1) << 2) + 1,
+            Self::TypeSpec(row) => ((row + 1) << 2) + 2,
+        }
+    }
+}
+
+/// A `HasConstant` is an index into a certain table used to identify the parent of a row in the `Constant` table.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HasConstant {
+    Field(u32),
+    Param(u32),
+    Property(u32),
+}
+
+impl HasConstant {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Field(row) => (row + 1) << 2,
+            Self::Param(row) => ((row + 1) << 2) + 1,
+            Self::Property(row) => ((row + 1) << 2) + 2,
+        }
+    }
+}
+
+/// A `HasAttribute` is an index into a certain table used to identify the parent of a row in the `CustomAttribute` table.
+#[derive(Clone)]
+pub enum HasAttribute {
+    MethodDef(u32),
+    Field(u32),
+    TypeRef(u32),
+    TypeDef(u32),
+    Param(u32),
+    InterfaceImpl(u32),
+    MemberRef(u32),
+    Property(u32),
+    Event(u32),
+    TypeSpec(u32),
+    GenericParam(u32),
+}
+
+impl HasAttribute {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::MethodDef(row) => (row + 1) << 5,
+            Self::Field(row) => ((row + 1) << 5) + 1,
+            Self::TypeRef(row) => ((row + 1) << 5) + 2,
+            Self::TypeDef(row) => ((row + 1) << 5) + 3,
+            Self::Param(row) => ((row + 1) << 5) + 4,
+            Self::InterfaceImpl(row) => ((row + 1) << 5) + 5,
+            Self::MemberRef(row) => ((row + 1) << 5) + 6,
+            Self::Property(row) => ((row + 1) << 5) + 9,
+            Self::Event(row) => ((row + 1) << 5) + 10,
+            Self::TypeSpec(row) => ((row + 1) << 5) + 13,
+            Self::GenericParam(row) => ((row + 1) << 5) + 19,
+        }
+    }
+}
+
+/// An `AttributeType` is an index into a certain table used to identify the constructor of a custom attribute.
+#[derive(Clone)]
+pub enum AttributeType {
+    MethodDef(u32),
+    MemberRef(u32),
+}
+
+impl AttributeType {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::MethodDef(row) => ((row + 1) << 3) + 2,
+            Self::MemberRef(row) => ((row + 1) << 3) + 3,
+        }
+    }
+}
+
+/// A `MemberRefParent` is an index into a certain table used to identify the type that owns a `MemberRef`.
+#[derive(Clone)]
+pub enum MemberRefParent {
+    TypeDef(u32),
+    TypeRef(u32),
+    ModuleRef(u32),
+    MethodDef(u32),
+    TypeSpec(u32),
+}
+
+impl MemberRefParent {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::TypeDef(row) => (row + 1) << 3,
+            Self::TypeRef(row) => ((row + 1) << 3) + 1,
+            Self::ModuleRef(row) => ((row + 1) << 3) + 2,
+            Self::MethodDef(row) => ((row + 1) << 3) + 3,
+            Self::TypeSpec(row) => ((row + 1) << 3) + 4,
+        }
+    }
+}
+
+/// A `HasSemantics` is an index into a certain table used to identify the property or event that owns a row in the
+/// `MethodSemantics` table.
+#[derive(Clone)]
+pub enum HasSemantics {
+    Event(u32),
+    Property(u32),
+}
+
+impl HasSemantics {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Event(row) => (row + 1) << 1,
+            Self::Property(row) => ((row + 1) << 1) + 1,
+        }
+    }
+}
+
+/// A `MemberForwarded` is an index into a certain table used to identify the member imported by an `ImplMap`.
+#[derive(Clone)]
+pub enum MemberForwarded {
+    Field(u32),
+    MethodDef(u32),
+}
+
+impl MemberForwarded {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Field(row) => (row + 1) << 1,
+            Self::MethodDef(row) => ((row + 1) << 1) + 1,
+        }
+    }
+}
+
+/// A `TypeOrMethodDef` is an index into a certain table used to identify the owner of a row in the `GenericParam` table.
+#[derive(Clone)]
+pub enum TypeOrMethodDef {
+    TypeDef(u32),
+    MethodDef(u32),
+}
+
+impl TypeOrMethodDef {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::TypeDef(row) => (row + 1) << 1,
+            Self::MethodDef(row) => ((row + 1) << 1) + 1,
+        }
+    }
+}
@@ -1,12 +1,9 @@
 use super::*;
-use metadata::imp::*;
+use crate::imp::*;
 use std::mem::*;
 
 pub fn write(mut tables: Vec<u8>, mut strings: Vec<u8>, mut blobs: Vec<u8>) -> Vec<u8> {
-    if [tables.len(), strings.len(), blobs.len()]
-        .iter()
-        .any(|len| *len > u32::MAX as _)
-    {
+    if [tables.len(), strings.len(), blobs.len()].iter().any(|len| *len > u32::MAX as _) {
         panic!("heap too large");
     }
 
@@ -23,8 +20,7 @@ pub fn write(mut tables: Vec<u8>, mut strings: Vec<u8>, mut blobs: Vec<u8>) -> V
         file.Machine = IMAGE_FILE_MACHINE_I386;
         file.NumberOfSections = 1;
         file.SizeOfOptionalHeader = size_of::<IMAGE_OPTIONAL_HEADER32>() as _;
-        file.Characteristics =
-            IMAGE_FILE_DLL | IMAGE_FILE_32BIT_MACHINE | IMAGE_FILE_EXECUTABLE_IMAGE;
+        file.Characteristics = IMAGE_FILE_DLL | IMAGE_FILE_32BIT_MACHINE | IMAGE_FILE_EXECUTABLE_IMAGE;
 
         let mut optional: IMAGE_OPTIONAL_HEADER32 = zeroed();
         optional.Magic = IMAGE_NT_OPTIONAL_HDR32_MAGIC;
@@ -39,9 +35,7 @@ pub fn write(mut tables: Vec<u8>, mut strings: Vec<u8>, mut blobs: Vec<u8>) -> V
         optional.MinorSubsystemVersion = 2;
         optional.SizeOfHeaders = 512;
         optional.Subsystem = IMAGE_SUBSYSTEM_WINDOWS_CUI;
-        optional.DllCharacteristics = IMAGE_DLLCHARACTERISTICS_NX_COMPAT
-            | IMAGE_DLLCHARACTERISTICS_NO_SEH
-            | IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE;
+        optional.DllCharacteristics = IMAGE_DLLCHARACTERISTICS_NX_COMPAT | IMAGE_DLLCHARACTERISTICS_NO_SEH | IMAGE_DLLCHARACTERISTICS_DYNAMIC_BASE;
         optional.SizeOfStackReserve = 0x100000;
         optional.SizeOfHeapReserve = 4096;
         optional.LoaderFlags = 0x100000;
@@ -73,25 +67,14 @@ pub fn write(mut tables: Vec<u8>, mut strings: Vec<u8>, mut blobs: Vec<u8>) -> V
         type GuidsHeader = StreamHeader<8>;
         type BlobsHeader = StreamHeader<8>;
 
-        let size_of_stream_headers = size_of::<TablesHeader>()
-            + size_of::<StringsHeader>()
-            + size_of::<GuidsHeader>()
-            + size_of::<BlobsHeader>();
-        let size_of_image = optional.FileAlignment as usize
-            + size_of::<IMAGE_COR20_HEADER>()
-            + size_of::<METADATA_HEADER>()
-            + size_of_stream_headers
-            + size_of_streams;
+        let size_of_stream_headers = size_of::<TablesHeader>() + size_of::<StringsHeader>() + size_of::<GuidsHeader>() + size_of::<BlobsHeader>();
+        let size_of_image = optional.FileAlignment as usize + size_of::<IMAGE_COR20_HEADER>() + size_of::<METADATA_HEADER>() + size_of_stream_headers + size_of_streams;
 
         optional.SizeOfImage = round(size_of_image, optional.SectionAlignment as _) as _;
         section.Misc.VirtualSize = size_of_image as u32 - optional.FileAlignment;
-        section.SizeOfRawData =
-            round(section.Misc.VirtualSize as _, optional.FileAlignment as _) as _;
+        section.SizeOfRawData = round(section.Misc.VirtualSize as _, optional.FileAlignment as _) as _;
 
-        optional.DataDirectory[14] = IMAGE_DATA_DIRECTORY {
-            VirtualAddress: SECTION_ALIGNMENT,
-            Size: size_of::<IMAGE_COR20_HEADER>() as _,
-        };
+        optional.DataDirectory[14] = IMAGE_DATA_DIRECTORY { VirtualAddress: SECTION_ALIGNMENT, Size: size_of::<IMAGE_COR20_HEADER>() as _ };
         section.PointerToRawData = optional.FileAlignment;
         clr.MetaData.VirtualAddress = SECTION_ALIGNMENT + size_of::<IMAGE_COR20_HEADER>() as u32;
         clr.MetaData.Size = section.Misc.VirtualSize - size_of::<IMAGE_COR20_HEADER>() as u32;
@@ -111,18 +94,9 @@ pub fn write(mut tables: Vec<u8>, mut strings: Vec<u8>, mut blobs: Vec<u8>) -> V
 
         let stream_offset = buffer.len() - metadata_offset + size_of_stream_headers;
         let tables_header = TablesHeader::new(stream_offset as _, tables.len() as _, b"#~\0\0");
-        let strings_header = StringsHeader::new(
-            tables_header.next_offset(),
-            strings.len() as _,
-            b"#Strings\0\0\0\0",
-        );
-        let guids_header = GuidsHeader::new(
-            strings_header.next_offset(),
-            guids.len() as _,
-            b"#GUID\0\0\0",
-        );
-        let blobs_header =
-            BlobsHeader::new(guids_header.next_offset(), blobs.len() as _, b"#Blob\0\0\0");
+        let strings_header = StringsHeader::new(tables_header.next_offset(), strings.len() as _, b"#Strings\0\0\0\0");
+        let guids_header = GuidsHeader::new(strings_header.next_offset(), guids.len() as _, b"#GUID\0\0\0");
+        let blobs_header = BlobsHeader::new(guids_header.next_offset(), blobs.len() as _, b"#Blob\0\0\0");
 
         buffer.write_header(&tables_header);
         buffer.write_header(&strings_header);
@@ -152,11 +126,7 @@ struct StreamHeader<const LEN: usize> {
 
 impl<const LEN: usize> StreamHeader<LEN> {
     fn new(offset: u32, size: u32, name: &[u8; LEN]) -> Self {
-        Self {
-            offset,
-            size,
-            name: *name,
-        }
+        Self { offset, size, name: *name }
     }
     fn next_offset(&self) -> u32 {
         self.offset + self.size
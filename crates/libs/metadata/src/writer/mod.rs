@@ -0,0 +1,621 @@
+mod blobs;
+mod codes;
+mod file;
+mod strings;
+mod tables;
+mod traits;
+mod r#type;
+
+use crate::imp::*;
+use blobs::Blobs;
+pub use codes::*;
+pub use r#type::*;
+use std::collections::HashMap;
+use strings::Strings;
+pub use tables::*;
+use traits::*;
+
+pub struct Writer {
+    pub blobs: Blobs,
+    pub strings: Strings,
+    pub tables: Tables,
+    pub scopes: HashMap<String, u32>,
+    pub references: HashMap<String, HashMap<String, u32>>,
+    pub constructors: HashMap<(u32, u32), u32>,
+    pub module_refs: HashMap<String, u32>,
+    pub type_specs: HashMap<u32, u32>,
+    pub assembly_refs: HashMap<String, u32>,
+}
+
+impl Writer {
+    pub fn new(name: &str) -> Self {
+        let mut writer = Self {
+            blobs: Default::default(),
+            strings: Default::default(),
+            tables: Default::default(),
+            scopes: Default::default(),
+            references: Default::default(),
+            constructors: Default::default(),
+            module_refs: Default::default(),
+            type_specs: Default::default(),
+            assembly_refs: Default::default(),
+        };
+
+        writer.tables.TypeDef.push(TypeDef { TypeName: writer.strings.insert("<Module>"), ..Default::default() });
+
+        let name = name.rsplit_once(['/', '\\']).map_or(name, |(_, name)| name);
+
+        writer.tables.Module.push(Module { Name: writer.strings.insert(name), Mvid: 1, ..Default::default() });
+
+        let name = name.rsplit_once('.').map_or(name, |(name, _)| name);
+
+        writer.tables.Assembly.push(Assembly {
+            Name: writer.strings.insert(name),
+            HashAlgId: 0x00008004,
+            MajorVersion: 0xFF,
+            MinorVersion: 0xFF,
+            BuildNumber: 0xFF,
+            RevisionNumber: 0xFF,
+            Flags: crate::AssemblyFlags::WindowsRuntime.0,
+            ..Default::default()
+        });
+
+        // Some winmd parsers will fail to read without an `mscorlib` reference. The `insert_module_types` function will typically include it
+        // automatically but a minimal `Module` tree may not add this dependency.
+        writer.insert_scope("System");
+
+        writer
+    }
+
+    pub fn into_stream(self) -> Vec<u8> {
+        file::write(self.tables.into_stream(), self.strings.into_stream(), self.blobs.into_stream())
+    }
+
+    // fn insert_module_types(&mut self, module: &'a Module) {
+    //     for (name, def) in &module.types {
+    //         self.insert_type_def(&module.namespace, name, def);
+    //     }
+    //     module.modules.values().for_each(|module| self.insert_module_types(module));
+    // }
+
+    // fn insert_type_def(&mut self, namespace: &'a str, name: &'a str, def: &'a [TypeDef]) {
+    //     for def in def {
+    //         let extends = if let Some(extends) = &def.extends { self.insert_type_ref(&extends.namespace, &extends.name) } else { 0 };
+    //         self.tables.TypeDef.push(TypeDef {
+    //             Flags: def.flags.0,
+    //             TypeName: self.strings.insert(name),
+    //             TypeNamespace: self.strings.insert(namespace),
+    //             Extends: extends,
+    //             FieldList: self.tables.Field.len() as _,
+    //             MethodList: self.tables.MethodDef.len() as _,
+    //         });
+    //         for field in &def.fields {
+    //             let blob = self.insert_field_sig(&field.ty);
+    //             let parent = self.tables.Field.push2(Field { Flags: field.flags.0, Name: self.strings.insert(&field.name), Signature: blob });
+    //             if let Some(value) = &field.value {
+    //                 let blob = self.insert_value_blob(value);
+    //                 self.tables.Constant.push(Constant { Type: value.to_code(), Parent: HasConstant::Field(parent).encode(), Value: blob });
+    //             }
+    //         }
+    //         for method in &def.methods {
+    //             let blob = self.insert_method_sig(method);
+    //             self.tables.MethodDef.push(MethodDef { RVA: 0, ImplFlags: 0, Flags: method.flags.0, Name: self.strings.insert(&method.name), Signature: blob, ParamList: self.tables.Param.len() as _ });
+    //             for (sequence, param) in method.params.iter().enumerate() {
+    //                 self.tables.Param.push(Param { Flags: param.flags.0, Sequence: (sequence + 1) as _, Name: self.strings.insert(&param.name) });
+    //             }
+    //         }
+    //     }
+    // }
+
+    pub fn insert_value_blob(&mut self, value: &Value) -> u32 {
+        // TODO: can either cache in Writer, like we do for scopes and references, or regenerate each time.
+        // Profile once we can stress test this with field/method signatures.
+
+        let blob = match value {
+            Value::Bool(value) => vec![*value as u8],
+            Value::I8(value) => value.to_le_bytes().to_vec(),
+            Value::U8(value) => value.to_le_bytes().to_vec(),
+            Value::I16(value) => value.to_le_bytes().to_vec(),
+            Value::U16(value) => value.to_le_bytes().to_vec(),
+            Value::I32(value) => value.to_le_bytes().to_vec(),
+            Value::U32(value) => value.to_le_bytes().to_vec(),
+            Value::I64(value) => value.to_le_bytes().to_vec(),
+            Value::U64(value) => value.to_le_bytes().to_vec(),
+            Value::F32(value) => value.to_le_bytes().to_vec(),
+            Value::F64(value) => value.to_le_bytes().to_vec(),
+            // Unlike custom attribute arguments, string constants are UTF-16 encoded without a length prefix.
+            Value::String(value) => value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect(),
+            rest => unimplemented!("{rest:?}"),
+        };
+
+        self.blobs.insert(&blob)
+    }
+
+    pub fn insert_method_sig(&mut self, call_flags: crate::MethodCallAttributes, return_type: &Type, param_types: &[Type]) -> u32 {
+        let mut blob = vec![call_flags.0];
+        usize_blob(param_types.len(), &mut blob);
+        self.type_blob(return_type, &mut blob);
+        for ty in param_types {
+            self.type_blob(ty, &mut blob);
+        }
+
+        self.blobs.insert(&blob)
+    }
+
+    pub fn insert_attribute(&mut self, parent: HasAttribute, namespace: &str, name: &str, args: &[Value]) {
+        self.insert_attribute_with_named_args(parent, namespace, name, args, &[])
+    }
+
+    /// Like `insert_attribute` but also accepts named arguments, which set fields or properties of the attribute
+    /// rather than being passed to its constructor.
+    pub fn insert_attribute_with_named_args(&mut self, parent: HasAttribute, namespace: &str, name: &str, args: &[Value], named_args: &[(String, Value)]) {
+        let mut signature = vec![crate::MethodCallAttributes::HASTHIS.0];
+        usize_blob(args.len(), &mut signature);
+        signature.push(ELEMENT_TYPE_VOID as _);
+
+        // See II.23.3 in ECMA-335 for the custom attribute value blob layout.
+        let mut value = vec![0x01, 0x00]; // prolog
+
+        for arg in args {
+            match arg {
+                Value::TypeName(_) => {
+                    let code = self.insert_type_ref("System", "Type");
+                    signature.push(ELEMENT_TYPE_CLASS as _);
+                    usize_blob(code as _, &mut signature);
+                }
+                Value::EnumRef(ty, _) => {
+                    let code = self.insert_type_ref(&ty.namespace, &ty.name);
+                    signature.push(ELEMENT_TYPE_VALUETYPE as _);
+                    usize_blob(code as _, &mut signature);
+                }
+                rest => signature.push(rest.to_code() as _),
+            }
+
+            attribute_value_blob(arg, &mut value);
+        }
+
+        value.extend_from_slice(&(named_args.len() as u16).to_le_bytes());
+
+        for (name, arg) in named_args {
+            value.push(0x53); // FIELD
+
+            match arg {
+                Value::TypeName(_) => value.push(0x50),
+                Value::EnumRef(ty, _) => {
+                    value.push(0x55);
+                    ser_string(&format!("{}.{}", ty.namespace, ty.name), &mut value);
+                }
+                rest => value.push(rest.to_code() as _),
+            }
+
+            ser_string(name, &mut value);
+            attribute_value_blob(arg, &mut value);
+        }
+
+        let constructor = self.insert_constructor(namespace, name, &signature);
+
+        self.tables.CustomAttribute.push(CustomAttribute { Parent: parent.encode(), Type: AttributeType::MemberRef(constructor).encode(), Value: self.blobs.insert(&value) });
+    }
+
+    fn insert_constructor(&mut self, namespace: &str, name: &str, signature: &[u8]) -> u32 {
+        // `insert_type_ref` returns an encoded `TypeDefOrRef` so this recovers the TypeRef row.
+        let class = (self.insert_type_ref(namespace, name) >> 2) - 1;
+
+        let signature = self.blobs.insert(signature);
+
+        if let Some(constructor) = self.constructors.get(&(class, signature)) {
+            return *constructor;
+        }
+
+        let constructor = self.tables.MemberRef.push2(MemberRef { Class: MemberRefParent::TypeRef(class).encode(), Name: self.strings.insert(".ctor"), Signature: signature });
+
+        self.constructors.insert((class, signature), constructor);
+        constructor
+    }
+
+    /// Returns the encoded `TypeDefOrRef` for the type, adding a `TypeSpec` row for generic instances.
+    pub fn insert_type_def_or_ref(&mut self, ty: &Type) -> u32 {
+        match ty {
+            Type::TypeRef(ty) if ty.generics.is_empty() => self.insert_type_ref(&ty.namespace, &ty.name),
+            Type::TypeDef(row) => TypeDefOrRef::TypeDef(*row).encode(),
+            _ => {
+                let mut blob = vec![];
+                self.type_blob(ty, &mut blob);
+                let signature = self.blobs.insert(&blob);
+
+                if let Some(spec) = self.type_specs.get(&signature) {
+                    return *spec;
+                }
+
+                let spec = TypeDefOrRef::TypeSpec(self.tables.TypeSpec.push2(TypeSpec { Signature: signature })).encode();
+
+                self.type_specs.insert(signature, spec);
+                spec
+            }
+        }
+    }
+
+    pub fn insert_field_sig(&mut self, ty: &Type) -> u32 {
+        // TODO: can either cache in Writer, like we do for scopes and references, or regenerate each time.
+        // Profile once we can stress test this with field/method signatures.
+
+        let mut blob = vec![0x6]; // FIELD
+        self.type_blob(ty, &mut blob);
+
+        self.blobs.insert(&blob)
+    }
+
+    pub fn insert_property_sig(&mut self, ty: &Type) -> u32 {
+        // WinRT properties are instance properties without parameters.
+        let mut blob = vec![0x8 | crate::MethodCallAttributes::HASTHIS.0, 0]; // PROPERTY
+        self.type_blob(ty, &mut blob);
+
+        self.blobs.insert(&blob)
+    }
+
+    fn insert_scope(&mut self, namespace: &str) -> u32 {
+        if let Some(scope) = self.scopes.get(namespace) {
+            *scope
+        } else if namespace == "System" {
+            let scope = ResolutionScope::AssemblyRef(self.tables.AssemblyRef.push2(AssemblyRef {
+                Name: self.strings.insert("mscorlib"),
+                MajorVersion: 4,
+                PublicKeyOrToken: self.blobs.insert(&[0xB7, 0x7A, 0x5C, 0x56, 0x19, 0x34, 0xE0, 0x89]),
+                ..Default::default()
+            }))
+            .encode();
+            self.scopes.insert(namespace.to_string(), scope);
+            scope
+        } else {
+            // TODO: may need to capture the original assembly info for external references.
+            let scope = self.insert_assembly_ref(namespace);
+            self.scopes.insert(namespace.to_string(), scope);
+            scope
+        }
+    }
+
+    /// Returns the encoded `ResolutionScope` for the WinRT assembly with the given name, such as "Windows.Foundation".
+    pub fn insert_assembly_ref(&mut self, name: &str) -> u32 {
+        if let Some(scope) = self.assembly_refs.get(name) {
+            *scope
+        } else {
+            let scope = ResolutionScope::AssemblyRef(self.tables.AssemblyRef.push2(AssemblyRef {
+                Name: self.strings.insert(name),
+                MajorVersion: 0xFF,
+                MinorVersion: 0xFF,
+                BuildNumber: 0xFF,
+                RevisionNumber: 0xFF,
+                Flags: crate::AssemblyFlags::WindowsRuntime.0,
+                ..Default::default()
+            }))
+            .encode();
+            self.assembly_refs.insert(name.to_string(), scope);
+            scope
+        }
+    }
+
+    /// Returns the `ModuleRef` row for the given library name, such as "kernel32.dll".
+    pub fn insert_module_ref(&mut self, name: &str) -> u32 {
+        if let Some(module_ref) = self.module_refs.get(name) {
+            *module_ref
+        } else {
+            let module_ref = self.tables.ModuleRef.push2(ModuleRef { Name: self.strings.insert(name) });
+            self.module_refs.insert(name.to_string(), module_ref);
+            module_ref
+        }
+    }
+
+    pub fn insert_type_ref(&mut self, namespace: &str, name: &str) -> u32 {
+        if let Some(key) = self.references.get(namespace) {
+            if let Some(reference) = key.get(name) {
+                return *reference;
+            }
+        }
+
+        let scope = self.insert_scope(namespace);
+
+        let reference = TypeDefOrRef::TypeRef(self.tables.TypeRef.push2(TypeRef { TypeName: self.strings.insert(name), TypeNamespace: self.strings.insert(namespace), ResolutionScope: scope })).encode();
+        self.references.entry(namespace.to_string()).or_default().insert(name.to_string(), reference);
+        reference
+    }
+
+    fn type_blob(&mut self, ty: &Type, blob: &mut Vec<u8>) {
+        match ty {
+            Type::Void => blob.push(ELEMENT_TYPE_VOID as _),
+            Type::Bool => blob.push(ELEMENT_TYPE_BOOLEAN as _),
+            Type::Char => blob.push(ELEMENT_TYPE_CHAR as _),
+            Type::I8 => blob.push(ELEMENT_TYPE_I1 as _),
+            Type::U8 => blob.push(ELEMENT_TYPE_U1 as _),
+            Type::I16 => blob.push(ELEMENT_TYPE_I2 as _),
+            Type::U16 => blob.push(ELEMENT_TYPE_U2 as _),
+            Type::I32 => blob.push(ELEMENT_TYPE_I4 as _),
+            Type::U32 => blob.push(ELEMENT_TYPE_U4 as _),
+            Type::I64 => blob.push(ELEMENT_TYPE_I8 as _),
+            Type::U64 => blob.push(ELEMENT_TYPE_U8 as _),
+            Type::F32 => blob.push(ELEMENT_TYPE_R4 as _),
+            Type::F64 => blob.push(ELEMENT_TYPE_R8 as _),
+            Type::ISize => blob.push(ELEMENT_TYPE_I as _),
+            Type::USize => blob.push(ELEMENT_TYPE_U as _),
+            Type::String => blob.push(ELEMENT_TYPE_STRING as _),
+            Type::IInspectable => blob.push(ELEMENT_TYPE_OBJECT as _),
+            Type::GUID => {
+                let code = self.insert_type_ref("System", "Guid");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::HRESULT => {
+                let code = self.insert_type_ref("Windows.Foundation", "HResult");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::TypeRef(ty) if ty.generics.is_empty() => {
+                let code = self.insert_type_ref(&ty.namespace, &ty.name);
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::TypeRef(ty) => {
+                // Generic instances are always interfaces or delegates, so they are reference types.
+                let code = self.insert_type_ref(&ty.namespace, &ty.name);
+                blob.push(ELEMENT_TYPE_GENERICINST as _);
+                blob.push(ELEMENT_TYPE_CLASS as _);
+                usize_blob(code as _, blob);
+                usize_blob(ty.generics.len(), blob);
+                for ty in &ty.generics {
+                    self.type_blob(ty, blob);
+                }
+            }
+            Type::TypeDef(row) => {
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(TypeDefOrRef::TypeDef(*row).encode() as _, blob);
+            }
+            Type::GenericParam(number) => {
+                blob.push(ELEMENT_TYPE_VAR as _);
+                usize_blob(*number as _, blob);
+            }
+            Type::BSTR => {
+                let code = self.insert_type_ref("Windows.Win32.Foundation", "BSTR");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::IUnknown => {
+                let code = self.insert_type_ref("Windows.Win32.System.Com", "IUnknown");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::PCWSTR | Type::PWSTR => {
+                let code = self.insert_type_ref("Windows.Win32.Foundation", "PWSTR");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::PCSTR | Type::PSTR => {
+                let code = self.insert_type_ref("Windows.Win32.Foundation", "PSTR");
+                blob.push(ELEMENT_TYPE_VALUETYPE as _);
+                usize_blob(code as _, blob);
+            }
+            Type::ConstRef(ty) => {
+                usize_blob(ELEMENT_TYPE_CMOD_OPT as _, blob);
+                usize_blob(self.insert_type_ref("System.Runtime.CompilerServices", "IsConst") as _, blob);
+                usize_blob(ELEMENT_TYPE_BYREF as _, blob);
+                self.type_blob(ty, blob);
+            }
+            Type::WinrtArrayRef(ty) => {
+                usize_blob(ELEMENT_TYPE_BYREF as _, blob);
+                usize_blob(ELEMENT_TYPE_SZARRAY as _, blob);
+                self.type_blob(ty, blob);
+            }
+            Type::WinrtArray(ty) => {
+                usize_blob(ELEMENT_TYPE_SZARRAY as _, blob);
+                self.type_blob(ty, blob);
+            }
+            Type::RefMut(ty) => {
+                usize_blob(ELEMENT_TYPE_BYREF as _, blob);
+                self.type_blob(ty, blob);
+            }
+            Type::Win32Array(ty, bounds) => {
+                usize_blob(ELEMENT_TYPE_ARRAY as _, blob);
+                self.type_blob(ty, blob);
+                usize_blob(1, blob); // rank
+                usize_blob(1, blob); // count
+                usize_blob(*bounds as _, blob);
+            }
+            Type::TypeName => {
+                let code = self.insert_type_ref("System", "Type");
+                blob.push(ELEMENT_TYPE_CLASS as _);
+                usize_blob(code as _, blob);
+            }
+            Type::MutPtr(ty, pointers) | Type::ConstPtr(ty, pointers) => {
+                for _ in 0..*pointers {
+                    usize_blob(ELEMENT_TYPE_PTR as _, blob);
+                }
+                self.type_blob(ty, blob);
+            }
+        }
+    }
+}
+
+fn attribute_value_blob(value: &Value, blob: &mut Vec<u8>) {
+    match value {
+        Value::Bool(value) => blob.push(*value as _),
+        Value::U8(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::I8(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::U16(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::I16(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::U32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::I32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::U64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::I64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::F32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::F64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+        Value::String(value) | Value::TypeName(value) => ser_string(value, blob),
+        Value::EnumRef(_, value) => blob.extend_from_slice(&value.to_le_bytes()),
+    }
+}
+
+// See II.23.3 in ECMA-335 - unlike constants, attribute strings are UTF-8 encoded with a length prefix.
+fn ser_string(value: &str, blob: &mut Vec<u8>) {
+    usize_blob(value.len(), blob);
+    blob.extend_from_slice(value.as_bytes());
+}
+
+fn round(size: usize, round: usize) -> usize {
+    let round = round - 1;
+    (size + round) & !round
+}
+
+fn usize_blob(value: usize, blob: &mut Vec<u8>) {
+    // See II.23.2 in ECMA-335
+    assert!(value < 0x20000000);
+
+    if value < 0x80 {
+        blob.push(value as _);
+    } else if value < 0x4000 {
+        blob.push((0x80 | (value & 0x3F00) >> 8) as _);
+        blob.push((value & 0xFF) as _);
+    } else {
+        blob.push((0xC0 | (value & 0x1F000000) >> 24) as _);
+        blob.push(((value & 0xFF0000) >> 16) as _);
+        blob.push(((value & 0xFF00) >> 8) as _);
+        blob.push((value & 0xFF) as _);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usize_blob() {
+        let mut blob = vec![];
+        usize_blob(0, &mut blob);
+        usize_blob(1, &mut blob);
+        usize_blob(2, &mut blob);
+
+        usize_blob(0x80 - 2, &mut blob);
+        usize_blob(0x80 - 1, &mut blob);
+        usize_blob(0x80, &mut blob);
+        usize_blob(0x80 + 1, &mut blob);
+        usize_blob(0x80 + 2, &mut blob);
+
+        usize_blob(0x4000 - 2, &mut blob);
+        usize_blob(0x4000 - 1, &mut blob);
+        usize_blob(0x4000, &mut blob);
+        usize_blob(0x4000 + 1, &mut blob);
+        usize_blob(0x4000 + 2, &mut blob);
+
+        usize_blob(0x20000000 - 3, &mut blob);
+        usize_blob(0x20000000 - 2, &mut blob);
+        usize_blob(0x20000000 - 1, &mut blob);
+
+        let mut blob = crate::Blob::new(0, &blob);
+        assert_eq!(blob.read_usize(), 0);
+        assert_eq!(blob.read_usize(), 1);
+        assert_eq!(blob.read_usize(), 2);
+
+        assert_eq!(blob.read_usize(), 0x80 - 2);
+        assert_eq!(blob.read_usize(), 0x80 - 1);
+        assert_eq!(blob.read_usize(), 0x80);
+        assert_eq!(blob.read_usize(), 0x80 + 1);
+        assert_eq!(blob.read_usize(), 0x80 + 2);
+
+        assert_eq!(blob.read_usize(), 0x4000 - 2);
+        assert_eq!(blob.read_usize(), 0x4000 - 1);
+        assert_eq!(blob.read_usize(), 0x4000);
+        assert_eq!(blob.read_usize(), 0x4000 + 1);
+        assert_eq!(blob.read_usize(), 0x4000 + 2);
+
+        assert_eq!(blob.read_usize(), 0x20000000 - 3);
+        assert_eq!(blob.read_usize(), 0x20000000 - 2);
+        assert_eq!(blob.read_usize(), 0x20000000 - 1);
+
+        assert_eq!(blob.slice.len(), 0);
+    }
+
+    #[test]
+    fn test_named_attribute_args() {
+        let mut writer = Writer::new("test.winmd");
+
+        let def = writer.tables.TypeDef.push2(TypeDef {
+            Flags: crate::TypeAttributes::Public.0,
+            TypeName: writer.strings.insert("Name"),
+            TypeNamespace: writer.strings.insert("Namespace"),
+            ..Default::default()
+        });
+
+        writer.insert_attribute_with_named_args(HasAttribute::TypeDef(def), "Namespace", "TestAttribute", &[Value::U32(1)], &[("Index".to_string(), Value::I16(2)), ("Text".to_string(), Value::String("three".to_string()))]);
+
+        let files = [crate::File::new(writer.into_stream()).unwrap()];
+        let reader = &crate::Reader::new(&files);
+        let def = reader.get(crate::TypeName::new("Namespace", "Name")).next().unwrap();
+        let attribute = reader.type_def_attributes(def).next().unwrap();
+        let args = reader.attribute_args(attribute);
+
+        assert_eq!(reader.attribute_name(attribute), "TestAttribute");
+        assert_eq!(args.len(), 3);
+        assert!(matches!(&args[0], (name, crate::Value::U32(1)) if name.is_empty()));
+        assert!(matches!(&args[1], (name, crate::Value::I16(2)) if name == "Index"));
+        assert!(matches!(&args[2], (name, crate::Value::String(value)) if name == "Text" && value == "three"));
+    }
+
+    #[test]
+    fn test_property_and_event() {
+        let mut writer = Writer::new("test.winmd");
+
+        let def = writer.tables.TypeDef.push2(TypeDef {
+            Flags: (crate::TypeAttributes::Public | crate::TypeAttributes::Interface).0,
+            TypeName: writer.strings.insert("Name"),
+            TypeNamespace: writer.strings.insert("Namespace"),
+            ..Default::default()
+        });
+
+        let mut methods = vec![];
+
+        for name in ["get_Value", "put_Value", "add_Changed", "remove_Changed"] {
+            let signature = writer.insert_method_sig(crate::MethodCallAttributes::HASTHIS, &Type::Void, &[]);
+
+            methods.push(writer.tables.MethodDef.push2(MethodDef { Flags: crate::MethodAttributes::SpecialName.0, Name: writer.strings.insert(name), Signature: signature, ..Default::default() }));
+        }
+
+        writer.tables.PropertyMap.push(PropertyMap { Parent: def, PropertyList: 0 });
+
+        let signature = writer.insert_property_sig(&Type::I32);
+        let property = writer.tables.Property.push2(Property { Flags: 0, Name: writer.strings.insert("Value"), Type: signature });
+
+        writer.tables.EventMap.push(EventMap { Parent: def, EventList: 0 });
+
+        let event_type = writer.insert_type_ref("Windows.Foundation", "EventHandler");
+        let event = writer.tables.Event.push2(Event { EventFlags: 0, Name: writer.strings.insert("Changed"), EventType: event_type });
+
+        // Rows are written out of order to check that the `MethodSemantics` table is sorted.
+        for (semantics, method, association) in [(crate::MethodSemanticsAttributes::RemoveOn, methods[3], HasSemantics::Event(event)), (crate::MethodSemanticsAttributes::Getter, methods[0], HasSemantics::Property(property)), (crate::MethodSemanticsAttributes::AddOn, methods[2], HasSemantics::Event(event)), (crate::MethodSemanticsAttributes::Setter, methods[1], HasSemantics::Property(property))] {
+            writer.tables.MethodSemantics.push(MethodSemantics { Semantics: semantics.0, Method: method, Association: association.encode() });
+        }
+
+        let files = [crate::File::new(writer.into_stream()).unwrap()];
+        let reader = &crate::Reader::new(&files);
+        let def = reader.get(crate::TypeName::new("Namespace", "Name")).next().unwrap();
+
+        let properties: Vec<crate::Property> = reader.type_def_properties(def).collect();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(reader.property_name(properties[0]), "Value");
+        assert_eq!(reader.property_type(properties[0], &[]), crate::Type::I32);
+
+        let getter = reader.property_getter(properties[0]).unwrap();
+        assert_eq!(reader.method_def_name(getter), "get_Value");
+        let setter = reader.property_setter(properties[0]).unwrap();
+        assert_eq!(reader.method_def_name(setter), "put_Value");
+
+        let events: Vec<crate::Event> = reader.type_def_events(def).collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(reader.event_name(events[0]), "Changed");
+
+        let crate::Type::TypeRef(event_type) = reader.event_type(events[0], &[]) else { panic!("wrong type") };
+        let event_type = reader.type_def_or_ref(event_type);
+        assert_eq!(event_type.namespace, "Windows.Foundation");
+        assert_eq!(event_type.name, "EventHandler");
+
+        let add = reader.event_add(events[0]).unwrap();
+        assert_eq!(reader.method_def_name(add), "add_Changed");
+        let remove = reader.event_remove(events[0]).unwrap();
+        assert_eq!(reader.method_def_name(remove), "remove_Changed");
+    }
+}
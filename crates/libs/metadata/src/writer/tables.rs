@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use super::Write;
-use metadata::imp::coded_index_size;
+use crate::imp::coded_index_size;
 
 #[derive(Default)]
 pub struct Tables {
@@ -10,17 +10,21 @@ pub struct Tables {
     pub ClassLayout: Vec<ClassLayout>,
     pub Constant: Vec<Constant>,
     pub CustomAttribute: Vec<CustomAttribute>,
+    pub Event: Vec<Event>,
+    pub EventMap: Vec<EventMap>,
     pub Field: Vec<Field>,
     pub GenericParam: Vec<GenericParam>,
     pub ImplMap: Vec<ImplMap>,
     pub InterfaceImpl: Vec<InterfaceImpl>,
     pub MemberRef: Vec<MemberRef>,
     pub MethodDef: Vec<MethodDef>,
+    pub MethodSemantics: Vec<MethodSemantics>,
     pub Module: Vec<Module>,
     pub ModuleRef: Vec<ModuleRef>,
     pub NestedClass: Vec<NestedClass>,
     pub Param: Vec<Param>,
     pub Property: Vec<Property>,
+    pub PropertyMap: Vec<PropertyMap>,
     pub TypeDef: Vec<TypeDef>,
     pub TypeRef: Vec<TypeRef>,
     pub TypeSpec: Vec<TypeSpec>,
@@ -73,6 +77,19 @@ pub struct CustomAttribute {
     pub Value: u32,
 }
 
+#[derive(Default)]
+pub struct Event {
+    pub EventFlags: u16,
+    pub Name: u32,
+    pub EventType: u32,
+}
+
+#[derive(Default)]
+pub struct EventMap {
+    pub Parent: u32,
+    pub EventList: u32,
+}
+
 #[derive(Default)]
 pub struct Field {
     pub Flags: u16,
@@ -119,6 +136,13 @@ pub struct MethodDef {
     pub ParamList: u32,
 }
 
+#[derive(Default)]
+pub struct MethodSemantics {
+    pub Semantics: u16,
+    pub Method: u32,
+    pub Association: u32,
+}
+
 #[derive(Default)]
 pub struct Module {
     pub Generation: u16,
@@ -153,6 +177,12 @@ pub struct Property {
     pub Type: u32,
 }
 
+#[derive(Default)]
+pub struct PropertyMap {
+    pub Parent: u32,
+    pub PropertyList: u32,
+}
+
 #[derive(Default)]
 pub struct TypeDef {
     pub Flags: u32,
@@ -176,23 +206,27 @@ pub struct TypeSpec {
 }
 
 impl Tables {
-    pub fn into_stream(self) -> Vec<u8> {
+    pub fn into_stream(mut self) -> Vec<u8> {
         if [
             self.AssemblyRef.len(),
             self.ClassLayout.len(),
             self.Constant.len(),
             self.CustomAttribute.len(),
+            self.Event.len(),
+            self.EventMap.len(),
             self.Field.len(),
             self.GenericParam.len(),
             self.ImplMap.len(),
             self.InterfaceImpl.len(),
             self.MemberRef.len(),
             self.MethodDef.len(),
+            self.MethodSemantics.len(),
             self.Module.len(),
             self.ModuleRef.len(),
             self.NestedClass.len(),
             self.Param.len(),
             self.Property.len(),
+            self.PropertyMap.len(),
             self.TypeDef.len(),
             self.TypeRef.len(),
             self.TypeSpec.len(),
@@ -203,16 +237,54 @@ impl Tables {
             panic!("metadata table too large");
         }
 
-        let resolution_scope = coded_index_size(&[
+        let resolution_scope = coded_index_size(&[self.Module.len(), self.ModuleRef.len(), self.AssemblyRef.len(), self.TypeRef.len()]);
+        let type_def_or_ref = coded_index_size(&[self.TypeDef.len(), self.TypeRef.len(), self.TypeSpec.len()]);
+        let has_constant = coded_index_size(&[self.Field.len(), self.Param.len(), self.Property.len()]);
+        let has_custom_attribute = coded_index_size(&[
+            self.MethodDef.len(),
+            self.Field.len(),
+            self.TypeRef.len(),
+            self.TypeDef.len(),
+            self.Param.len(),
+            self.InterfaceImpl.len(),
+            self.MemberRef.len(),
             self.Module.len(),
+            0, // Permission
+            self.Property.len(),
+            self.Event.len(),
+            0, // StandAloneSig
             self.ModuleRef.len(),
+            self.TypeSpec.len(),
+            self.Assembly.len(),
             self.AssemblyRef.len(),
-            self.TypeRef.len(),
+            0, // File
+            0, // ExportedType
+            0, // ManifestResource
+            self.GenericParam.len(),
+            0, // GenericParamConstraint
+            0, // MethodSpec
         ]);
-        let type_def_or_ref =
-            coded_index_size(&[self.TypeDef.len(), self.TypeRef.len(), self.TypeSpec.len()]);
-        let has_constant =
-            coded_index_size(&[self.Field.len(), self.Param.len(), self.Property.len()]);
+        let custom_attribute_type = coded_index_size(&[0, 0, self.MethodDef.len(), self.MemberRef.len(), 0]);
+        let member_ref_parent = coded_index_size(&[self.TypeDef.len(), self.TypeRef.len(), self.ModuleRef.len(), self.MethodDef.len(), self.TypeSpec.len()]);
+        let member_forwarded = coded_index_size(&[self.Field.len(), self.MethodDef.len()]);
+        let type_or_method_def = coded_index_size(&[self.TypeDef.len(), self.MethodDef.len()]);
+        let has_semantics = coded_index_size(&[self.Event.len(), self.Property.len()]);
+
+        let type_def_len = self.TypeDef.len();
+        let module_ref_len = self.ModuleRef.len();
+        let method_def_len = self.MethodDef.len();
+        let event_len = self.Event.len();
+        let property_len = self.Property.len();
+
+        // These tables must be sorted by their parent column (see II.22 in ECMA-335). No other rows refer to
+        // them by index, so they can be sorted here. A stable sort preserves the order of rows with the same parent.
+        self.Constant.sort_by_key(|x| x.Parent);
+        self.CustomAttribute.sort_by_key(|x| x.Parent);
+        self.ClassLayout.sort_by_key(|x| x.Parent);
+        self.ImplMap.sort_by_key(|x| x.MemberForwarded);
+        self.NestedClass.sort_by_key(|x| x.NestedClass);
+        self.GenericParam.sort_by_key(|x| x.Owner);
+        self.MethodSemantics.sort_by_key(|x| x.Association);
 
         let valid_tables: u64 = 1 << 0 | // Module 
         1 << 0x01 | // TypeRef
@@ -225,7 +297,11 @@ impl Tables {
         1 << 0x0B | // Constant
         1 << 0x0C | // CustomAttribute
         1 << 0x0F | // ClassLayout
+        1 << 0x12 | // EventMap
+        1 << 0x14 | // Event
+        1 << 0x15 | // PropertyMap
         1 << 0x17 | // Property
+        1 << 0x18 | // MethodSemantics
         1 << 0x1A | // ModuleRef
         1 << 0x1B | // TypeSpec
         1 << 0x1C | // ImplMap
@@ -258,7 +334,11 @@ impl Tables {
         buffer.write_u32(self.Constant.len() as _);
         buffer.write_u32(self.CustomAttribute.len() as _);
         buffer.write_u32(self.ClassLayout.len() as _);
+        buffer.write_u32(self.EventMap.len() as _);
+        buffer.write_u32(self.Event.len() as _);
+        buffer.write_u32(self.PropertyMap.len() as _);
         buffer.write_u32(self.Property.len() as _);
+        buffer.write_u32(self.MethodSemantics.len() as _);
         buffer.write_u32(self.ModuleRef.len() as _);
         buffer.write_u32(self.TypeSpec.len() as _);
         buffer.write_u32(self.ImplMap.len() as _);
@@ -313,12 +393,78 @@ impl Tables {
             buffer.write_u32(x.Name);
         }
 
+        for x in self.InterfaceImpl {
+            buffer.write_index(x.Class, type_def_len);
+            buffer.write_code(x.Interface, type_def_or_ref);
+        }
+
+        for x in self.MemberRef {
+            buffer.write_code(x.Class, member_ref_parent);
+            buffer.write_u32(x.Name);
+            buffer.write_u32(x.Signature);
+        }
+
         for x in self.Constant {
             buffer.write_u16(x.Type);
             buffer.write_code(x.Parent, has_constant);
             buffer.write_u32(x.Value);
         }
 
+        for x in self.CustomAttribute {
+            buffer.write_code(x.Parent, has_custom_attribute);
+            buffer.write_code(x.Type, custom_attribute_type);
+            buffer.write_u32(x.Value);
+        }
+
+        for x in self.ClassLayout {
+            buffer.write_u16(x.PackingSize);
+            buffer.write_u32(x.ClassSize);
+            buffer.write_index(x.Parent, type_def_len);
+        }
+
+        for x in self.EventMap {
+            buffer.write_index(x.Parent, type_def_len);
+            buffer.write_index(x.EventList, event_len);
+        }
+
+        for x in self.Event {
+            buffer.write_u16(x.EventFlags);
+            buffer.write_u32(x.Name);
+            buffer.write_code(x.EventType, type_def_or_ref);
+        }
+
+        for x in self.PropertyMap {
+            buffer.write_index(x.Parent, type_def_len);
+            buffer.write_index(x.PropertyList, property_len);
+        }
+
+        for x in self.Property {
+            buffer.write_u16(x.Flags);
+            buffer.write_u32(x.Name);
+            buffer.write_u32(x.Type);
+        }
+
+        for x in self.MethodSemantics {
+            buffer.write_u16(x.Semantics);
+            buffer.write_index(x.Method, method_def_len);
+            buffer.write_code(x.Association, has_semantics);
+        }
+
+        for x in self.ModuleRef {
+            buffer.write_u32(x.Name);
+        }
+
+        for x in self.TypeSpec {
+            buffer.write_u32(x.Signature);
+        }
+
+        for x in self.ImplMap {
+            buffer.write_u16(x.MappingFlags);
+            buffer.write_code(x.MemberForwarded, member_forwarded);
+            buffer.write_u32(x.ImportName);
+            buffer.write_index(x.ImportScope, module_ref_len);
+        }
+
         for x in self.Assembly {
             buffer.write_u32(x.HashAlgId);
             buffer.write_u16(x.MajorVersion);
@@ -343,6 +489,18 @@ impl Tables {
             buffer.write_u32(x.HashValue);
         }
 
+        for x in self.NestedClass {
+            buffer.write_index(x.NestedClass, type_def_len);
+            buffer.write_index(x.EnclosingClass, type_def_len);
+        }
+
+        for x in self.GenericParam {
+            buffer.write_u16(x.Number);
+            buffer.write_u16(x.Flags);
+            buffer.write_code(x.Owner, type_or_method_def);
+            buffer.write_u32(x.Name);
+        }
+
         buffer.into_stream()
     }
 }
@@ -13,10 +13,7 @@ pub trait Write {
 
 impl Write for Vec<u8> {
     unsafe fn write_header<T: Sized>(&mut self, value: &T) {
-        self.extend_from_slice(std::slice::from_raw_parts(
-            value as *const _ as _,
-            std::mem::size_of::<T>(),
-        ));
+        self.extend_from_slice(std::slice::from_raw_parts(value as *const _ as _, std::mem::size_of::<T>()));
     }
 
     fn write_u8(&mut self, value: u8) {
@@ -0,0 +1,118 @@
+#![allow(dead_code, clippy::upper_case_acronyms, clippy::enum_variant_names)]
+
+use crate::imp::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeName {
+    pub namespace: String,
+    pub name: String,
+    pub generics: Vec<Type>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type {
+    Void,
+    Bool,
+    Char,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    ISize,
+    USize,
+    String,
+    GUID,
+    IUnknown,
+    IInspectable,
+    HRESULT,
+    PSTR,
+    PWSTR,
+    PCSTR,
+    PCWSTR,
+    BSTR,
+    TypeName,
+    TypeRef(TypeName),
+    /// A type defined in the same file, identified by its `TypeDef` row. Nested types are referenced this way since
+    /// they can't be resolved by name alone.
+    TypeDef(u32),
+    /// A generic type parameter, identified by its position in the enclosing type's generic parameter list.
+    GenericParam(u16),
+    MutPtr(Box<Self>, usize),
+    ConstPtr(Box<Self>, usize),
+    Win32Array(Box<Self>, usize),
+    WinrtArray(Box<Self>),
+    WinrtArrayRef(Box<Self>),
+    ConstRef(Box<Self>),
+    RefMut(Box<Self>),
+}
+
+impl Type {
+    pub fn into_mut_ptr(self) -> Self {
+        match self {
+            Self::MutPtr(ty, count) => Self::MutPtr(ty, count + 1),
+            Self::ConstPtr(ty, count) => Self::MutPtr(ty, count + 1),
+            _ => Self::MutPtr(Box::new(self), 1),
+        }
+    }
+
+    pub fn into_const_ptr(self) -> Self {
+        match self {
+            Self::MutPtr(ty, count) => Self::ConstPtr(ty, count + 1),
+            Self::ConstPtr(ty, count) => Self::ConstPtr(ty, count + 1),
+            _ => Self::ConstPtr(Box::new(self), 1),
+        }
+    }
+
+    pub fn into_array(self, len: usize) -> Self {
+        Self::Win32Array(Box::new(self), len)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    /// The full name of a type, used for `System.Type` attribute arguments.
+    TypeName(String),
+    /// An enum attribute argument and its 32-bit underlying value.
+    EnumRef(TypeName, i32),
+}
+
+impl Value {
+    /// Returns the `ELEMENT_TYPE` (see ECMA-335) type constant used in the `Constant` table.
+    pub fn to_code(&self) -> u16 {
+        let code = match self {
+            Self::Bool(_) => ELEMENT_TYPE_BOOLEAN,
+            Self::U8(_) => ELEMENT_TYPE_U1,
+            Self::I8(_) => ELEMENT_TYPE_I1,
+            Self::U16(_) => ELEMENT_TYPE_U2,
+            Self::I16(_) => ELEMENT_TYPE_I2,
+            Self::U32(_) => ELEMENT_TYPE_U4,
+            Self::I32(_) => ELEMENT_TYPE_I4,
+            Self::U64(_) => ELEMENT_TYPE_U8,
+            Self::I64(_) => ELEMENT_TYPE_I8,
+            Self::F32(_) => ELEMENT_TYPE_R4,
+            Self::F64(_) => ELEMENT_TYPE_R8,
+            Self::String(_) => ELEMENT_TYPE_STRING,
+            rest => unimplemented!("{rest:?}"),
+        };
+
+        code as _
+    }
+}
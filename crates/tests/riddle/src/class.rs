@@ -0,0 +1,520 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+pub struct IBase(::windows_core::IUnknown);
+impl IBase {
+    pub fn BaseMethod(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).BaseMethod)(::windows_core::Interface::as_raw(
+                this,
+            ))
+            .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IBase,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IBase {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IBase {}
+impl ::core::fmt::Debug for IBase {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IBase").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IBase {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c01}");
+}
+unsafe impl ::windows_core::Interface for IBase {
+    type Vtable = IBase_Vtbl;
+}
+impl ::core::clone::Clone for IBase {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IBase {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c01);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IBase_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub BaseMethod:
+        unsafe extern "system" fn(this: *mut ::core::ffi::c_void) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IBaseFactory(::windows_core::IUnknown);
+impl IBaseFactory {
+    pub fn CreateInstance<P0>(
+        &self,
+        outer: P0,
+        inner: &mut ::core::option::Option<::windows_core::IInspectable>,
+    ) -> ::windows_core::Result<Base>
+    where
+        P0: ::windows_core::IntoParam<::windows_core::IInspectable>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).CreateInstance)(
+                ::windows_core::Interface::as_raw(this),
+                outer.into_param().abi(),
+                inner as *mut _ as _,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IBaseFactory,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IBaseFactory {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IBaseFactory {}
+impl ::core::fmt::Debug for IBaseFactory {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IBaseFactory").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IBaseFactory {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c02}");
+}
+unsafe impl ::windows_core::Interface for IBaseFactory {
+    type Vtable = IBaseFactory_Vtbl;
+}
+impl ::core::clone::Clone for IBaseFactory {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IBaseFactory {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c02);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IBaseFactory_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub CreateInstance: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        outer: *mut ::core::ffi::c_void,
+        inner: *mut *mut ::core::ffi::c_void,
+        result__: *mut *mut ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IOther(::windows_core::IUnknown);
+impl IOther {
+    pub fn Other(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Other)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IOther,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IOther {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IOther {}
+impl ::core::fmt::Debug for IOther {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IOther").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IOther {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c06}");
+}
+unsafe impl ::windows_core::Interface for IOther {
+    type Vtable = IOther_Vtbl;
+}
+impl ::core::clone::Clone for IOther {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IOther {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c06);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IOther_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Other: unsafe extern "system" fn(this: *mut ::core::ffi::c_void) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IWidget(::windows_core::IUnknown);
+impl IWidget {
+    pub fn Method(&self) -> ::windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Method)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IWidget,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IWidget {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IWidget {}
+impl ::core::fmt::Debug for IWidget {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IWidget").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IWidget {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c03}");
+}
+unsafe impl ::windows_core::Interface for IWidget {
+    type Vtable = IWidget_Vtbl;
+}
+impl ::core::clone::Clone for IWidget {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IWidget {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c03);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWidget_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Method: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        result__: *mut i32,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IWidgetFactory(::windows_core::IUnknown);
+impl IWidgetFactory {
+    pub fn CreateWidget(&self, value: i32) -> ::windows_core::Result<Widget> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).CreateWidget)(
+                ::windows_core::Interface::as_raw(this),
+                value,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IWidgetFactory,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IWidgetFactory {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IWidgetFactory {}
+impl ::core::fmt::Debug for IWidgetFactory {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IWidgetFactory").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IWidgetFactory {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c04}");
+}
+unsafe impl ::windows_core::Interface for IWidgetFactory {
+    type Vtable = IWidgetFactory_Vtbl;
+}
+impl ::core::clone::Clone for IWidgetFactory {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IWidgetFactory {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c04);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWidgetFactory_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub CreateWidget: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        value: i32,
+        result__: *mut *mut ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IWidgetStatics(::windows_core::IUnknown);
+impl IWidgetStatics {
+    pub fn StaticMethod(&self) -> ::windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).StaticMethod)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IWidgetStatics,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IWidgetStatics {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IWidgetStatics {}
+impl ::core::fmt::Debug for IWidgetStatics {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IWidgetStatics").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IWidgetStatics {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c05}");
+}
+unsafe impl ::windows_core::Interface for IWidgetStatics {
+    type Vtable = IWidgetStatics_Vtbl;
+}
+impl ::core::clone::Clone for IWidgetStatics {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IWidgetStatics {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x1bd7b2ca_3b7b_4d20_8f20_5a7b5d5d6c05);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWidgetStatics_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub StaticMethod: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        result__: *mut i32,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct Base(::windows_core::IUnknown);
+impl Base {
+    pub fn BaseMethod(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).BaseMethod)(::windows_core::Interface::as_raw(
+                this,
+            ))
+            .ok()
+        }
+    }
+}
+impl ::core::cmp::PartialEq for Base {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for Base {}
+impl ::core::fmt::Debug for Base {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Base").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for Base {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(
+            b"rc(Test.Base;{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c01})",
+        );
+}
+impl ::core::clone::Clone for Base {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::Interface for Base {
+    type Vtable = IBase_Vtbl;
+}
+unsafe impl ::windows_core::ComInterface for Base {
+    const IID: ::windows_core::GUID = <IBase as ::windows_core::ComInterface>::IID;
+}
+impl ::windows_core::RuntimeName for Base {
+    const NAME: &'static str = "Test.Base";
+}
+::windows_core::imp::interface_hierarchy!(
+    Base,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::windows_core::CanTryInto<IBase> for Base {}
+#[repr(transparent)]
+pub struct Widget(::windows_core::IUnknown);
+impl Widget {
+    pub fn new() -> ::windows_core::Result<Self> {
+        Self::IActivationFactory(|f| f.ActivateInstance::<Self>())
+    }
+    fn IActivationFactory<
+        R,
+        F: FnOnce(&::windows_core::imp::IGenericFactory) -> ::windows_core::Result<R>,
+    >(
+        callback: F,
+    ) -> ::windows_core::Result<R> {
+        static SHARED: ::windows_core::imp::FactoryCache<
+            Widget,
+            ::windows_core::imp::IGenericFactory,
+        > = ::windows_core::imp::FactoryCache::new();
+        SHARED.call(callback)
+    }
+    pub fn BaseMethod(&self) -> ::windows_core::Result<()> {
+        let this = &::windows_core::ComInterface::cast::<IBase>(self)?;
+        unsafe {
+            (::windows_core::Interface::vtable(this).BaseMethod)(::windows_core::Interface::as_raw(
+                this,
+            ))
+            .ok()
+        }
+    }
+    pub fn Other(&self) -> ::windows_core::Result<()> {
+        let this = &::windows_core::ComInterface::cast::<IOther>(self)?;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Other)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+    pub fn Method(&self) -> ::windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Method)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn CreateWidget(value: i32) -> ::windows_core::Result<Widget> {
+        Self::IWidgetFactory(|this| unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).CreateWidget)(
+                ::windows_core::Interface::as_raw(this),
+                value,
+                &mut result__,
+            )
+            .from_abi(result__)
+        })
+    }
+    pub fn StaticMethod() -> ::windows_core::Result<i32> {
+        Self::IWidgetStatics(|this| unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).StaticMethod)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        })
+    }
+    #[doc(hidden)]
+    pub fn IWidgetFactory<R, F: FnOnce(&IWidgetFactory) -> ::windows_core::Result<R>>(
+        callback: F,
+    ) -> ::windows_core::Result<R> {
+        static SHARED: ::windows_core::imp::FactoryCache<Widget, IWidgetFactory> =
+            ::windows_core::imp::FactoryCache::new();
+        SHARED.call(callback)
+    }
+    #[doc(hidden)]
+    pub fn IWidgetStatics<R, F: FnOnce(&IWidgetStatics) -> ::windows_core::Result<R>>(
+        callback: F,
+    ) -> ::windows_core::Result<R> {
+        static SHARED: ::windows_core::imp::FactoryCache<Widget, IWidgetStatics> =
+            ::windows_core::imp::FactoryCache::new();
+        SHARED.call(callback)
+    }
+}
+impl ::core::cmp::PartialEq for Widget {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for Widget {}
+impl ::core::fmt::Debug for Widget {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Widget").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for Widget {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(
+            b"rc(Test.Widget;{1bd7b2ca-3b7b-4d20-8f20-5a7b5d5d6c03})",
+        );
+}
+impl ::core::clone::Clone for Widget {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::Interface for Widget {
+    type Vtable = IWidget_Vtbl;
+}
+unsafe impl ::windows_core::ComInterface for Widget {
+    const IID: ::windows_core::GUID = <IWidget as ::windows_core::ComInterface>::IID;
+}
+impl ::windows_core::RuntimeName for Widget {
+    const NAME: &'static str = "Test.Widget";
+}
+::windows_core::imp::interface_hierarchy!(
+    Widget,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::windows_core::CanTryInto<IBase> for Widget {}
+impl ::windows_core::CanTryInto<IOther> for Widget {}
+impl ::windows_core::CanTryInto<IWidget> for Widget {}
+impl ::windows_core::CanTryInto<Base> for Widget {}
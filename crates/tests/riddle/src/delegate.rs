@@ -0,0 +1,169 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+pub type Callback = ::core::option::Option<
+    unsafe extern "system" fn(value: i32, context: *mut ::core::ffi::c_void) -> bool,
+>;
+#[repr(transparent)]
+pub struct Handler(pub ::windows_core::IUnknown);
+impl Handler {
+    pub fn new<
+        F: FnMut(
+                ::core::option::Option<&::windows_core::IInspectable>,
+                &mut i32,
+            ) -> ::windows_core::Result<i32>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        invoke: F,
+    ) -> Self {
+        let com = HandlerBox::<F> {
+            vtable: &HandlerBox::<F>::VTABLE,
+            count: ::windows_core::imp::RefCount::new(1),
+            invoke,
+        };
+        unsafe { ::core::mem::transmute(::std::boxed::Box::new(com)) }
+    }
+    pub fn Invoke<P0>(&self, sender: P0, value: &mut i32) -> ::windows_core::Result<i32>
+    where
+        P0: ::windows_core::IntoParam<::windows_core::IInspectable>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Invoke)(
+                ::windows_core::Interface::as_raw(this),
+                sender.into_param().abi(),
+                value,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+#[repr(C)]
+struct HandlerBox<
+    F: FnMut(
+            ::core::option::Option<&::windows_core::IInspectable>,
+            &mut i32,
+        ) -> ::windows_core::Result<i32>
+        + ::core::marker::Send
+        + 'static,
+> {
+    vtable: *const Handler_Vtbl,
+    invoke: F,
+    count: ::windows_core::imp::RefCount,
+}
+impl<
+        F: FnMut(
+                ::core::option::Option<&::windows_core::IInspectable>,
+                &mut i32,
+            ) -> ::windows_core::Result<i32>
+            + ::core::marker::Send
+            + 'static,
+    > HandlerBox<F>
+{
+    const VTABLE: Handler_Vtbl = Handler_Vtbl {
+        base__: ::windows_core::IUnknown_Vtbl {
+            QueryInterface: Self::QueryInterface,
+            AddRef: Self::AddRef,
+            Release: Self::Release,
+        },
+        Invoke: Self::Invoke,
+    };
+    unsafe extern "system" fn QueryInterface(
+        this: *mut ::core::ffi::c_void,
+        iid: &::windows_core::GUID,
+        interface: *mut *const ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        *interface = if iid == &<Handler as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::IUnknown as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::imp::IAgileObject as ::windows_core::ComInterface>::IID
+        {
+            &mut (*this).vtable as *mut _ as _
+        } else {
+            ::core::ptr::null_mut()
+        };
+        if (*interface).is_null() {
+            ::windows_core::HRESULT(-2147467262)
+        } else {
+            (*this).count.add_ref();
+            ::windows_core::HRESULT(0)
+        }
+    }
+    unsafe extern "system" fn AddRef(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        (*this).count.add_ref()
+    }
+    unsafe extern "system" fn Release(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        let remaining = (*this).count.release();
+        if remaining == 0 {
+            let _ = ::std::boxed::Box::from_raw(this);
+        }
+        remaining
+    }
+    unsafe extern "system" fn Invoke(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        value: *mut i32,
+        result__: *mut i32,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        match ((*this).invoke)(
+            ::windows_core::from_raw_borrowed(&sender),
+            ::core::mem::transmute_copy(&value),
+        ) {
+            ::core::result::Result::Ok(ok__) => {
+                ::core::ptr::write(result__, ::core::mem::transmute_copy(&ok__));
+                ::windows_core::HRESULT(0)
+            }
+            ::core::result::Result::Err(err) => err.into(),
+        }
+    }
+}
+impl ::core::cmp::PartialEq for Handler {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for Handler {}
+impl ::core::fmt::Debug for Handler {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Handler").field(&self.0).finish()
+    }
+}
+unsafe impl ::windows_core::Interface for Handler {
+    type Vtable = Handler_Vtbl;
+}
+impl ::core::clone::Clone for Handler {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for Handler {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x8fb0c2e5_4f2b_4ec7_a1d9_3e5a2f1b7c60);
+}
+impl ::windows_core::RuntimeType for Handler {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{8fb0c2e5-4f2b-4ec7-a1d9-3e5a2f1b7c60}");
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct Handler_Vtbl {
+    pub base__: ::windows_core::IUnknown_Vtbl,
+    pub Invoke: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        value: *mut i32,
+        result__: *mut i32,
+    ) -> ::windows_core::HRESULT,
+}
@@ -0,0 +1,186 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct Signed(pub i32);
+impl Signed {
+    pub const One: Self = Self(1i32);
+    pub const Two: Self = Self(2i32);
+    pub const Negative: Self = Self(-3i32);
+}
+impl ::core::marker::Copy for Signed {}
+impl ::core::clone::Clone for Signed {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for Signed {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::windows_core::TypeKind for Signed {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for Signed {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.0 {
+            1i32 => f.write_str("One"),
+            2i32 => f.write_str("Two"),
+            -3i32 => f.write_str("Negative"),
+            _ => ::core::write!(f, "Signed({:#x})", self.0),
+        }
+    }
+}
+impl ::core::fmt::Display for Signed {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
+    }
+}
+impl ::windows_core::RuntimeType for Signed {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"enum(Test.Signed;i4)");
+}
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct Small(pub u8);
+impl Small {
+    pub const A: Self = Self(0u8);
+    pub const B: Self = Self(255u8);
+}
+impl ::core::marker::Copy for Small {}
+impl ::core::clone::Clone for Small {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for Small {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::windows_core::TypeKind for Small {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for Small {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.0 {
+            0u8 => f.write_str("A"),
+            255u8 => f.write_str("B"),
+            _ => ::core::write!(f, "Small({:#x})", self.0),
+        }
+    }
+}
+impl ::core::fmt::Display for Small {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
+    }
+}
+impl ::windows_core::RuntimeType for Small {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"enum(Test.Small;u1)");
+}
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct Unsigned(pub u32);
+impl Unsigned {
+    pub const None: Self = Self(0u32);
+    pub const One: Self = Self(1u32);
+    pub const Two: Self = Self(2u32);
+    pub const Max: Self = Self(4294967295u32);
+}
+impl ::core::marker::Copy for Unsigned {}
+impl ::core::clone::Clone for Unsigned {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for Unsigned {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::windows_core::TypeKind for Unsigned {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for Unsigned {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        const NAMES: &[(&str, Unsigned)] = &[
+            ("None", Unsigned(0u32)),
+            ("One", Unsigned(1u32)),
+            ("Two", Unsigned(2u32)),
+            ("Max", Unsigned(4294967295u32)),
+        ];
+        if let Some((name, _)) = NAMES.iter().find(|(_, value)| value.0 == self.0) {
+            return f.write_str(name);
+        }
+        let mut remaining = self.0;
+        let mut first = true;
+        for (name, value) in NAMES {
+            if value.0 != 0 && remaining & value.0 == value.0 {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                remaining &= !value.0;
+                first = false;
+            }
+        }
+        if first {
+            ::core::write!(f, "Unsigned({:#x})", self.0)
+        } else if remaining != 0 {
+            ::core::write!(f, " | {:#x}", remaining)
+        } else {
+            Ok(())
+        }
+    }
+}
+impl ::core::fmt::Display for Unsigned {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
+    }
+}
+impl Unsigned {
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl ::core::ops::BitOr for Unsigned {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+impl ::core::ops::BitAnd for Unsigned {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+impl ::core::ops::BitOrAssign for Unsigned {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0.bitor_assign(other.0)
+    }
+}
+impl ::core::ops::BitAndAssign for Unsigned {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0.bitand_assign(other.0)
+    }
+}
+impl ::core::ops::Not for Unsigned {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+}
+impl ::windows_core::RuntimeType for Unsigned {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"enum(Test.Unsigned;u4)");
+}
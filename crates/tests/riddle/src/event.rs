@@ -0,0 +1,495 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+pub struct IWindow(::windows_core::IUnknown);
+impl IWindow {
+    pub fn Closed<P0>(&self, handler: P0) -> ::windows_core::Result<EventRegistrationToken>
+    where
+        P0: ::windows_core::IntoParam<ClosedHandler>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Closed)(
+                ::windows_core::Interface::as_raw(this),
+                handler.into_param().abi(),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn on_closed<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        &self,
+        handler: F,
+    ) -> ::windows_core::Result<::windows_core::EventRevoker> {
+        let token = self.Closed(&<ClosedHandler>::new(handler))?;
+        let this = self.clone();
+        Ok(::windows_core::EventRevoker::new(move || {
+            let _ = this.RemoveClosed(token);
+        }))
+    }
+    pub fn RemoveClosed(&self, token: EventRegistrationToken) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).RemoveClosed)(
+                ::windows_core::Interface::as_raw(this),
+                token,
+            )
+            .ok()
+        }
+    }
+    pub fn Close(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Close)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IWindow,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IWindow {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IWindow {}
+impl ::core::fmt::Debug for IWindow {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IWindow").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IWindow {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{5c1c7a4e-2d3b-4f6a-9e8d-7b6a5c4d3e02}");
+}
+unsafe impl ::windows_core::Interface for IWindow {
+    type Vtable = IWindow_Vtbl;
+}
+impl ::core::clone::Clone for IWindow {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IWindow {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x5c1c7a4e_2d3b_4f6a_9e8d_7b6a5c4d3e02);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWindow_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Closed: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        handler: *mut ::core::ffi::c_void,
+        result__: *mut EventRegistrationToken,
+    ) -> ::windows_core::HRESULT,
+    pub RemoveClosed: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        token: EventRegistrationToken,
+    ) -> ::windows_core::HRESULT,
+    pub Close: unsafe extern "system" fn(this: *mut ::core::ffi::c_void) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct IWindowStatics(::windows_core::IUnknown);
+impl IWindowStatics {
+    pub fn Created<P0>(&self, handler: P0) -> ::windows_core::Result<EventRegistrationToken>
+    where
+        P0: ::windows_core::IntoParam<ClosedHandler>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Created)(
+                ::windows_core::Interface::as_raw(this),
+                handler.into_param().abi(),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn on_created<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        &self,
+        handler: F,
+    ) -> ::windows_core::Result<::windows_core::EventRevoker> {
+        let token = self.Created(&<ClosedHandler>::new(handler))?;
+        let this = self.clone();
+        Ok(::windows_core::EventRevoker::new(move || {
+            let _ = this.RemoveCreated(token);
+        }))
+    }
+    pub fn RemoveCreated(&self, token: EventRegistrationToken) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).RemoveCreated)(
+                ::windows_core::Interface::as_raw(this),
+                token,
+            )
+            .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IWindowStatics,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IWindowStatics {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IWindowStatics {}
+impl ::core::fmt::Debug for IWindowStatics {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IWindowStatics").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IWindowStatics {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{5c1c7a4e-2d3b-4f6a-9e8d-7b6a5c4d3e03}");
+}
+unsafe impl ::windows_core::Interface for IWindowStatics {
+    type Vtable = IWindowStatics_Vtbl;
+}
+impl ::core::clone::Clone for IWindowStatics {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IWindowStatics {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x5c1c7a4e_2d3b_4f6a_9e8d_7b6a5c4d3e03);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWindowStatics_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Created: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        handler: *mut ::core::ffi::c_void,
+        result__: *mut EventRegistrationToken,
+    ) -> ::windows_core::HRESULT,
+    pub RemoveCreated: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        token: EventRegistrationToken,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct Window(::windows_core::IUnknown);
+impl Window {
+    pub fn Closed<P0>(&self, handler: P0) -> ::windows_core::Result<EventRegistrationToken>
+    where
+        P0: ::windows_core::IntoParam<ClosedHandler>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Closed)(
+                ::windows_core::Interface::as_raw(this),
+                handler.into_param().abi(),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn on_closed<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        &self,
+        handler: F,
+    ) -> ::windows_core::Result<::windows_core::EventRevoker> {
+        let token = self.Closed(&<ClosedHandler>::new(handler))?;
+        let this = self.clone();
+        Ok(::windows_core::EventRevoker::new(move || {
+            let _ = this.RemoveClosed(token);
+        }))
+    }
+    pub fn RemoveClosed(&self, token: EventRegistrationToken) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).RemoveClosed)(
+                ::windows_core::Interface::as_raw(this),
+                token,
+            )
+            .ok()
+        }
+    }
+    pub fn Close(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Close)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+    pub fn Created<P0>(handler: P0) -> ::windows_core::Result<EventRegistrationToken>
+    where
+        P0: ::windows_core::IntoParam<ClosedHandler>,
+    {
+        Self::IWindowStatics(|this| unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Created)(
+                ::windows_core::Interface::as_raw(this),
+                handler.into_param().abi(),
+                &mut result__,
+            )
+            .from_abi(result__)
+        })
+    }
+    pub fn on_created<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        handler: F,
+    ) -> ::windows_core::Result<::windows_core::EventRevoker> {
+        let token = Self::Created(&<ClosedHandler>::new(handler))?;
+        Ok(::windows_core::EventRevoker::new(move || {
+            let _ = Self::RemoveCreated(token);
+        }))
+    }
+    pub fn RemoveCreated(token: EventRegistrationToken) -> ::windows_core::Result<()> {
+        Self::IWindowStatics(|this| unsafe {
+            (::windows_core::Interface::vtable(this).RemoveCreated)(
+                ::windows_core::Interface::as_raw(this),
+                token,
+            )
+            .ok()
+        })
+    }
+    #[doc(hidden)]
+    pub fn IWindowStatics<R, F: FnOnce(&IWindowStatics) -> ::windows_core::Result<R>>(
+        callback: F,
+    ) -> ::windows_core::Result<R> {
+        static SHARED: ::windows_core::imp::FactoryCache<Window, IWindowStatics> =
+            ::windows_core::imp::FactoryCache::new();
+        SHARED.call(callback)
+    }
+}
+impl ::core::cmp::PartialEq for Window {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for Window {}
+impl ::core::fmt::Debug for Window {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Window").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for Window {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(
+            b"rc(Test.Window;{5c1c7a4e-2d3b-4f6a-9e8d-7b6a5c4d3e02})",
+        );
+}
+impl ::core::clone::Clone for Window {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::Interface for Window {
+    type Vtable = IWindow_Vtbl;
+}
+unsafe impl ::windows_core::ComInterface for Window {
+    const IID: ::windows_core::GUID = <IWindow as ::windows_core::ComInterface>::IID;
+}
+impl ::windows_core::RuntimeName for Window {
+    const NAME: &'static str = "Test.Window";
+}
+::windows_core::imp::interface_hierarchy!(
+    Window,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::windows_core::CanTryInto<IWindow> for Window {}
+#[repr(C)]
+pub struct EventRegistrationToken {
+    pub Value: i64,
+}
+impl ::core::marker::Copy for EventRegistrationToken {}
+impl ::core::clone::Clone for EventRegistrationToken {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::fmt::Debug for EventRegistrationToken {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("EventRegistrationToken")
+            .field("Value", &self.Value)
+            .finish()
+    }
+}
+impl ::windows_core::TypeKind for EventRegistrationToken {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::windows_core::RuntimeType for EventRegistrationToken {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"struct(Test.EventRegistrationToken;i8)");
+}
+impl ::core::cmp::PartialEq for EventRegistrationToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.Value == other.Value
+    }
+}
+impl ::core::cmp::Eq for EventRegistrationToken {}
+impl ::core::default::Default for EventRegistrationToken {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+#[repr(transparent)]
+pub struct ClosedHandler(pub ::windows_core::IUnknown);
+impl ClosedHandler {
+    pub fn new<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        invoke: F,
+    ) -> Self {
+        let com = ClosedHandlerBox::<F> {
+            vtable: &ClosedHandlerBox::<F>::VTABLE,
+            count: ::windows_core::imp::RefCount::new(1),
+            invoke,
+        };
+        unsafe { ::core::mem::transmute(::std::boxed::Box::new(com)) }
+    }
+    pub fn Invoke<P0>(&self, sender: P0, value: i32) -> ::windows_core::Result<()>
+    where
+        P0: ::windows_core::TryIntoParam<IWindow>,
+    {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Invoke)(
+                ::windows_core::Interface::as_raw(this),
+                sender.try_into_param()?.abi(),
+                value,
+            )
+            .ok()
+        }
+    }
+}
+#[repr(C)]
+struct ClosedHandlerBox<
+    F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+        + ::core::marker::Send
+        + 'static,
+> {
+    vtable: *const ClosedHandler_Vtbl,
+    invoke: F,
+    count: ::windows_core::imp::RefCount,
+}
+impl<
+        F: FnMut(::core::option::Option<&IWindow>, i32) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    > ClosedHandlerBox<F>
+{
+    const VTABLE: ClosedHandler_Vtbl = ClosedHandler_Vtbl {
+        base__: ::windows_core::IUnknown_Vtbl {
+            QueryInterface: Self::QueryInterface,
+            AddRef: Self::AddRef,
+            Release: Self::Release,
+        },
+        Invoke: Self::Invoke,
+    };
+    unsafe extern "system" fn QueryInterface(
+        this: *mut ::core::ffi::c_void,
+        iid: &::windows_core::GUID,
+        interface: *mut *const ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        *interface = if iid == &<ClosedHandler as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::IUnknown as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::imp::IAgileObject as ::windows_core::ComInterface>::IID
+        {
+            &mut (*this).vtable as *mut _ as _
+        } else {
+            ::core::ptr::null_mut()
+        };
+        if (*interface).is_null() {
+            ::windows_core::HRESULT(-2147467262)
+        } else {
+            (*this).count.add_ref();
+            ::windows_core::HRESULT(0)
+        }
+    }
+    unsafe extern "system" fn AddRef(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        (*this).count.add_ref()
+    }
+    unsafe extern "system" fn Release(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        let remaining = (*this).count.release();
+        if remaining == 0 {
+            let _ = ::std::boxed::Box::from_raw(this);
+        }
+        remaining
+    }
+    unsafe extern "system" fn Invoke(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        value: i32,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        ((*this).invoke)(::windows_core::from_raw_borrowed(&sender), value).into()
+    }
+}
+impl ::core::cmp::PartialEq for ClosedHandler {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for ClosedHandler {}
+impl ::core::fmt::Debug for ClosedHandler {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("ClosedHandler").field(&self.0).finish()
+    }
+}
+unsafe impl ::windows_core::Interface for ClosedHandler {
+    type Vtable = ClosedHandler_Vtbl;
+}
+impl ::core::clone::Clone for ClosedHandler {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for ClosedHandler {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x5c1c7a4e_2d3b_4f6a_9e8d_7b6a5c4d3e01);
+}
+impl ::windows_core::RuntimeType for ClosedHandler {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{5c1c7a4e-2d3b-4f6a-9e8d-7b6a5c4d3e01}");
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ClosedHandler_Vtbl {
+    pub base__: ::windows_core::IUnknown_Vtbl,
+    pub Invoke: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        value: i32,
+    ) -> ::windows_core::HRESULT,
+}
@@ -0,0 +1,30 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[inline]
+pub unsafe fn GetData(handle: isize, buffer: *mut u8, len: u32) -> i32 {
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn GetData(handle: isize, buffer: *mut u8, len: u32) -> i32;
+    }
+    GetData(handle, buffer, len)
+}
+#[inline]
+pub unsafe fn SetData(handle: isize, buffer: *const u8, len: u32) -> ::windows_core::Result<()> {
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn SetData(handle: isize, buffer: *const u8, len: u32) -> ::windows_core::HRESULT;
+    }
+    SetData(handle, buffer, len).ok()
+}
+pub const FLOAT: f32 = 1.5f32;
+pub const HR: ::windows_core::HRESULT = ::windows_core::HRESULT(-2147467259i32);
+pub const NAME: ::windows_core::PCWSTR = ::windows_core::w!("riddle");
+pub const SIZE: i32 = -1i32;
+pub const VERSION: u16 = 2u16;
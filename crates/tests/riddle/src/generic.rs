@@ -0,0 +1,456 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+pub struct IMyVector<T>(::windows_core::IUnknown, ::core::marker::PhantomData<T>)
+where
+    T: ::windows_core::RuntimeType + 'static;
+impl<T: ::windows_core::RuntimeType + 'static> IMyVector<T> {
+    pub fn GetAt(&self, index: u32) -> ::windows_core::Result<T> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).GetAt)(
+                ::windows_core::Interface::as_raw(this),
+                index,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Append<P0>(&self, value: P0) -> ::windows_core::Result<()>
+    where
+        P0: ::windows_core::IntoParam<T>,
+    {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Append)(
+                ::windows_core::Interface::as_raw(this),
+                value.into_param().abi(),
+            )
+            .ok()
+        }
+    }
+    pub fn GetMany(
+        &self,
+        items: &mut [<T as ::windows_core::Type<T>>::Default],
+    ) -> ::windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).GetMany)(
+                ::windows_core::Interface::as_raw(this),
+                items.len() as u32,
+                ::core::mem::transmute_copy(&items),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::CanInto<::windows_core::IUnknown>
+    for IMyVector<T>
+{
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::CanInto<::windows_core::IInspectable>
+    for IMyVector<T>
+{
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::cmp::PartialEq for IMyVector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::cmp::Eq for IMyVector<T> {}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::fmt::Debug for IMyVector<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IMyVector").field(&self.0).finish()
+    }
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::RuntimeType for IMyVector<T> {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer = {
+        ::windows_core::imp::ConstBuffer::new()
+            .push_slice(b"pinterface(")
+            .push_slice(b"{913337e9-11a1-4345-a3a2-4e7f956e222d}")
+            .push_slice(b";")
+            .push_other(<T as ::windows_core::RuntimeType>::SIGNATURE)
+            .push_slice(b")")
+    };
+}
+unsafe impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::Interface for IMyVector<T> {
+    type Vtable = IMyVector_Vtbl<T>;
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::clone::Clone for IMyVector<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), ::core::marker::PhantomData::<T>)
+    }
+}
+unsafe impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::ComInterface
+    for IMyVector<T>
+{
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_signature(<Self as ::windows_core::RuntimeType>::SIGNATURE);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IMyVector_Vtbl<T>
+where
+    T: ::windows_core::RuntimeType + 'static,
+{
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub GetAt: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        index: u32,
+        result__: *mut ::windows_core::AbiType<T>,
+    ) -> ::windows_core::HRESULT,
+    pub Append: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        value: ::windows_core::AbiType<T>,
+    ) -> ::windows_core::HRESULT,
+    pub GetMany: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        items_array_size: u32,
+        items: *mut ::windows_core::AbiType<T>,
+        result__: *mut u32,
+    ) -> ::windows_core::HRESULT,
+    pub T: ::core::marker::PhantomData<T>,
+}
+#[repr(transparent)]
+pub struct IUsesGenerics(::windows_core::IUnknown);
+impl IUsesGenerics {
+    pub fn Strings(&self) -> ::windows_core::Result<IMyVector<::windows_core::HSTRING>> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Strings)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Nested(&self) -> ::windows_core::Result<IMyVector<IMyVector<i32>>> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Nested)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn SetHandler<P0>(&self, handler: P0) -> ::windows_core::Result<()>
+    where
+        P0: ::windows_core::IntoParam<MyHandler<::windows_core::HSTRING>>,
+    {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).SetHandler)(
+                ::windows_core::Interface::as_raw(this),
+                handler.into_param().abi(),
+            )
+            .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    IUsesGenerics,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for IUsesGenerics {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for IUsesGenerics {}
+impl ::core::fmt::Debug for IUsesGenerics {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("IUsesGenerics").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for IUsesGenerics {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{c3a8f1d6-4b2e-4f7a-8d9c-1e5b6a3f0d27}");
+}
+unsafe impl ::windows_core::Interface for IUsesGenerics {
+    type Vtable = IUsesGenerics_Vtbl;
+}
+impl ::core::clone::Clone for IUsesGenerics {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for IUsesGenerics {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0xc3a8f1d6_4b2e_4f7a_8d9c_1e5b6a3f0d27);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IUsesGenerics_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Strings: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        result__: *mut *mut ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT,
+    pub Nested: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        result__: *mut *mut ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT,
+    pub SetHandler: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        handler: *mut ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT,
+}
+#[repr(transparent)]
+pub struct Strings(::windows_core::IUnknown);
+impl Strings {
+    pub fn GetAt(&self, index: u32) -> ::windows_core::Result<::windows_core::HSTRING> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).GetAt)(
+                ::windows_core::Interface::as_raw(this),
+                index,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Append(&self, value: &::windows_core::HSTRING) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Append)(
+                ::windows_core::Interface::as_raw(this),
+                ::core::mem::transmute_copy(value),
+            )
+            .ok()
+        }
+    }
+    pub fn GetMany(&self, items: &mut [::windows_core::HSTRING]) -> ::windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).GetMany)(
+                ::windows_core::Interface::as_raw(this),
+                items.len() as u32,
+                ::core::mem::transmute_copy(&items),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+}
+impl ::core::cmp::PartialEq for Strings {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for Strings {}
+impl ::core::fmt::Debug for Strings {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Strings").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for Strings {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(
+            b"rc(Test.Strings;pinterface({913337e9-11a1-4345-a3a2-4e7f956e222d};string))",
+        );
+}
+impl ::core::clone::Clone for Strings {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::Interface for Strings {
+    type Vtable = IMyVector_Vtbl<::windows_core::HSTRING>;
+}
+unsafe impl ::windows_core::ComInterface for Strings {
+    const IID: ::windows_core::GUID =
+        <IMyVector<::windows_core::HSTRING> as ::windows_core::ComInterface>::IID;
+}
+impl ::windows_core::RuntimeName for Strings {
+    const NAME: &'static str = "Test.Strings";
+}
+::windows_core::imp::interface_hierarchy!(
+    Strings,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::windows_core::CanTryInto<IMyVector<::windows_core::HSTRING>> for Strings {}
+#[repr(transparent)]
+pub struct MyHandler<T>(pub ::windows_core::IUnknown, ::core::marker::PhantomData<T>)
+where
+    T: ::windows_core::RuntimeType + 'static;
+impl<T: ::windows_core::RuntimeType + 'static> MyHandler<T> {
+    pub fn new<
+        F: FnMut(
+                ::core::option::Option<&IMyVector<T>>,
+                &<T as ::windows_core::Type<T>>::Default,
+            ) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    >(
+        invoke: F,
+    ) -> Self {
+        let com = MyHandlerBox::<T, F> {
+            vtable: &MyHandlerBox::<T, F>::VTABLE,
+            count: ::windows_core::imp::RefCount::new(1),
+            invoke,
+        };
+        unsafe { ::core::mem::transmute(::std::boxed::Box::new(com)) }
+    }
+    pub fn Invoke<P0, P1>(&self, sender: P0, args: P1) -> ::windows_core::Result<()>
+    where
+        P0: ::windows_core::TryIntoParam<IMyVector<T>>,
+        P1: ::windows_core::IntoParam<T>,
+    {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Invoke)(
+                ::windows_core::Interface::as_raw(this),
+                sender.try_into_param()?.abi(),
+                args.into_param().abi(),
+            )
+            .ok()
+        }
+    }
+}
+#[repr(C)]
+struct MyHandlerBox<
+    T,
+    F: FnMut(
+            ::core::option::Option<&IMyVector<T>>,
+            &<T as ::windows_core::Type<T>>::Default,
+        ) -> ::windows_core::Result<()>
+        + ::core::marker::Send
+        + 'static,
+> where
+    T: ::windows_core::RuntimeType + 'static,
+{
+    vtable: *const MyHandler_Vtbl<T>,
+    invoke: F,
+    count: ::windows_core::imp::RefCount,
+}
+impl<
+        T: ::windows_core::RuntimeType + 'static,
+        F: FnMut(
+                ::core::option::Option<&IMyVector<T>>,
+                &<T as ::windows_core::Type<T>>::Default,
+            ) -> ::windows_core::Result<()>
+            + ::core::marker::Send
+            + 'static,
+    > MyHandlerBox<T, F>
+{
+    const VTABLE: MyHandler_Vtbl<T> = MyHandler_Vtbl::<T> {
+        base__: ::windows_core::IUnknown_Vtbl {
+            QueryInterface: Self::QueryInterface,
+            AddRef: Self::AddRef,
+            Release: Self::Release,
+        },
+        Invoke: Self::Invoke,
+        T: ::core::marker::PhantomData::<T>,
+    };
+    unsafe extern "system" fn QueryInterface(
+        this: *mut ::core::ffi::c_void,
+        iid: &::windows_core::GUID,
+        interface: *mut *const ::core::ffi::c_void,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        *interface = if iid == &<MyHandler<T> as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::IUnknown as ::windows_core::ComInterface>::IID
+            || iid == &<::windows_core::imp::IAgileObject as ::windows_core::ComInterface>::IID
+        {
+            &mut (*this).vtable as *mut _ as _
+        } else {
+            ::core::ptr::null_mut()
+        };
+        if (*interface).is_null() {
+            ::windows_core::HRESULT(-2147467262)
+        } else {
+            (*this).count.add_ref();
+            ::windows_core::HRESULT(0)
+        }
+    }
+    unsafe extern "system" fn AddRef(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        (*this).count.add_ref()
+    }
+    unsafe extern "system" fn Release(this: *mut ::core::ffi::c_void) -> u32 {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        let remaining = (*this).count.release();
+        if remaining == 0 {
+            let _ = ::std::boxed::Box::from_raw(this);
+        }
+        remaining
+    }
+    unsafe extern "system" fn Invoke(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        args: ::windows_core::AbiType<T>,
+    ) -> ::windows_core::HRESULT {
+        let this = this as *mut *mut ::core::ffi::c_void as *mut Self;
+        ((*this).invoke)(
+            ::windows_core::from_raw_borrowed(&sender),
+            ::core::mem::transmute(&args),
+        )
+        .into()
+    }
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::cmp::PartialEq for MyHandler<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::cmp::Eq for MyHandler<T> {}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::fmt::Debug for MyHandler<T> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("MyHandler").field(&self.0).finish()
+    }
+}
+unsafe impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::Interface for MyHandler<T> {
+    type Vtable = MyHandler_Vtbl<T>;
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::core::clone::Clone for MyHandler<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), ::core::marker::PhantomData::<T>)
+    }
+}
+unsafe impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::ComInterface
+    for MyHandler<T>
+{
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_signature(<Self as ::windows_core::RuntimeType>::SIGNATURE);
+}
+impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::RuntimeType for MyHandler<T> {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer = {
+        ::windows_core::imp::ConstBuffer::new()
+            .push_slice(b"pinterface(")
+            .push_slice(b"{9b2e4c7a-1f3d-4e8b-a6c5-7d0f2b9e3a18}")
+            .push_slice(b";")
+            .push_other(<T as ::windows_core::RuntimeType>::SIGNATURE)
+            .push_slice(b")")
+    };
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct MyHandler_Vtbl<T>
+where
+    T: ::windows_core::RuntimeType + 'static,
+{
+    pub base__: ::windows_core::IUnknown_Vtbl,
+    pub Invoke: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        sender: *mut ::core::ffi::c_void,
+        args: ::windows_core::AbiType<T>,
+    ) -> ::windows_core::HRESULT,
+    pub T: ::core::marker::PhantomData<T>,
+}
@@ -0,0 +1,114 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+pub struct ITest(::windows_core::IUnknown);
+impl ITest {
+    pub fn Method(&self, a: i32, b: &mut ::windows_core::HSTRING) -> ::windows_core::Result<u8> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Method)(
+                ::windows_core::Interface::as_raw(this),
+                a,
+                b as *mut _ as _,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn MethodWithDefault(&self, a: i32) -> ::windows_core::Result<u8> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).MethodWithDefault)(
+                ::windows_core::Interface::as_raw(this),
+                a,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Arrays(&self, a: &[u8], b: &mut [i32]) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Arrays)(
+                ::windows_core::Interface::as_raw(this),
+                a.len() as u32,
+                a.as_ptr(),
+                b.len() as u32,
+                b.as_mut_ptr(),
+            )
+            .ok()
+        }
+    }
+    pub fn Void(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Void)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+}
+::windows_core::imp::interface_hierarchy!(
+    ITest,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl ::core::cmp::PartialEq for ITest {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl ::core::cmp::Eq for ITest {}
+impl ::core::fmt::Debug for ITest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("ITest").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for ITest {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"{8ceeb155-2849-4ce5-9448-91ff70e1e4d9}");
+}
+unsafe impl ::windows_core::Interface for ITest {
+    type Vtable = ITest_Vtbl;
+}
+impl ::core::clone::Clone for ITest {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+unsafe impl ::windows_core::ComInterface for ITest {
+    const IID: ::windows_core::GUID =
+        ::windows_core::GUID::from_u128(0x8ceeb155_2849_4ce5_9448_91ff70e1e4d9);
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct ITest_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Method: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        a: i32,
+        b: *mut ::std::mem::MaybeUninit<::windows_core::HSTRING>,
+        result__: *mut u8,
+    ) -> ::windows_core::HRESULT,
+    pub MethodWithDefault: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        a: i32,
+        result__: *mut u8,
+    ) -> ::windows_core::HRESULT,
+    pub Arrays: unsafe extern "system" fn(
+        this: *mut ::core::ffi::c_void,
+        a_array_size: u32,
+        a: *const u8,
+        b_array_size: u32,
+        b: *mut i32,
+    ) -> ::windows_core::HRESULT,
+    pub Void: unsafe extern "system" fn(this: *mut ::core::ffi::c_void) -> ::windows_core::HRESULT,
+}
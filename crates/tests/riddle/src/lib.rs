@@ -1,9 +1,22 @@
+mod class;
+mod delegate;
+mod r#enum;
+mod event;
+mod function;
+mod generic;
+mod interface;
+mod module;
 mod nested_struct;
 mod r#struct;
 
 use std::process::Command;
 
 pub fn run_riddle(name: &str) -> Vec<windows_metadata::File> {
+    run_riddle_with_config(name, &[])
+}
+
+/// Like `run_riddle` but generates the `.rs` file with the given configuration values.
+pub fn run_riddle_with_config(name: &str, config: &[&str]) -> Vec<windows_metadata::File> {
     let idl = format!("tests/{name}.idl");
     let winmd = format!("tests/{name}.winmd");
     let rs = format!("src/{name}.rs");
@@ -33,6 +46,9 @@ pub fn run_riddle(name: &str) -> Vec<windows_metadata::File> {
     command.args([
         "run", "-p", "riddle", "--", "-in", &idl, "-out", &rs, "-filter", "Test",
     ]); // TODO: -config FLATTEN doesn't work for namespaces
+    if !config.is_empty() {
+        command.arg("-config").args(config);
+    }
     assert!(command.status().unwrap().success());
 
     // Return winmd file for validation
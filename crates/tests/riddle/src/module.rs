@@ -0,0 +1,125 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+pub mod Inner {
+    #[repr(C)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+        pub size: super::Size,
+    }
+    impl ::core::marker::Copy for Point {}
+    impl ::core::clone::Clone for Point {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+    impl ::core::fmt::Debug for Point {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct("Point")
+                .field("x", &self.x)
+                .field("y", &self.y)
+                .field("size", &self.size)
+                .finish()
+        }
+    }
+    impl ::windows_core::TypeKind for Point {
+        type TypeKind = ::windows_core::CopyType;
+    }
+    impl ::windows_core::RuntimeType for Point {
+        const SIGNATURE: ::windows_core::imp::ConstBuffer =
+            ::windows_core::imp::ConstBuffer::from_slice(
+                b"struct(Test.Inner.Point;i4;i4;struct(Test.Size;i4;i4))",
+            );
+    }
+    impl ::core::cmp::PartialEq for Point {
+        fn eq(&self, other: &Self) -> bool {
+            self.x == other.x && self.y == other.y && self.size == other.size
+        }
+    }
+    impl ::core::cmp::Eq for Point {}
+    impl ::core::default::Default for Point {
+        fn default() -> Self {
+            unsafe { ::core::mem::zeroed() }
+        }
+    }
+}
+#[repr(C)]
+pub struct Rect {
+    pub origin: Inner::Point,
+    pub size: Size,
+}
+impl ::core::marker::Copy for Rect {}
+impl ::core::clone::Clone for Rect {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::fmt::Debug for Rect {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Rect")
+            .field("origin", &self.origin)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+impl ::windows_core::TypeKind for Rect {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::windows_core::RuntimeType for Rect {
+    const SIGNATURE : ::windows_core::imp::ConstBuffer =::windows_core::imp::ConstBuffer::from_slice ( b"struct(Test.Rect;struct(Test.Inner.Point;i4;i4;struct(Test.Size;i4;i4));struct(Test.Size;i4;i4))" ) ;
+}
+impl ::core::cmp::PartialEq for Rect {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.size == other.size
+    }
+}
+impl ::core::cmp::Eq for Rect {}
+impl ::core::default::Default for Rect {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+pub struct Size {
+    pub width: i32,
+    pub height: i32,
+}
+impl ::core::marker::Copy for Size {}
+impl ::core::clone::Clone for Size {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::fmt::Debug for Size {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("Size")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+impl ::windows_core::TypeKind for Size {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::windows_core::RuntimeType for Size {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"struct(Test.Size;i4;i4)");
+}
+impl ::core::cmp::PartialEq for Size {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height
+    }
+}
+impl ::core::cmp::Eq for Size {}
+impl ::core::default::Default for Size {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
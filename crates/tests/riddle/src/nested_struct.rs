@@ -46,7 +46,7 @@ impl ::core::default::Default for Inner {
 pub struct Outer {
     pub field_bool: bool,
     pub field_inner: Inner,
-    pub field_usize: usize,
+    pub field_u64: u64,
 }
 impl ::core::marker::Copy for Outer {}
 impl ::core::clone::Clone for Outer {
@@ -59,7 +59,7 @@ impl ::core::fmt::Debug for Outer {
         f.debug_struct("Outer")
             .field("field_bool", &self.field_bool)
             .field("field_inner", &self.field_inner)
-            .field("field_usize", &self.field_usize)
+            .field("field_u64", &self.field_u64)
             .finish()
     }
 }
@@ -69,14 +69,14 @@ impl ::windows_core::TypeKind for Outer {
 impl ::windows_core::RuntimeType for Outer {
     const SIGNATURE: ::windows_core::imp::ConstBuffer =
         ::windows_core::imp::ConstBuffer::from_slice(
-            b"struct(Test.Outer;b1;struct(Test.Inner;i4);us)",
+            b"struct(Test.Outer;b1;struct(Test.Inner;i4);u8)",
         );
 }
 impl ::core::cmp::PartialEq for Outer {
     fn eq(&self, other: &Self) -> bool {
         self.field_bool == other.field_bool
             && self.field_inner == other.field_inner
-            && self.field_usize == other.field_usize
+            && self.field_u64 == other.field_u64
     }
 }
 impl ::core::cmp::Eq for Outer {}
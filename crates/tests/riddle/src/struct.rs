@@ -20,8 +20,6 @@ pub struct Primitives {
     pub field_u64: u64,
     pub field_f32: f32,
     pub field_f64: f64,
-    pub field_isize: isize,
-    pub field_usize: usize,
 }
 impl ::core::marker::Copy for Primitives {}
 impl ::core::clone::Clone for Primitives {
@@ -43,8 +41,6 @@ impl ::core::fmt::Debug for Primitives {
             .field("field_u64", &self.field_u64)
             .field("field_f32", &self.field_f32)
             .field("field_f64", &self.field_f64)
-            .field("field_isize", &self.field_isize)
-            .field("field_usize", &self.field_usize)
             .finish()
     }
 }
@@ -54,7 +50,7 @@ impl ::windows_core::TypeKind for Primitives {
 impl ::windows_core::RuntimeType for Primitives {
     const SIGNATURE: ::windows_core::imp::ConstBuffer =
         ::windows_core::imp::ConstBuffer::from_slice(
-            b"struct(Test.Primitives;b1;i1;u1;i2;u2;i4;u4;i8;u8;f4;f8;is;us)",
+            b"struct(Test.Primitives;b1;i1;u1;i2;u2;i4;u4;i8;u8;f4;f8)",
         );
 }
 impl ::core::cmp::PartialEq for Primitives {
@@ -70,8 +66,6 @@ impl ::core::cmp::PartialEq for Primitives {
             && self.field_u64 == other.field_u64
             && self.field_f32 == other.field_f32
             && self.field_f64 == other.field_f64
-            && self.field_isize == other.field_isize
-            && self.field_usize == other.field_usize
     }
 }
 impl ::core::cmp::Eq for Primitives {}
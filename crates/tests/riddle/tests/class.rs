@@ -0,0 +1,57 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn class() {
+    let files = run_riddle("class");
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test", "Widget"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Class);
+    assert!(reader.type_def_flags(def).contains(TypeAttributes::Sealed));
+    assert!(reader.type_def_extends(def) == Some(TypeName::new("Test", "Base")));
+
+    assert!(reader.type_def_has_default_interface(def));
+    assert!(reader.type_def_has_default_constructor(def));
+
+    let interfaces: Vec<Interface> = reader.type_def_interfaces(def, &[]).collect();
+    assert_eq!(interfaces.len(), 2);
+    assert_eq!(interfaces[0].kind, InterfaceKind::Default);
+    assert_eq!(reader.type_name(&interfaces[0].ty), "IWidget");
+    assert_eq!(interfaces[1].kind, InterfaceKind::None);
+    assert_eq!(reader.type_name(&interfaces[1].ty), "IOther");
+
+    let statics: Vec<&str> = reader
+        .type_interfaces(&Type::TypeDef(def, vec![]))
+        .iter()
+        .filter(|interface| interface.kind == InterfaceKind::Static)
+        .map(|interface| reader.type_name(&interface.ty))
+        .collect();
+
+    assert_eq!(statics, ["IWidgetFactory", "IWidgetStatics"]);
+
+    let def = reader
+        .get(TypeName::new("Test", "Base"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Class);
+    assert!(!reader.type_def_flags(def).contains(TypeAttributes::Sealed));
+    assert!(reader.type_def_extends(def) == Some(TypeName::Object));
+    assert!(!reader.type_def_has_default_constructor(def));
+
+    let attribute = reader
+        .type_def_attributes(def)
+        .find(|attribute| reader.attribute_name(*attribute) == "ComposableAttribute")
+        .expect("Attribute missing");
+
+    let args = reader.attribute_args(attribute);
+    assert_eq!(args.len(), 3);
+    assert!(
+        matches!(args[0].1, Value::TypeDef(factory) if reader.type_def_name(factory) == "IBaseFactory")
+    );
+}
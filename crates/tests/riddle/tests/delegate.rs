@@ -0,0 +1,47 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn delegates() {
+    let files = run_riddle("delegate");
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test", "Handler"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Delegate);
+    assert!(!reader.type_def_is_callback(def));
+
+    let guid = reader.type_def_guid(def).expect("GUID missing");
+    assert_eq!(format!("{guid:?}"), "8fb0c2e5-4f2b-4ec7-a1d9-3e5a2f1b7c60");
+
+    let invoke = reader.type_def_invoke_method(def);
+    let signature = reader.method_def_signature(invoke, &[]);
+    assert_eq!(signature.return_type, Type::I32);
+    assert_eq!(signature.params.len(), 2);
+    assert_eq!(signature.params[0].ty, Type::IInspectable);
+    assert_eq!(signature.params[1].ty, Type::I32);
+    assert!(reader
+        .param_flags(signature.params[1].def)
+        .contains(ParamAttributes::Out));
+
+    let def = reader
+        .get(TypeName::new("Test", "Callback"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Delegate);
+    assert!(reader.type_def_is_callback(def));
+    assert!(reader.type_def_guid(def).is_none());
+
+    let invoke = reader.type_def_invoke_method(def);
+    let signature = reader.method_def_signature(invoke, &[]);
+    assert_eq!(signature.return_type, Type::Bool);
+    assert_eq!(signature.params[0].ty, Type::I32);
+    assert_eq!(
+        signature.params[1].ty,
+        Type::MutPtr(Box::new(Type::Void), 1)
+    );
+}
@@ -0,0 +1,74 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn enums() {
+    let files = run_riddle("enum");
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test", "Signed"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Enum);
+    assert_eq!(reader.type_def_underlying_type(def), Type::I32);
+    assert!(!reader.type_def_is_flags(def));
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 4);
+
+    assert_eq!(reader.field_name(fields[0]), "value__");
+    assert!(reader.field_constant(fields[0]).is_none());
+
+    assert_eq!(reader.field_name(fields[1]), "One");
+    assert_eq!(reader.field_name(fields[2]), "Two");
+    assert_eq!(reader.field_name(fields[3]), "Negative");
+
+    assert!(matches!(
+        reader.constant_value(reader.field_constant(fields[1]).unwrap()),
+        Value::I32(1)
+    ));
+    assert!(matches!(
+        reader.constant_value(reader.field_constant(fields[2]).unwrap()),
+        Value::I32(2)
+    ));
+    assert!(matches!(
+        reader.constant_value(reader.field_constant(fields[3]).unwrap()),
+        Value::I32(-3)
+    ));
+
+    let def = reader
+        .get(TypeName::new("Test", "Unsigned"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Enum);
+    assert_eq!(reader.type_def_underlying_type(def), Type::U32);
+    assert!(reader.type_def_is_flags(def));
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 5);
+
+    assert_eq!(reader.field_name(fields[4]), "Max");
+    assert!(matches!(
+        reader.constant_value(reader.field_constant(fields[4]).unwrap()),
+        Value::U32(u32::MAX)
+    ));
+
+    let def = reader
+        .get(TypeName::new("Test", "Small"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Enum);
+    assert_eq!(reader.type_def_underlying_type(def), Type::U8);
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 3);
+
+    assert!(matches!(
+        reader.constant_value(reader.field_constant(fields[2]).unwrap()),
+        Value::U8(255)
+    ));
+}
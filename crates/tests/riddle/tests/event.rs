@@ -0,0 +1,40 @@
+use test_riddle::run_riddle_with_config;
+use windows_metadata::*;
+
+#[test]
+fn event() {
+    let files = run_riddle_with_config("event", &["EVENT_REVOKERS"]);
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test", "IWindow"))
+        .next()
+        .expect("Type missing");
+
+    let methods: Vec<MethodDef> = reader.type_def_methods(def).collect();
+    assert_eq!(methods.len(), 3);
+
+    assert_eq!(reader.method_def_special_name(methods[0]), "Closed");
+    assert_eq!(reader.method_def_special_name(methods[1]), "RemoveClosed");
+    assert_eq!(reader.method_def_special_name(methods[2]), "Close");
+
+    let events: Vec<Event> = reader.type_def_events(def).collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(reader.event_name(events[0]), "Closed");
+    assert_eq!(reader.event_add(events[0]), Some(methods[0]));
+    assert_eq!(reader.event_remove(events[0]), Some(methods[1]));
+
+    let Type::TypeDef(event_type, _) = reader.event_type(events[0], &[]) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_name(event_type), "ClosedHandler");
+
+    let def = reader
+        .get(TypeName::new("Test", "IWindowStatics"))
+        .next()
+        .expect("Type missing");
+
+    let events: Vec<Event> = reader.type_def_events(def).collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(reader.event_name(events[0]), "Created");
+}
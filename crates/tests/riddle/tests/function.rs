@@ -0,0 +1,60 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn functions() {
+    let files = run_riddle("function");
+    let reader = &Reader::new(&files);
+
+    let functions: Vec<MethodDef> = reader.namespace_functions("Test").collect();
+    assert_eq!(functions.len(), 2);
+
+    let def = functions[0];
+    assert_eq!(reader.method_def_name(def), "GetData");
+    assert_eq!(reader.method_def_module_name(def), "kernel32.dll");
+    assert_eq!(reader.method_def_extern_abi(def), "system");
+
+    let signature = reader.method_def_signature(def, &[]);
+    assert_eq!(signature.return_type, Type::I32);
+    assert_eq!(signature.params.len(), 3);
+    assert_eq!(signature.params[0].ty, Type::ISize);
+    assert_eq!(signature.params[1].ty, Type::MutPtr(Box::new(Type::U8), 1));
+    assert!(reader
+        .param_flags(signature.params[1].def)
+        .contains(ParamAttributes::Out));
+    assert_eq!(signature.params[2].ty, Type::U32);
+
+    let def = functions[1];
+    assert_eq!(reader.method_def_name(def), "SetData");
+
+    let signature = reader.method_def_signature(def, &[]);
+    assert_eq!(signature.return_type, Type::HRESULT);
+    assert_eq!(
+        signature.params[1].ty,
+        Type::ConstPtr(Box::new(Type::U8), 1)
+    );
+    assert!(reader
+        .param_flags(signature.params[1].def)
+        .contains(ParamAttributes::In));
+
+    let constants: Vec<Field> = reader.namespace_constants("Test").collect();
+    assert_eq!(constants.len(), 5);
+
+    let names: Vec<&str> = constants
+        .iter()
+        .map(|field| reader.field_name(*field))
+        .collect();
+
+    assert_eq!(names, ["FLOAT", "HR", "NAME", "SIZE", "VERSION"]);
+
+    let value = |field| reader.constant_value(reader.field_constant(field).unwrap());
+
+    assert!(matches!(value(constants[0]), Value::F32(value) if value == 1.5));
+    assert!(matches!(value(constants[1]), Value::I32(-2147467259)));
+    assert!(matches!(value(constants[2]), Value::String(value) if value == "riddle"));
+    assert!(matches!(value(constants[3]), Value::I32(-1)));
+    assert!(matches!(value(constants[4]), Value::U16(2)));
+
+    assert_eq!(reader.field_type(constants[1], None), Type::HRESULT);
+    assert_eq!(reader.field_type(constants[2], None), Type::String);
+}
@@ -0,0 +1,68 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn generic() {
+    let files = run_riddle("generic");
+    let reader = &Reader::new(&files);
+
+    let vector = reader
+        .get(TypeName::new("Test", "IMyVector"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_name(vector), "IMyVector`1");
+
+    let generics: Vec<Type> = reader.type_def_generics(vector).collect();
+    assert_eq!(generics.len(), 1);
+
+    let Type::GenericParam(param) = generics[0] else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.generic_param_name(param), "T");
+
+    let methods: Vec<MethodDef> = reader.type_def_methods(vector).collect();
+    let signature = reader.method_def_signature(methods[0], &generics);
+    assert_eq!(signature.return_type, generics[0]);
+
+    let def = reader
+        .get(TypeName::new("Test", "IUsesGenerics"))
+        .next()
+        .expect("Type missing");
+
+    let methods: Vec<MethodDef> = reader.type_def_methods(def).collect();
+    let signature = reader.method_def_signature(methods[0], &[]);
+    assert_eq!(
+        signature.return_type,
+        Type::TypeDef(vector, vec![Type::String])
+    );
+
+    let signature = reader.method_def_signature(methods[1], &[]);
+    assert_eq!(
+        signature.return_type,
+        Type::TypeDef(vector, vec![Type::TypeDef(vector, vec![Type::I32])])
+    );
+
+    // `IMyVector` shares its GUID with `IVector` so the PIID must match that of `IVector<HSTRING>`.
+    let signature = reader.type_def_signature(vector, &[Type::String]);
+    assert_eq!(
+        signature,
+        "pinterface({913337e9-11a1-4345-a3a2-4e7f956e222d};string)"
+    );
+    assert_eq!(
+        windows_core::GUID::from_signature(windows_core::imp::ConstBuffer::from_slice(
+            signature.as_bytes()
+        )),
+        windows_core::GUID::from_u128(0x98b9acc1_4b56_532e_ac73_03d5291cca90)
+    );
+
+    let class = reader
+        .get(TypeName::new("Test", "Strings"))
+        .next()
+        .expect("Type missing");
+
+    let default = reader
+        .type_def_default_interface(class)
+        .expect("Default interface missing");
+    assert_eq!(default, Type::TypeDef(vector, vec![Type::String]));
+}
@@ -0,0 +1,62 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn interface() {
+    let files = run_riddle("interface");
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test", "ITest"))
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(reader.type_def_kind(def), TypeKind::Interface);
+
+    let guid = reader.type_def_guid(def).expect("GUID missing");
+    assert_eq!(format!("{guid:?}"), "8ceeb155-2849-4ce5-9448-91ff70e1e4d9");
+
+    let methods: Vec<MethodDef> = reader.type_def_methods(def).collect();
+    assert_eq!(methods.len(), 4);
+
+    assert_eq!(reader.method_def_name(methods[0]), "Method");
+    assert_eq!(reader.method_def_name(methods[1]), "Method");
+    assert_eq!(reader.method_def_name(methods[2]), "Arrays");
+    assert_eq!(reader.method_def_name(methods[3]), "Void");
+
+    assert_eq!(reader.method_def_special_name(methods[0]), "Method");
+    assert_eq!(
+        reader.method_def_special_name(methods[1]),
+        "MethodWithDefault"
+    );
+
+    let sig = reader.method_def_signature(methods[0], &[]);
+    assert_eq!(sig.return_type, Type::U8);
+    assert_eq!(sig.params.len(), 2);
+    assert_eq!(reader.param_name(sig.params[0].def), "a");
+    assert_eq!(reader.param_flags(sig.params[0].def), ParamAttributes::In);
+    assert_eq!(sig.params[0].ty, Type::I32);
+    assert_eq!(reader.param_name(sig.params[1].def), "b");
+    assert_eq!(reader.param_flags(sig.params[1].def), ParamAttributes::Out);
+    assert_eq!(sig.params[1].ty, Type::String);
+
+    let sig = reader.method_def_signature(methods[1], &[]);
+    assert_eq!(sig.return_type, Type::U8);
+    assert_eq!(sig.params.len(), 1);
+    assert_eq!(reader.param_name(sig.params[0].def), "a");
+    assert_eq!(sig.params[0].ty, Type::I32);
+
+    let sig = reader.method_def_signature(methods[2], &[]);
+    assert_eq!(sig.return_type, Type::Void);
+    assert_eq!(sig.params.len(), 2);
+    assert_eq!(reader.param_name(sig.params[0].def), "a");
+    assert_eq!(reader.param_flags(sig.params[0].def), ParamAttributes::In);
+    assert_eq!(sig.params[0].ty, Type::WinrtArray(Box::new(Type::U8)));
+    assert_eq!(reader.param_name(sig.params[1].def), "b");
+    assert_eq!(reader.param_flags(sig.params[1].def), ParamAttributes::Out);
+    assert_eq!(sig.params[1].ty, Type::WinrtArray(Box::new(Type::I32)));
+
+    let sig = reader.method_def_signature(methods[3], &[]);
+    assert_eq!(sig.return_type, Type::Void);
+    assert!(sig.params.is_empty());
+}
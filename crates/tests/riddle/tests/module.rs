@@ -0,0 +1,43 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn module() {
+    let files = run_riddle("module");
+    let reader = &Reader::new(&files);
+
+    let def = reader
+        .get(TypeName::new("Test.Inner", "Point"))
+        .next()
+        .expect("Type missing");
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 3);
+    assert_eq!(reader.field_name(fields[2]), "size");
+
+    let Type::TypeDef(size, _) = reader.field_type(fields[2], None) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_namespace(size), "Test");
+    assert_eq!(reader.type_def_name(size), "Size");
+
+    let def = reader
+        .get(TypeName::new("Test", "Rect"))
+        .next()
+        .expect("Type missing");
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 2);
+
+    let Type::TypeDef(origin, _) = reader.field_type(fields[0], None) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_namespace(origin), "Test.Inner");
+    assert_eq!(reader.type_def_name(origin), "Point");
+
+    let Type::TypeDef(size, _) = reader.field_type(fields[1], None) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_namespace(size), "Test");
+    assert_eq!(reader.type_def_name(size), "Size");
+}
@@ -27,9 +27,9 @@ fn primitives() {
     assert_eq!(fields.len(), 3);
     assert_eq!(reader.field_name(fields[0]), "field_bool");
     assert_eq!(reader.field_name(fields[1]), "field_inner");
-    assert_eq!(reader.field_name(fields[2]), "field_usize");
+    assert_eq!(reader.field_name(fields[2]), "field_u64");
     assert!(matches!(reader.field_type(fields[0], None), Type::Bool));
-    assert!(matches!(reader.field_type(fields[2], None), Type::USize));
+    assert!(matches!(reader.field_type(fields[2], None), Type::U64));
 
     let Type::TypeDef(def, generics) = reader.field_type(fields[1], None) else { panic!("wrong type") };
     assert_eq!(reader.type_def_namespace(def), "Test");
@@ -0,0 +1,84 @@
+use std::process::Command;
+use windows_metadata::*;
+
+#[test]
+fn split() {
+    // Split .idl into one .winmd per namespace
+    let mut command = Command::new("cargo");
+    command.args([
+        "run",
+        "-p",
+        "riddle",
+        "--",
+        "-in",
+        "tests/module.idl",
+        "-out",
+        "tests/split",
+        "-filter",
+        "Test",
+        "-config",
+        "SPLIT=2",
+    ]);
+    assert!(command.status().unwrap().success());
+
+    let files = vec![
+        read("tests/split/Test.winmd"),
+        read("tests/split/Test.Inner.winmd"),
+    ];
+    check(&files);
+
+    // Merge the split .winmd files back into one
+    let mut command = Command::new("cargo");
+    command.args([
+        "run",
+        "-p",
+        "riddle",
+        "--",
+        "-in",
+        "tests/split",
+        "-out",
+        "tests/split.winmd",
+        "-filter",
+        "Test",
+    ]);
+    assert!(command.status().unwrap().success());
+
+    let files = vec![read("tests/split.winmd")];
+    check(&files);
+}
+
+fn read(path: &str) -> File {
+    File::new(std::fs::read(path).expect("failed to read winmd")).expect("failed to parse winmd")
+}
+
+fn check(files: &[File]) {
+    let reader = &Reader::new(files);
+
+    let def = reader
+        .get(TypeName::new("Test", "Rect"))
+        .next()
+        .expect("Type missing");
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 2);
+
+    let Type::TypeDef(origin, _) = reader.field_type(fields[0], None) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_namespace(origin), "Test.Inner");
+    assert_eq!(reader.type_def_name(origin), "Point");
+
+    let def = reader
+        .get(TypeName::new("Test.Inner", "Point"))
+        .next()
+        .expect("Type missing");
+
+    let fields: Vec<Field> = reader.type_def_fields(def).collect();
+    assert_eq!(fields.len(), 3);
+
+    let Type::TypeDef(size, _) = reader.field_type(fields[2], None) else {
+        panic!("wrong type")
+    };
+    assert_eq!(reader.type_def_namespace(size), "Test");
+    assert_eq!(reader.type_def_name(size), "Size");
+}
@@ -14,7 +14,7 @@ fn primitives() {
     assert_eq!(reader.type_def_kind(def), TypeKind::Struct);
 
     let fields: Vec<Field> = reader.type_def_fields(def).collect();
-    assert_eq!(fields.len(), 13);
+    assert_eq!(fields.len(), 11);
 
     assert_eq!(reader.field_name(fields[0]), "field_bool");
     assert_eq!(reader.field_name(fields[1]), "field_i8");
@@ -27,8 +27,6 @@ fn primitives() {
     assert_eq!(reader.field_name(fields[8]), "field_u64");
     assert_eq!(reader.field_name(fields[9]), "field_f32");
     assert_eq!(reader.field_name(fields[10]), "field_f64");
-    assert_eq!(reader.field_name(fields[11]), "field_isize");
-    assert_eq!(reader.field_name(fields[12]), "field_usize");
 
     assert!(matches!(reader.field_type(fields[0], None), Type::Bool));
     assert!(matches!(reader.field_type(fields[1], None), Type::I8));
@@ -41,6 +39,4 @@ fn primitives() {
     assert!(matches!(reader.field_type(fields[8], None), Type::U64));
     assert!(matches!(reader.field_type(fields[9], None), Type::F32));
     assert!(matches!(reader.field_type(fields[10], None), Type::F64));
-    assert!(matches!(reader.field_type(fields[11], None), Type::ISize));
-    assert!(matches!(reader.field_type(fields[12], None), Type::USize));
 }
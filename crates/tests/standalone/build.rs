@@ -73,6 +73,42 @@ fn main() {
         ],
     );
 
+    // Ensures that BUFFERS writes helpers allocating the buffers filled by
+    // functions following the "call twice to get the size" pattern
+    riddle(
+        "src/b_buffers.rs",
+        &["Windows.Win32.System.LibraryLoader.GetModuleFileNameW"],
+        &["FLATTEN", "MINIMAL", "BUFFERS"],
+    );
+
+    // Ensures that enums and error codes are printed by name and that error
+    // codes can be mapped back to the names of the constants included
+    riddle(
+        "src/b_error_names.rs",
+        &[
+            "Windows.Win32.Foundation.ERROR_ACCESS_DENIED",
+            "Windows.Win32.Foundation.E_FAIL",
+            "Windows.Win32.Foundation.STATUS_ACCESS_VIOLATION",
+            "Windows.Win32.Foundation.S_OK",
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_READ",
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_WRITE",
+        ],
+        &["FLATTEN", "MINIMAL", "ERROR_NAMES"],
+    );
+
+    // Ensures that SYS_TYPED_ENUMS writes enums as newtypes with flag
+    // operators and symbolic Debug output
+    riddle(
+        "src/b_typed_enums.rs",
+        &[
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_DELETE",
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_MODE",
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_READ",
+            "Windows.Win32.Storage.FileSystem.FILE_SHARE_WRITE",
+        ],
+        &["FLATTEN", "SYS", "MINIMAL", "SYS_TYPED_ENUMS"],
+    );
+
     write_win(
         "src/b_win_enumerator.rs",
         &[
@@ -95,6 +131,17 @@ fn main() {
         ],
     );
 
+    // Ensures that functions returning handles with a known free function return
+    // owned wrappers
+    riddle(
+        "src/b_owned.rs",
+        &[
+            "Windows.Win32.System.Console.ClosePseudoConsole",
+            "Windows.Win32.System.Console.CreatePseudoConsole",
+        ],
+        &["FLATTEN", "MINIMAL", "OWNED"],
+    );
+
     write_win("src/b_uri.rs", &["Windows.Foundation.Uri"]);
     write_win("src/b_stringable.rs", &["Windows.Foundation.IStringable"]);
     write_win("src/b_calendar.rs", &["Windows.Globalization.Calendar"]);
@@ -0,0 +1,61 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[inline]
+pub unsafe fn GetModuleFileNameW<P0>(hmodule: P0, lpfilename: &mut [u16]) -> u32
+where
+    P0: ::windows_core::IntoParam<HMODULE>,
+{
+    ::windows_targets::link!("kernel32.dll" "system" fn GetModuleFileNameW(hmodule : HMODULE, lpfilename : ::windows_core::PWSTR, nsize : u32) -> u32);
+    GetModuleFileNameW(
+        hmodule.into_param().abi(),
+        ::core::mem::transmute(lpfilename.as_ptr()),
+        lpfilename.len() as _,
+    )
+}
+pub unsafe fn GetModuleFileNameW_string<P0>(
+    hmodule: P0,
+) -> ::windows_core::Result<::std::string::String>
+where
+    P0: ::windows_core::IntoParam<HMODULE>,
+{
+    ::windows_targets::link!("kernel32.dll" "system" fn GetModuleFileNameW(hmodule : HMODULE, lpfilename : ::windows_core::PWSTR, nsize : u32) -> u32);
+    let hmodule = hmodule.into_param();
+    let buffer__ = ::windows_core::imp::read_truncated_buffer(|data__: *mut u16, capacity__| {
+        GetModuleFileNameW(hmodule.abi(), ::core::mem::transmute(data__), capacity__)
+    })?;
+    Ok(::windows_core::imp::wide_to_string(&buffer__))
+}
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct HMODULE(pub isize);
+impl HMODULE {
+    pub fn is_invalid(&self) -> bool {
+        self.0 == 0
+    }
+}
+impl ::core::default::Default for HMODULE {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+impl ::core::clone::Clone for HMODULE {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::marker::Copy for HMODULE {}
+impl ::core::fmt::Debug for HMODULE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("HMODULE").field(&self.0).finish()
+    }
+}
+impl ::windows_core::TypeKind for HMODULE {
+    type TypeKind = ::windows_core::CopyType;
+}
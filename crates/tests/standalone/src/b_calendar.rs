@@ -1363,7 +1363,21 @@ impl ::windows_core::TypeKind for DayOfWeek {
 }
 impl ::core::fmt::Debug for DayOfWeek {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_tuple("DayOfWeek").field(&self.0).finish()
+        match self.0 {
+            0i32 => f.write_str("Sunday"),
+            1i32 => f.write_str("Monday"),
+            2i32 => f.write_str("Tuesday"),
+            3i32 => f.write_str("Wednesday"),
+            4i32 => f.write_str("Thursday"),
+            5i32 => f.write_str("Friday"),
+            6i32 => f.write_str("Saturday"),
+            _ => ::core::write!(f, "DayOfWeek({:#x})", self.0),
+        }
+    }
+}
+impl ::core::fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
     }
 }
 impl ::windows_core::RuntimeType for DayOfWeek {
@@ -0,0 +1,191 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+pub const ERROR_ACCESS_DENIED: WIN32_ERROR = WIN32_ERROR(5u32);
+pub const E_FAIL: ::windows_core::HRESULT = ::windows_core::HRESULT(-2147467259i32);
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct FILE_SHARE_MODE(pub u32);
+impl ::core::marker::Copy for FILE_SHARE_MODE {}
+impl ::core::clone::Clone for FILE_SHARE_MODE {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for FILE_SHARE_MODE {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::windows_core::TypeKind for FILE_SHARE_MODE {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for FILE_SHARE_MODE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        const NAMES: &[(&str, FILE_SHARE_MODE)] = &[
+            ("FILE_SHARE_NONE", FILE_SHARE_MODE(0u32)),
+            ("FILE_SHARE_READ", FILE_SHARE_MODE(1u32)),
+            ("FILE_SHARE_WRITE", FILE_SHARE_MODE(2u32)),
+            ("FILE_SHARE_DELETE", FILE_SHARE_MODE(4u32)),
+        ];
+        if let Some((name, _)) = NAMES.iter().find(|(_, value)| value.0 == self.0) {
+            return f.write_str(name);
+        }
+        let mut remaining = self.0;
+        let mut first = true;
+        for (name, value) in NAMES {
+            if value.0 != 0 && remaining & value.0 == value.0 {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                remaining &= !value.0;
+                first = false;
+            }
+        }
+        if first {
+            ::core::write!(f, "FILE_SHARE_MODE({:#x})", self.0)
+        } else if remaining != 0 {
+            ::core::write!(f, " | {:#x}", remaining)
+        } else {
+            Ok(())
+        }
+    }
+}
+impl ::core::fmt::Display for FILE_SHARE_MODE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
+    }
+}
+impl FILE_SHARE_MODE {
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl ::core::ops::BitOr for FILE_SHARE_MODE {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+impl ::core::ops::BitAnd for FILE_SHARE_MODE {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+impl ::core::ops::BitOrAssign for FILE_SHARE_MODE {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0.bitor_assign(other.0)
+    }
+}
+impl ::core::ops::BitAndAssign for FILE_SHARE_MODE {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0.bitand_assign(other.0)
+    }
+}
+impl ::core::ops::Not for FILE_SHARE_MODE {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+}
+pub const FILE_SHARE_READ: FILE_SHARE_MODE = FILE_SHARE_MODE(1u32);
+pub const FILE_SHARE_WRITE: FILE_SHARE_MODE = FILE_SHARE_MODE(2u32);
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct NTSTATUS(pub i32);
+impl ::core::default::Default for NTSTATUS {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+impl ::core::clone::Clone for NTSTATUS {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::marker::Copy for NTSTATUS {}
+impl ::core::fmt::Debug for NTSTATUS {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            ::core::option::Option::Some(name) => f.write_str(name),
+            ::core::option::Option::None => ::core::write!(f, "NTSTATUS({:#x})", self.0),
+        }
+    }
+}
+impl ::core::fmt::Display for NTSTATUS {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            ::core::option::Option::Some(name) => f.write_str(name),
+            ::core::option::Option::None => ::core::write!(f, "{:#x}", self.0),
+        }
+    }
+}
+impl ::windows_core::TypeKind for NTSTATUS {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl NTSTATUS {
+    pub const fn name(&self) -> ::core::option::Option<&'static str> {
+        match self.0 {
+            -1073741819 => Some("STATUS_ACCESS_VIOLATION"),
+            _ => None,
+        }
+    }
+}
+pub const STATUS_ACCESS_VIOLATION: NTSTATUS = NTSTATUS(-1073741819i32);
+pub const S_OK: ::windows_core::HRESULT = ::windows_core::HRESULT(0i32);
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct WIN32_ERROR(pub u32);
+impl ::core::marker::Copy for WIN32_ERROR {}
+impl ::core::clone::Clone for WIN32_ERROR {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for WIN32_ERROR {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::windows_core::TypeKind for WIN32_ERROR {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for WIN32_ERROR {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            ::core::option::Option::Some(name) => f.write_str(name),
+            ::core::option::Option::None => ::core::write!(f, "WIN32_ERROR({:#x})", self.0),
+        }
+    }
+}
+impl ::core::fmt::Display for WIN32_ERROR {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self.name() {
+            ::core::option::Option::Some(name) => f.write_str(name),
+            ::core::option::Option::None => ::core::write!(f, "{:#x}", self.0),
+        }
+    }
+}
+impl WIN32_ERROR {
+    pub const fn name(&self) -> ::core::option::Option<&'static str> {
+        match self.0 {
+            5 => Some("ERROR_ACCESS_DENIED"),
+            _ => None,
+        }
+    }
+}
+pub const fn hresult_name(value: ::windows_core::HRESULT) -> ::core::option::Option<&'static str> {
+    match value.0 {
+        -2147467259 => Some("E_FAIL"),
+        0 => Some("S_OK"),
+        _ => None,
+    }
+}
@@ -0,0 +1,157 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[inline]
+pub unsafe fn ClosePseudoConsole<P0>(hpc: P0)
+where
+    P0: ::windows_core::IntoParam<HPCON>,
+{
+    ::windows_targets::link!("kernel32.dll" "system" fn ClosePseudoConsole(hpc : HPCON) -> ());
+    ClosePseudoConsole(hpc.into_param().abi())
+}
+#[inline]
+pub unsafe fn CreatePseudoConsole<P0, P1>(
+    size: COORD,
+    hinput: P0,
+    houtput: P1,
+    dwflags: u32,
+) -> ::windows_core::Result<OwnedHPCON>
+where
+    P0: ::windows_core::IntoParam<HANDLE>,
+    P1: ::windows_core::IntoParam<HANDLE>,
+{
+    ::windows_targets::link!("kernel32.dll" "system" fn CreatePseudoConsole(size : COORD, hinput : HANDLE, houtput : HANDLE, dwflags : u32, phpc : *mut HPCON) -> ::windows_core::HRESULT);
+    let mut result__ = ::std::mem::zeroed();
+    CreatePseudoConsole(
+        ::core::mem::transmute(size),
+        hinput.into_param().abi(),
+        houtput.into_param().abi(),
+        dwflags,
+        &mut result__,
+    )
+    .from_abi(result__)
+    .map(|handle| OwnedHPCON::from_raw(handle))
+}
+#[repr(C)]
+pub struct COORD {
+    pub X: i16,
+    pub Y: i16,
+}
+impl ::core::marker::Copy for COORD {}
+impl ::core::clone::Clone for COORD {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::fmt::Debug for COORD {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("COORD")
+            .field("X", &self.X)
+            .field("Y", &self.Y)
+            .finish()
+    }
+}
+impl ::windows_core::TypeKind for COORD {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::cmp::PartialEq for COORD {
+    fn eq(&self, other: &Self) -> bool {
+        self.X == other.X && self.Y == other.Y
+    }
+}
+impl ::core::cmp::Eq for COORD {}
+impl ::core::default::Default for COORD {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct HANDLE(pub isize);
+impl HANDLE {
+    pub fn is_invalid(&self) -> bool {
+        self.0 == -1 || self.0 == 0
+    }
+}
+impl ::core::default::Default for HANDLE {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+impl ::core::clone::Clone for HANDLE {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::marker::Copy for HANDLE {}
+impl ::core::fmt::Debug for HANDLE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("HANDLE").field(&self.0).finish()
+    }
+}
+impl ::windows_core::TypeKind for HANDLE {
+    type TypeKind = ::windows_core::CopyType;
+}
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct HPCON(pub isize);
+impl HPCON {
+    pub fn is_invalid(&self) -> bool {
+        self.0 == -1 || self.0 == 0
+    }
+}
+impl ::core::default::Default for HPCON {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+impl ::core::clone::Clone for HPCON {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::marker::Copy for HPCON {}
+impl ::core::fmt::Debug for HPCON {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("HPCON").field(&self.0).finish()
+    }
+}
+impl ::windows_core::TypeKind for HPCON {
+    type TypeKind = ::windows_core::CopyType;
+}
+#[repr(transparent)]
+pub struct OwnedHPCON(HPCON);
+impl OwnedHPCON {
+    #[doc = "Takes ownership of the handle, which is freed with `ClosePseudoConsole` when the wrapper is dropped."]
+    pub unsafe fn from_raw(handle: HPCON) -> Self {
+        Self(handle)
+    }
+    pub fn as_raw(&self) -> HPCON {
+        self.0
+    }
+    pub fn into_raw(self) -> HPCON {
+        let handle = self.0;
+        ::core::mem::forget(self);
+        handle
+    }
+}
+impl ::core::ops::Drop for OwnedHPCON {
+    fn drop(&mut self) {
+        if !self.0.is_invalid() {
+            unsafe {
+                let _ = ClosePseudoConsole(self.0);
+            }
+        }
+    }
+}
+impl ::core::fmt::Debug for OwnedHPCON {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("OwnedHPCON").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,96 @@
+// Bindings generated by `riddle` 0.0.1
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+pub const FILE_SHARE_DELETE: FILE_SHARE_MODE = FILE_SHARE_MODE(4u32);
+#[repr(transparent)]
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct FILE_SHARE_MODE(pub u32);
+impl ::core::marker::Copy for FILE_SHARE_MODE {}
+impl ::core::clone::Clone for FILE_SHARE_MODE {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::core::default::Default for FILE_SHARE_MODE {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+impl ::core::fmt::Debug for FILE_SHARE_MODE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        const NAMES: &[(&str, FILE_SHARE_MODE)] = &[
+            ("FILE_SHARE_NONE", FILE_SHARE_MODE(0u32)),
+            ("FILE_SHARE_READ", FILE_SHARE_MODE(1u32)),
+            ("FILE_SHARE_WRITE", FILE_SHARE_MODE(2u32)),
+            ("FILE_SHARE_DELETE", FILE_SHARE_MODE(4u32)),
+        ];
+        if let Some((name, _)) = NAMES.iter().find(|(_, value)| value.0 == self.0) {
+            return f.write_str(name);
+        }
+        let mut remaining = self.0;
+        let mut first = true;
+        for (name, value) in NAMES {
+            if value.0 != 0 && remaining & value.0 == value.0 {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                remaining &= !value.0;
+                first = false;
+            }
+        }
+        if first {
+            ::core::write!(f, "FILE_SHARE_MODE({:#x})", self.0)
+        } else if remaining != 0 {
+            ::core::write!(f, " | {:#x}", remaining)
+        } else {
+            Ok(())
+        }
+    }
+}
+impl ::core::fmt::Display for FILE_SHARE_MODE {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Debug::fmt(self, f)
+    }
+}
+impl FILE_SHARE_MODE {
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl ::core::ops::BitOr for FILE_SHARE_MODE {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+impl ::core::ops::BitAnd for FILE_SHARE_MODE {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+impl ::core::ops::BitOrAssign for FILE_SHARE_MODE {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0.bitor_assign(other.0)
+    }
+}
+impl ::core::ops::BitAndAssign for FILE_SHARE_MODE {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0.bitand_assign(other.0)
+    }
+}
+impl ::core::ops::Not for FILE_SHARE_MODE {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+}
+pub const FILE_SHARE_READ: FILE_SHARE_MODE = FILE_SHARE_MODE(1u32);
+pub const FILE_SHARE_WRITE: FILE_SHARE_MODE = FILE_SHARE_MODE(2u32);
@@ -4,11 +4,13 @@
 mod b_arch;
 mod b_arch_dependencies;
 mod b_bstr;
+mod b_buffers;
 mod b_calendar;
 mod b_constant_types;
 mod b_depends;
 mod b_enumeration;
 mod b_enumerator;
+mod b_error_names;
 mod b_guid;
 mod b_hresult;
 mod b_hstring;
@@ -16,6 +18,7 @@ mod b_inspectable;
 mod b_nested;
 mod b_none;
 mod b_overloads;
+mod b_owned;
 mod b_pcstr;
 mod b_pcwstr;
 mod b_pstr;
@@ -23,6 +26,7 @@ mod b_pwstr;
 mod b_std;
 mod b_stringable;
 mod b_test;
+mod b_typed_enums;
 mod b_unknown;
 mod b_uri;
 mod b_win_enumerator;
@@ -112,6 +116,76 @@ fn enumerator() {
     assert_eq!(b_enumerator::WAIT_TIMEOUT, 258);
 }
 
+#[test]
+fn buffers() -> windows_core::Result<()> {
+    use b_buffers::*;
+    unsafe {
+        // The path of the test executable is read into a buffer grown as needed.
+        let path = GetModuleFileNameW_string(HMODULE(0))?;
+        assert!(path.ends_with(".exe"));
+
+        let mut buffer = [0; 260];
+        let len = GetModuleFileNameW(HMODULE(0), &mut buffer);
+        assert_eq!(String::from_utf16_lossy(&buffer[..len as usize]), path);
+    }
+    Ok(())
+}
+
+#[test]
+fn error_names() {
+    use b_error_names::*;
+    assert_eq!(format!("{ERROR_ACCESS_DENIED:?}"), "ERROR_ACCESS_DENIED");
+    assert_eq!(format!("{:?}", WIN32_ERROR(2)), "WIN32_ERROR(0x2)");
+    assert_eq!(ERROR_ACCESS_DENIED.to_string(), "ERROR_ACCESS_DENIED");
+    assert_eq!(WIN32_ERROR(2).to_string(), "0x2");
+    assert_eq!(ERROR_ACCESS_DENIED.name(), Some("ERROR_ACCESS_DENIED"));
+    assert_eq!(WIN32_ERROR(2).name(), None);
+
+    assert_eq!(
+        STATUS_ACCESS_VIOLATION.name(),
+        Some("STATUS_ACCESS_VIOLATION")
+    );
+    assert_eq!(NTSTATUS(1).name(), None);
+    assert_eq!(
+        format!("{STATUS_ACCESS_VIOLATION:?}"),
+        "STATUS_ACCESS_VIOLATION"
+    );
+    assert_eq!(format!("{:?}", NTSTATUS(1)), "NTSTATUS(0x1)");
+    assert_eq!(
+        STATUS_ACCESS_VIOLATION.to_string(),
+        "STATUS_ACCESS_VIOLATION"
+    );
+    assert_eq!(NTSTATUS(1).to_string(), "0x1");
+
+    assert_eq!(hresult_name(E_FAIL), Some("E_FAIL"));
+    assert_eq!(hresult_name(S_OK), Some("S_OK"));
+    assert_eq!(hresult_name(windows_core::HRESULT(1)), None);
+
+    let mode = FILE_SHARE_READ | FILE_SHARE_WRITE;
+    assert_eq!(format!("{FILE_SHARE_READ:?}"), "FILE_SHARE_READ");
+    assert_eq!(format!("{mode:?}"), "FILE_SHARE_READ | FILE_SHARE_WRITE");
+    assert_eq!(mode.to_string(), "FILE_SHARE_READ | FILE_SHARE_WRITE");
+}
+
+#[test]
+fn typed_enums() {
+    use b_typed_enums::*;
+    let mut mode = FILE_SHARE_READ | FILE_SHARE_WRITE;
+    assert!(mode.contains(FILE_SHARE_READ));
+    assert!(!mode.contains(FILE_SHARE_DELETE));
+    assert_eq!(format!("{mode:?}"), "FILE_SHARE_READ | FILE_SHARE_WRITE");
+
+    mode &= !FILE_SHARE_WRITE;
+    assert_eq!(mode, FILE_SHARE_READ);
+    assert_eq!(format!("{mode:?}"), "FILE_SHARE_READ");
+    assert_eq!(
+        format!("{:?}", FILE_SHARE_MODE::default()),
+        "FILE_SHARE_NONE"
+    );
+    assert_eq!(format!("{:?}", FILE_SHARE_MODE(9)), "FILE_SHARE_READ | 0x8");
+    assert_eq!(FILE_SHARE_MODE(9).to_string(), "FILE_SHARE_READ | 0x8");
+}
+
 #[test]
 fn std() {
     unsafe {
@@ -150,6 +224,23 @@ fn test() {
     }
 }
 
+#[test]
+fn owned() {
+    use b_owned::*;
+    unsafe {
+        // Pseudo consoles require valid input and output handles.
+        let console: windows_core::Result<OwnedHPCON> =
+            CreatePseudoConsole(COORD { X: 80, Y: 25 }, HANDLE(0), HANDLE(0), 0);
+        assert!(console.is_err());
+
+        // Invalid handles are not passed to `ClosePseudoConsole` when dropped.
+        let console = OwnedHPCON::from_raw(HPCON(0));
+        assert_eq!(console.as_raw(), HPCON(0));
+        assert_eq!(console.into_raw(), HPCON(0));
+        drop(OwnedHPCON::from_raw(HPCON(-1)));
+    }
+}
+
 #[test]
 fn uri() -> windows_core::Result<()> {
     use b_uri::*;
@@ -6,8 +6,8 @@ fn main() {
         "-p",
         "riddle",
         "--",
-        "-etc",
-        "crates/tools/sys/bindings.txt",
+        "-manifest",
+        "crates/tools/bindings/bindings.json",
     ]);
 
     assert!(command.status().unwrap().success());
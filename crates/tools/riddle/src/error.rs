@@ -4,7 +4,40 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub struct Error {
     message: String,
     path: String,
-    span: Option<(usize, usize)>,
+    span: Option<Span>,
+    // The line of source code containing the span, if available.
+    excerpt: String,
+    // Any further errors reported in the same run.
+    others: Vec<Error>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Span {
+    // One-based line number.
+    line: u32,
+    // Zero-based column as reported by `proc_macro2`.
+    column: u32,
+    // Number of columns to underline.
+    length: u32,
+}
+
+impl Span {
+    fn new(span: proc_macro2::Span) -> Self {
+        let start = span.start();
+        let end = span.end();
+
+        let length = if end.line == start.line {
+            end.column.saturating_sub(start.column).max(1)
+        } else {
+            1
+        };
+
+        Self {
+            line: start.line as _,
+            column: start.column as _,
+            length: length as _,
+        }
+    }
 }
 
 impl std::error::Error for Error {}
@@ -17,25 +50,33 @@ impl From<Error> for std::io::Error {
 
 impl From<syn::Error> for Error {
     fn from(error: syn::Error) -> Self {
-        let start = error.span().start();
-        Self {
-            message: error.to_string(),
-            span: Some((start.line, start.column)),
-            ..Self::default()
-        }
+        let errors = error
+            .into_iter()
+            .map(|error| Self::new(&error.to_string()).with_span(error.span()))
+            .collect();
+
+        Self::combine(errors)
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(fmt, "error: {}", self.message)?;
-        if !self.path.is_empty() {
-            if let Some((line, column)) = self.span {
-                writeln!(fmt, "  --> {}:{line}:{column}", self.path)?;
-            } else {
-                writeln!(fmt, "  --> {}", self.path)?;
-            }
+        self.fmt_one(fmt)?;
+
+        for other in &self.others {
+            writeln!(fmt)?;
+            other.fmt_one(fmt)?;
+        }
+
+        if !self.others.is_empty() {
+            writeln!(fmt)?;
+            writeln!(
+                fmt,
+                "error: aborting due to {} previous errors",
+                self.others.len() + 1
+            )?;
         }
+
         Ok(())
     }
 }
@@ -51,15 +92,147 @@ impl Error {
     pub fn with_path(self, path: &str) -> Self {
         Self {
             path: path.to_string(),
+            others: self
+                .others
+                .into_iter()
+                .map(|other| other.with_path(path))
+                .collect(),
             ..self
         }
     }
 
     pub fn with_span(self, span: proc_macro2::Span) -> Self {
-        let start = span.start();
         Self {
-            span: Some((start.line, start.column)),
+            span: Some(Span::new(span)),
+            ..self
+        }
+    }
+
+    /// Sets the location of the error for sources that aren't parsed by `syn`. The line is one-based and the column is
+    /// zero-based.
+    pub fn with_position(self, line: usize, column: usize) -> Self {
+        Self {
+            span: Some(Span {
+                line: line as _,
+                column: column as _,
+                length: 1,
+            }),
+            ..self
+        }
+    }
+
+    /// Adds the offending line of source code so that it can be displayed along with the error.
+    pub fn with_source(self, source: &str) -> Self {
+        let excerpt = self
+            .span
+            .and_then(|span| source.lines().nth((span.line as usize).checked_sub(1)?))
+            .map_or(String::new(), |line| line.to_string());
+
+        Self {
+            excerpt,
+            others: self
+                .others
+                .into_iter()
+                .map(|other| other.with_source(source))
+                .collect(),
             ..self
         }
     }
+
+    /// Combines multiple errors, ordered by location, so that they can all be reported at once.
+    pub fn combine(errors: Vec<Self>) -> Self {
+        let mut errors: Vec<Self> = errors
+            .into_iter()
+            .flat_map(|mut error| {
+                let others = std::mem::take(&mut error.others);
+                std::iter::once(error).chain(others)
+            })
+            .collect();
+
+        errors.sort_by(|a, b| (&a.path, a.span).cmp(&(&b.path, b.span)));
+
+        if errors.is_empty() {
+            Self::default()
+        } else {
+            let mut error = errors.remove(0);
+            error.others = errors;
+            error
+        }
+    }
+
+    /// Returns `Ok` if there are no errors, otherwise an error reporting all of them.
+    pub fn check(errors: Vec<Self>) -> Result<()> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Self::combine(errors))
+        }
+    }
+
+    fn fmt_one(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(fmt, "error: {}", self.message)?;
+
+        if self.path.is_empty() {
+            return Ok(());
+        }
+
+        let Some(span) = self.span else {
+            return writeln!(fmt, "  --> {}", self.path);
+        };
+
+        writeln!(fmt, "  --> {}:{}:{}", self.path, span.line, span.column + 1)?;
+
+        if self.excerpt.is_empty() {
+            return Ok(());
+        }
+
+        // Tabs are kept so that the underline lines up with the excerpt.
+        let indent: String = self
+            .excerpt
+            .chars()
+            .take(span.column as _)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        let line = span.line.to_string();
+        let gutter = " ".repeat(line.len());
+
+        writeln!(fmt, "{gutter} |")?;
+        writeln!(fmt, "{line} | {}", self.excerpt)?;
+        writeln!(fmt, "{gutter} | {indent}{}", "^".repeat(span.length as _))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_display() {
+        let source =
+            "mod Test {\n    struct Rect {\n        origin: Point,\n        size: Size,\n    }\n}";
+
+        let error = crate::idl::File::parse_str(source)
+            .and_then(|file| file.into_winmd())
+            .map(|_| ())
+            .unwrap_err()
+            .with_path("test.idl")
+            .with_source(source);
+
+        assert_eq!(
+            error.to_string(),
+            r#"error: `Point` not found
+  --> test.idl:3:17
+  |
+3 |         origin: Point,
+  |                 ^^^^^
+
+error: `Size` not found
+  --> test.idl:4:15
+  |
+4 |         size: Size,
+  |               ^^^^
+
+error: aborting due to 2 previous errors
+"#
+        );
+    }
 }
@@ -0,0 +1,370 @@
+use crate::{idl, Error, Result};
+use std::collections::{HashMap, HashSet};
+use syn::spanned::Spanned;
+
+// Type names that are understood by the IDL compiler and never need to be resolved.
+const BUILTINS: [&str; 26] = [
+    "void",
+    "bool",
+    "char",
+    "i8",
+    "u8",
+    "i16",
+    "u16",
+    "i32",
+    "u32",
+    "i64",
+    "u64",
+    "f32",
+    "f64",
+    "isize",
+    "usize",
+    "str",
+    "HSTRING",
+    "GUID",
+    "IUnknown",
+    "IInspectable",
+    "HRESULT",
+    "PSTR",
+    "PWSTR",
+    "PCSTR",
+    "PCWSTR",
+    "BSTR",
+];
+
+/// Rewrites every type reference in the IDL file as a fully-qualified path so that subsequent stages can assume
+/// that any path with more than one segment is absolute.
+///
+/// * `super` and `self` paths can't refer to something outside of the IDL file.
+/// * Unqualified names refer to types in the same module before any `use` declarations are considered.
+/// * `use` declarations don't support globs or renames and must name all externally defined types.
+/// * Qualified paths are first tried relative to the current module and are otherwise assumed to be absolute.
+pub fn canonicalize(file: &mut idl::File) -> Result<()> {
+    let mut resolver = Resolver::default();
+
+    for module in &file.modules {
+        resolver.collect_module(module, &module.name);
+    }
+
+    for reference in &file.references {
+        resolver.collect_use(&reference.tree, &mut vec![]);
+    }
+
+    for module in &mut file.modules {
+        let namespace = module.name.clone();
+        resolver.resolve_module(module, &namespace);
+    }
+
+    Error::check(resolver.errors)
+}
+
+#[derive(Default)]
+struct Resolver {
+    // Fully-qualified names of the types defined in the IDL file - e.g. "Windows.Foundation.Uri"
+    types: HashSet<String>,
+    // Local-to-qualified type names found in use declarations - e.g. "IStringable" -> "Windows.Foundation.IStringable"
+    uses: HashMap<String, String>,
+    // Type parameters of the generic interface or delegate being resolved - e.g. "T" in `IVector<T>`
+    generics: Vec<String>,
+    // Errors are collected rather than returned so that as many as possible can be reported at once.
+    errors: Vec<Error>,
+}
+
+impl Resolver {
+    fn collect_module(&mut self, module: &idl::Module, namespace: &str) {
+        for member in &module.members {
+            if let idl::ModuleMember::Module(module) = member {
+                self.collect_module(module, &format!("{namespace}.{}", module.name));
+            } else {
+                // Duplicate names are reported by `validate`.
+                self.types.insert(format!("{namespace}.{}", member.name()));
+            }
+        }
+    }
+
+    fn collect_use(&mut self, tree: &syn::UseTree, prefix: &mut Vec<String>) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.collect_use(&path.tree, prefix);
+                prefix.pop();
+            }
+            syn::UseTree::Name(name) => {
+                if prefix.is_empty() {
+                    self.errors.push(
+                        Error::new("use declarations must be qualified").with_span(name.span()),
+                    );
+                    return;
+                }
+
+                let local = name.ident.to_string();
+                let qualified = format!("{}.{local}", prefix.join("."));
+
+                if self.uses.insert(local, qualified).is_some() {
+                    self.errors.push(
+                        Error::new(&format!("`{}` is imported more than once", name.ident))
+                            .with_span(name.span()),
+                    );
+                }
+            }
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.collect_use(tree, prefix);
+                }
+            }
+            syn::UseTree::Glob(glob) => self
+                .errors
+                .push(Error::new("glob imports not supported").with_span(glob.span())),
+            syn::UseTree::Rename(rename) => self
+                .errors
+                .push(Error::new("use renames not supported").with_span(rename.span())),
+        }
+    }
+
+    fn report(&mut self, result: Result<()>) {
+        if let Err(error) = result {
+            self.errors.push(error);
+        }
+    }
+
+    fn resolve_module(&mut self, module: &mut idl::Module, namespace: &str) {
+        for member in &mut module.members {
+            match member {
+                idl::ModuleMember::Module(module) => {
+                    let namespace = format!("{namespace}.{}", module.name);
+                    self.resolve_module(module, &namespace);
+                }
+                idl::ModuleMember::Interface(member) => {
+                    self.generics = member.generics.clone();
+
+                    for method in &mut member.methods {
+                        self.resolve_signature(namespace, &mut method.sig);
+                    }
+
+                    self.generics.clear();
+                }
+                idl::ModuleMember::Struct(member) => {
+                    for field in &mut member.fields {
+                        self.resolve_type(namespace, &mut field.ty);
+                    }
+                }
+                idl::ModuleMember::Enum(_) => {}
+                idl::ModuleMember::Class(member) => {
+                    for path in &mut member.extends {
+                        let result = self.resolve_path(namespace, path);
+                        self.report(result);
+                    }
+
+                    for attribute in &mut member.attributes {
+                        let result = self.resolve_class_attribute(namespace, attribute);
+                        self.report(result);
+                    }
+                }
+                idl::ModuleMember::Delegate(member) => {
+                    self.generics = member.generics.clone();
+                    self.resolve_signature(namespace, &mut member.signature);
+                    self.generics.clear();
+                }
+                idl::ModuleMember::Function(member) => {
+                    self.resolve_signature(namespace, &mut member.signature)
+                }
+                idl::ModuleMember::Constant(member) => {
+                    self.resolve_type(namespace, &mut member.item.ty)
+                }
+            }
+        }
+    }
+
+    // The first argument of the `activatable`, `static` and `composable` attributes names an interface.
+    fn resolve_class_attribute(
+        &mut self,
+        namespace: &str,
+        attribute: &mut syn::Attribute,
+    ) -> Result<()> {
+        let syn::Meta::List(list) = &mut attribute.meta else {
+            return Ok(());
+        };
+
+        if !["base", "activatable", "static", "composable"]
+            .iter()
+            .any(|name| list.path.is_ident(name))
+        {
+            return Ok(());
+        }
+
+        let mut args = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+        )?;
+
+        if let Some(first) = args.first_mut() {
+            self.resolve_path(namespace, first)?;
+        }
+
+        let mut tokens = proc_macro2::TokenStream::new();
+
+        for pair in args.pairs() {
+            path_tokens(pair.value(), &mut tokens);
+
+            if let Some(comma) = pair.punct() {
+                let mut punct = proc_macro2::Punct::new(',', proc_macro2::Spacing::Alone);
+                punct.set_span(comma.span);
+                tokens.extend([proc_macro2::TokenTree::Punct(punct)]);
+            }
+        }
+
+        list.tokens = tokens;
+        Ok(())
+    }
+
+    fn resolve_signature(&mut self, namespace: &str, signature: &mut syn::Signature) {
+        for input in &mut signature.inputs {
+            if let syn::FnArg::Typed(pat_type) = input {
+                self.resolve_type(namespace, &mut pat_type.ty);
+            }
+        }
+
+        if let syn::ReturnType::Type(_, ty) = &mut signature.output {
+            self.resolve_type(namespace, ty);
+        }
+    }
+
+    fn resolve_type(&mut self, namespace: &str, ty: &mut syn::Type) {
+        match ty {
+            syn::Type::Path(ty) => {
+                let result = self.resolve_path(namespace, &mut ty.path);
+                self.report(result);
+            }
+            syn::Type::Ptr(ptr) => self.resolve_type(namespace, &mut ptr.elem),
+            syn::Type::Reference(reference) => self.resolve_type(namespace, &mut reference.elem),
+            syn::Type::Slice(slice) => self.resolve_type(namespace, &mut slice.elem),
+            syn::Type::Array(array) => self.resolve_type(namespace, &mut array.elem),
+            _ => {}
+        }
+    }
+
+    fn resolve_path(&mut self, namespace: &str, path: &mut syn::Path) -> Result<()> {
+        if let Some(segment) = path.segments.last_mut() {
+            if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                for arg in &mut args.args {
+                    if let syn::GenericArgument::Type(ty) = arg {
+                        self.resolve_type(namespace, ty);
+                    }
+                }
+            }
+        }
+
+        let segments: Vec<String> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+
+        let not_found = || {
+            let name = segments.join("::");
+            Error::new(&format!("`{name}` not found")).with_span(path.span())
+        };
+
+        let qualified = match segments.as_slice() {
+            [name] if BUILTINS.contains(&name.as_str()) || self.generics.contains(name) => {
+                return Ok(())
+            }
+            [name] => {
+                let local = format!("{namespace}.{name}");
+
+                if self.types.contains(&local) {
+                    local
+                } else if let Some(qualified) = self.uses.get(name) {
+                    qualified.clone()
+                } else {
+                    return Err(not_found());
+                }
+            }
+            [first, ..] if first == "super" || first == "self" => {
+                let mut base: Vec<&str> = namespace.split('.').collect();
+                let mut rest = segments.as_slice();
+
+                if first == "self" {
+                    rest = &rest[1..];
+                }
+
+                while rest.first().map(String::as_str) == Some("super") {
+                    if base.pop().is_none() {
+                        return Err(not_found());
+                    }
+                    rest = &rest[1..];
+                }
+
+                let qualified = base
+                    .iter()
+                    .copied()
+                    .chain(rest.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(".");
+
+                if !self.types.contains(&qualified) {
+                    return Err(not_found());
+                }
+
+                qualified
+            }
+            [first, rest @ ..] => {
+                let relative = format!("{namespace}.{}", segments.join("."));
+
+                if self.types.contains(&relative) {
+                    relative
+                } else if let Some(qualified) = self.uses.get(first) {
+                    // A `use` declaration may also name a namespace - e.g. `use Windows::Foundation;` and `Foundation::Uri`
+                    format!("{qualified}.{}", rest.join("."))
+                } else {
+                    // Types defined outside of the IDL file can't be verified until the metadata is combined.
+                    segments.join(".")
+                }
+            }
+            [] => return Ok(()),
+        };
+
+        set_path(path, &qualified);
+        Ok(())
+    }
+}
+
+// Replaces the path with the qualified name while preserving any generic arguments and the original span.
+fn set_path(path: &mut syn::Path, qualified: &str) {
+    let span = path.span();
+    let arguments = path
+        .segments
+        .last()
+        .map(|segment| segment.arguments.clone())
+        .unwrap_or_default();
+
+    path.leading_colon = None;
+    path.segments = qualified
+        .split('.')
+        .map(|name| syn::PathSegment::from(syn::Ident::new(name, span)))
+        .collect();
+
+    if let Some(segment) = path.segments.last_mut() {
+        segment.arguments = arguments;
+    }
+}
+
+fn path_tokens(path: &syn::Path, tokens: &mut proc_macro2::TokenStream) {
+    let mut first = true;
+
+    for segment in &path.segments {
+        if first {
+            first = false;
+        } else {
+            let mut joint = proc_macro2::Punct::new(':', proc_macro2::Spacing::Joint);
+            let mut alone = proc_macro2::Punct::new(':', proc_macro2::Spacing::Alone);
+            joint.set_span(segment.ident.span());
+            alone.set_span(segment.ident.span());
+            tokens.extend([
+                proc_macro2::TokenTree::Punct(joint),
+                proc_macro2::TokenTree::Punct(alone),
+            ]);
+        }
+
+        tokens.extend([proc_macro2::TokenTree::Ident(segment.ident.clone())]);
+    }
+}
@@ -1,5 +1,7 @@
+mod canonicalize;
 mod to_idl;
 mod to_winmd;
+mod validate;
 mod writer;
 use crate::Result;
 use syn::spanned::Spanned;
@@ -12,14 +14,32 @@ impl File {
 
     // Note: this isn't called automatically by `parse_str` to avoid canonicalizing when we're merely formatting IDL.
     pub fn canonicalize(&mut self) -> Result<()> {
-        // TODO maybe we rewrite the `File` here to resolve any `super` references and use declarations so that
-        // subsequently the idl-to-winmd conversion can just assume everything's fully qualified?
-        // * super can't refer to something outside of the IDL file
-        // * use declarations are only used for unqualified names that aren't defined in the IDL file
-        // * use declarations don't support globs and must name all externally defined types
-        // This way we can quickly kick out common invalid IDL files before we lost file/span context info
-
-        Ok(())
+        canonicalize::canonicalize(self)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        validate::validate(self)
+    }
+
+    /// Returns the fully-qualified name and span of every type defined in the IDL file.
+    pub fn type_names(&self) -> Vec<(String, proc_macro2::Span)> {
+        fn collect(module: &Module, namespace: &str, names: &mut Vec<(String, proc_macro2::Span)>) {
+            for member in &module.members {
+                if let ModuleMember::Module(module) = member {
+                    collect(module, &format!("{namespace}.{}", module.name), names);
+                } else {
+                    names.push((format!("{namespace}.{}", member.name()), member.span()));
+                }
+            }
+        }
+
+        let mut names = vec![];
+
+        for module in &self.modules {
+            collect(module, &module.name, &mut names);
+        }
+
+        names
     }
 
     pub fn fmt(&self) -> String {
@@ -28,6 +48,7 @@ impl File {
 
     pub fn into_winmd(mut self) -> Result<Vec<u8>> {
         self.canonicalize()?;
+        self.validate()?;
         to_winmd::idl_to_winmd(&self)
     }
 }
@@ -52,6 +73,7 @@ pub struct File {
 pub struct Module {
     pub attributes: Vec<syn::Attribute>, // winrt/win32
     pub name: String,
+    pub span: proc_macro2::Span,
     pub members: Vec<ModuleMember>,
 }
 
@@ -62,7 +84,9 @@ pub enum ModuleMember {
     Struct(Struct),
     Enum(Enum),
     Class(Class),
-    // Function and Delegate
+    Delegate(Delegate),
+    Function(Function),
+    Constant(Constant),
 }
 
 impl ModuleMember {
@@ -73,6 +97,22 @@ impl ModuleMember {
             Self::Struct(member) => &member.name,
             Self::Enum(member) => &member.name,
             Self::Class(member) => &member.name,
+            Self::Delegate(member) => &member.name,
+            Self::Function(member) => &member.name,
+            Self::Constant(member) => &member.name,
+        }
+    }
+
+    pub fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::Module(module) => module.span,
+            Self::Interface(member) => member.span,
+            Self::Struct(member) => member.span,
+            Self::Enum(member) => member.item.ident.span(),
+            Self::Class(member) => member.span,
+            Self::Delegate(member) => member.signature.ident.span(),
+            Self::Function(member) => member.signature.ident.span(),
+            Self::Constant(member) => member.item.ident.span(),
         }
     }
 }
@@ -103,6 +143,7 @@ pub struct Field {
 pub struct Class {
     pub name: String,
     pub attributes: Vec<syn::Attribute>,
+    pub span: proc_macro2::Span,
     pub extends: Vec<syn::Path>,
 }
 
@@ -110,11 +151,35 @@ pub struct Class {
 pub struct Interface {
     pub name: String,
     pub attributes: Vec<syn::Attribute>,
+    pub span: proc_macro2::Span,
+    pub generics: Vec<String>,
     pub methods: Vec<syn::TraitItemFn>,
 }
 
+#[derive(Clone)]
+pub struct Delegate {
+    pub name: String,
+    pub attributes: Vec<syn::Attribute>,
+    pub generics: Vec<String>,
+    pub signature: syn::Signature,
+}
+
+#[derive(Clone)]
+pub struct Function {
+    pub name: String,
+    pub attributes: Vec<syn::Attribute>,
+    pub signature: syn::Signature,
+}
+
+#[derive(Clone)]
+pub struct Constant {
+    pub name: String,
+    pub item: syn::ItemConst,
+}
+
 syn::custom_keyword!(interface);
 syn::custom_keyword!(class);
+syn::custom_keyword!(delegate);
 
 impl syn::parse::Parse for File {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
@@ -140,7 +205,8 @@ impl syn::parse::Parse for File {
 impl syn::parse::Parse for Module {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         input.parse::<syn::Token![mod]>()?;
-        let name = input.parse::<syn::Ident>()?.to_string();
+        let ident = input.parse::<syn::Ident>()?;
+        let name = ident.to_string();
         let content;
         syn::braced!(content in input);
         let mut members = vec![];
@@ -150,6 +216,7 @@ impl syn::parse::Parse for Module {
         Ok(Self {
             attributes: vec![],
             name,
+            span: ident.span(),
             members,
         })
     }
@@ -157,7 +224,7 @@ impl syn::parse::Parse for Module {
 
 impl syn::parse::Parse for ModuleMember {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let attributes: Vec<syn::Attribute> = input.call(syn::Attribute::parse_outer)?;
+        let attributes: Vec<syn::Attribute> = input.call(parse_attributes)?;
         let lookahead = input.lookahead1();
         if lookahead.peek(syn::Token![mod]) {
             if let Some(attribute) = attributes.first() {
@@ -177,16 +244,62 @@ impl syn::parse::Parse for ModuleMember {
             Ok(ModuleMember::Enum(Enum::parse(attributes, input)?))
         } else if lookahead.peek(class) {
             Ok(ModuleMember::Class(Class::parse(attributes, input)?))
+        } else if lookahead.peek(delegate) {
+            Ok(ModuleMember::Delegate(Delegate::parse(attributes, input)?))
+        } else if lookahead.peek(syn::Token![extern]) {
+            Ok(ModuleMember::Function(Function::parse(attributes, input)?))
+        } else if lookahead.peek(syn::Token![const]) {
+            Ok(ModuleMember::Constant(Constant::parse(attributes, input)?))
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+// Like `syn::Attribute::parse_outer` but also accepts keywords such as `static` as attribute names.
+fn parse_attributes(input: syn::parse::ParseStream) -> syn::Result<Vec<syn::Attribute>> {
+    use syn::ext::IdentExt;
+    let mut attributes = vec![];
+
+    while input.peek(syn::Token![#]) {
+        let pound_token = input.parse()?;
+        let content;
+        let bracket_token = syn::bracketed!(content in input);
+
+        let meta = if content.peek(syn::Token![static]) {
+            let path: syn::Path = syn::Ident::parse_any(&content)?.into();
+
+            if content.is_empty() {
+                syn::Meta::Path(path)
+            } else {
+                let args;
+                let paren_token = syn::parenthesized!(args in content);
+                syn::Meta::List(syn::MetaList {
+                    path,
+                    delimiter: syn::MacroDelimiter::Paren(paren_token),
+                    tokens: args.parse()?,
+                })
+            }
+        } else {
+            content.parse()?
+        };
+
+        attributes.push(syn::Attribute {
+            pound_token,
+            style: syn::AttrStyle::Outer,
+            bracket_token,
+            meta,
+        });
+    }
+
+    Ok(attributes)
+}
+
 impl Class {
     fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
         input.parse::<class>()?;
-        let name = input.parse::<syn::Ident>()?.to_string();
+        let ident = input.parse::<syn::Ident>()?;
+        let name = ident.to_string();
         let mut extends = Vec::new();
 
         if input.peek(syn::Token![:]) {
@@ -201,6 +314,7 @@ impl Class {
         Ok(Self {
             attributes,
             name,
+            span: ident.span(),
             extends,
         })
     }
@@ -209,7 +323,9 @@ impl Class {
 impl Interface {
     fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
         input.parse::<interface>()?;
-        let name = input.parse::<syn::Ident>()?.to_string();
+        let ident = input.parse::<syn::Ident>()?;
+        let name = ident.to_string();
+        let generics = generic_names(&input.parse::<syn::Generics>()?)?;
         let content;
         syn::braced!(content in input);
         let mut methods = vec![];
@@ -219,6 +335,8 @@ impl Interface {
         Ok(Self {
             attributes,
             name,
+            span: ident.span(),
+            generics,
             methods,
         })
     }
@@ -228,14 +346,14 @@ impl Struct {
     fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
         // TODO: need to validate that the struct is valid according to the constraints of the winmd type system.
         // Same for the other types. That way we can spit out errors quickly for things like unnamed fields.
-        let span = input.span();
         let item: syn::ItemStruct = input.parse()?;
+        let span = item.ident.span();
         let name = item.ident.to_string();
         let mut fields = vec![];
 
         let syn::Fields::Named(named) = item.fields else {
             return Err(syn::Error::new(
-                item.span(),
+                item.fields.span(),
                 "unnamed fields not supported",
             ));
         };
@@ -267,3 +385,64 @@ impl Enum {
         Ok(Self { name, item })
     }
 }
+
+impl Delegate {
+    fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<delegate>()?;
+        let signature: syn::Signature = input.parse()?;
+        input.parse::<syn::Token![;]>()?;
+        Ok(Self {
+            name: signature.ident.to_string(),
+            attributes,
+            generics: generic_names(&signature.generics)?,
+            signature,
+        })
+    }
+}
+
+// Generic interfaces and delegates only support type parameters without bounds, e.g. `IVector<T>`.
+fn generic_names(generics: &syn::Generics) -> syn::Result<Vec<String>> {
+    if let Some(where_clause) = &generics.where_clause {
+        return Err(syn::Error::new(
+            where_clause.span(),
+            "where clauses not supported",
+        ));
+    }
+
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(param)
+                if param.bounds.is_empty() && param.default.is_none() =>
+            {
+                Ok(param.ident.to_string())
+            }
+            rest => Err(syn::Error::new(
+                rest.span(),
+                "only unbounded type parameters are supported",
+            )),
+        })
+        .collect()
+}
+
+impl Function {
+    fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let signature: syn::Signature = input.parse()?;
+        input.parse::<syn::Token![;]>()?;
+        Ok(Self {
+            name: signature.ident.to_string(),
+            attributes,
+            signature,
+        })
+    }
+}
+
+impl Constant {
+    fn parse(attributes: Vec<syn::Attribute>, input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut item: syn::ItemConst = input.parse()?;
+        item.attrs = attributes;
+        let name = item.ident.to_string();
+        Ok(Self { name, item })
+    }
+}
@@ -60,20 +60,89 @@ impl<'a> Writer<'a> {
                     .rsplit_once('.')
                     .map_or(tree.namespace, |(_, name)| name),
             );
+            let constants = self
+                .reader
+                .namespace_constants(tree.namespace)
+                .filter_map(|field| self.constant(field));
+
+            let functions = self
+                .reader
+                .namespace_functions(tree.namespace)
+                .map(|method| self.function(method));
+
             let types = self
                 .reader
                 .namespace_types(tree.namespace, self.filter)
+                .filter(|def| !self.is_apis(*def))
                 .map(|def| self.type_def(def));
 
             quote! {
                 mod #name {
                     #(#modules)*
+                    #(#constants)*
+                    #(#functions)*
                     #(#types)*
                 }
             }
         }
     }
 
+    // The `Apis` class holds a namespace's functions and constants, which IDL writes as free-standing items.
+    fn is_apis(&self, def: metadata::TypeDef) -> bool {
+        self.reader.type_def_name(def) == "Apis"
+            && !self
+                .reader
+                .type_def_flags(def)
+                .contains(metadata::TypeAttributes::WindowsRuntime)
+    }
+
+    // Only literal constants can be written in IDL.
+    fn constant(&self, field: metadata::Field) -> Option<TokenStream> {
+        let constant = self.reader.field_constant(field)?;
+        let name = to_ident(self.reader.field_name(field));
+
+        let ty = match self.reader.field_type(field, None) {
+            metadata::Type::String => quote! { &str },
+            ty => self.ty(&ty),
+        };
+
+        let value = self.value(&self.reader.constant_value(constant));
+
+        Some(quote! {
+            const #name: #ty = #value;
+        })
+    }
+
+    fn function(&self, method: metadata::MethodDef) -> TokenStream {
+        let name = to_ident(self.reader.method_def_name(method));
+        let signature = self.reader.method_def_signature(method, &[]);
+
+        let library: TokenStream = self
+            .reader
+            .method_def_impl_map(method)
+            .map(|impl_map| {
+                let scope = self.reader.impl_map_scope(impl_map);
+                format!("\"{}\"", self.reader.module_ref_name(scope))
+            })
+            .unwrap_or_default()
+            .into();
+
+        let abi: TokenStream = format!("\"{}\"", self.reader.method_def_extern_abi(method)).into();
+
+        let params = signature.params.iter().map(|param| {
+            let name = to_ident(self.reader.param_name(param.def));
+            let ty = self.ty(&param.ty);
+            quote! { #name: #ty }
+        });
+
+        let return_type = self.return_type(&signature.return_type);
+
+        quote! {
+            #[library(#library)]
+            extern #abi fn #name(#(#params),*) #return_type;
+        }
+    }
+
     fn type_def(&self, def: metadata::TypeDef) -> TokenStream {
         if let Some(extends) = self.reader.type_def_extends(def) {
             if extends.namespace == "System" {
@@ -97,9 +166,40 @@ impl<'a> Writer<'a> {
     fn enum_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(self.reader.type_def_name(def));
 
-        quote! {
-            struct #name {
+        let is_flags = self
+            .reader
+            .type_def_attributes(def)
+            .any(|attribute| self.reader.attribute_name(attribute) == "FlagsAttribute");
 
+        let flags = if is_flags {
+            quote! { #[flags] }
+        } else {
+            quote! {}
+        };
+
+        // The underlying type is only written if it differs from the WinRT default.
+        let underlying_type = self.reader.type_def_underlying_type(def);
+
+        let repr = match (is_flags, &underlying_type) {
+            (false, metadata::Type::I32) | (true, metadata::Type::U32) => quote! {},
+            (_, ty) => {
+                let ty = self.ty(ty);
+                quote! { #[repr(#ty)] }
+            }
+        };
+
+        let variants = self.reader.type_def_fields(def).filter_map(|field| {
+            let constant = self.reader.field_constant(field)?;
+            let name = to_ident(self.reader.field_name(field));
+            let value = self.value(&self.reader.constant_value(constant));
+            Some(quote! { #name = #value })
+        });
+
+        quote! {
+            #flags
+            #repr
+            enum #name {
+                #(#variants),*
             }
         }
     }
@@ -125,19 +225,109 @@ impl<'a> Writer<'a> {
     fn delegate_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(self.reader.type_def_name(def));
 
-        quote! {
-            struct #name {
+        // Only WinRT delegates have a GUID, otherwise this is a Win32 callback using raw pointers.
+        let is_winrt = self
+            .reader
+            .type_def_flags(def)
+            .contains(metadata::TypeAttributes::WindowsRuntime);
 
-            }
+        let guid = self.reader.type_def_guid(def).map(|guid| {
+            let guid: TokenStream = format!("\"{guid:?}\"").into();
+            quote! { #[guid(#guid)] }
+        });
+
+        let generics: Vec<metadata::Type> = self.reader.type_def_generics(def).collect();
+        let generic_names = self.generic_names(&generics);
+        let method = self.reader.type_def_invoke_method(def);
+        let signature = self.reader.method_def_signature(method, &generics);
+
+        let params = signature.params.iter().map(|param| {
+            let name = to_ident(self.reader.param_name(param.def));
+            let ty = if is_winrt {
+                self.param_type(param)
+            } else {
+                self.ty(&param.ty)
+            };
+            quote! { #name: #ty }
+        });
+
+        let return_type = self.return_type(&signature.return_type);
+
+        quote! {
+            #guid
+            delegate fn #name #generic_names(#(#params),*) #return_type;
         }
     }
 
     fn class_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(self.reader.type_def_name(def));
 
-        quote! {
-            struct #name {
+        let attributes = self
+            .reader
+            .type_def_attributes(def)
+            .filter_map(|attribute| {
+                let args = self.reader.attribute_args(attribute);
+
+                let factory = args.iter().find_map(|(_, value)| match value {
+                    metadata::Value::TypeDef(def) => {
+                        Some(self.ty(&metadata::Type::TypeDef(*def, vec![])))
+                    }
+                    _ => None,
+                });
+
+                match (self.reader.attribute_name(attribute), factory) {
+                    ("ActivatableAttribute", None) => Some(quote! { #[activatable] }),
+                    ("ActivatableAttribute", Some(factory)) => {
+                        Some(quote! { #[activatable(#factory)] })
+                    }
+                    ("StaticAttribute", Some(statics)) => Some(quote! { #[static(#statics)] }),
+                    ("ComposableAttribute", Some(factory)) => {
+                        // See `Windows.Foundation.Metadata.CompositionType` for these values.
+                        let is_protected = args.iter().any(|(_, value)| match value {
+                            metadata::Value::EnumDef(_, value)
+                            | metadata::Value::EnumRef(_, value) => {
+                                matches!(**value, metadata::Value::I32(1))
+                            }
+                            _ => false,
+                        });
+
+                        if is_protected {
+                            Some(quote! { #[composable(#factory, protected)] })
+                        } else {
+                            Some(quote! { #[composable(#factory)] })
+                        }
+                    }
+                    _ => None,
+                }
+            });
+
+        let base = self
+            .reader
+            .type_def_extends(def)
+            .filter(|base| *base != metadata::TypeName::Object)
+            .map(|base| {
+                let namespace = self.namespace(base.namespace);
+                let name = to_ident(base.name);
+                quote! { #[base(#namespace#name)] }
+            });
+
+        let mut extends = vec![];
+
+        for interface in self.reader.type_def_interfaces(def, &[]) {
+            extends.push(self.ty(&interface.ty));
+        }
 
+        if extends.is_empty() {
+            quote! {
+                #base
+                #(#attributes)*
+                class #name;
+            }
+        } else {
+            quote! {
+                #base
+                #(#attributes)*
+                class #name : #(#extends),*;
             }
         }
     }
@@ -145,10 +335,134 @@ impl<'a> Writer<'a> {
     fn interface_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(self.reader.type_def_name(def));
 
+        let guid = self.reader.type_def_guid(def).map(|guid| {
+            let guid: TokenStream = format!("\"{guid:?}\"").into();
+            quote! { #[guid(#guid)] }
+        });
+
+        let generics: Vec<metadata::Type> = self.reader.type_def_generics(def).collect();
+        let generic_names = self.generic_names(&generics);
+
+        let event_methods: Vec<metadata::MethodDef> = self
+            .reader
+            .type_def_events(def)
+            .flat_map(|event| {
+                [
+                    self.reader.event_add(event),
+                    self.reader.event_remove(event),
+                ]
+            })
+            .flatten()
+            .collect();
+
+        let methods = self.reader.type_def_methods(def).map(|method| {
+            let name = to_ident(self.reader.method_def_name(method));
+            let signature = self.reader.method_def_signature(method, &generics);
+
+            let params = signature.params.iter().map(|param| {
+                let name = to_ident(self.reader.param_name(param.def));
+                let ty = self.param_type(param);
+                quote! { #name: #ty }
+            });
+
+            let return_type = self.return_type(&signature.return_type);
+
+            let overload = self
+                .reader
+                .method_def_attributes(method)
+                .filter(|attribute| self.reader.attribute_name(*attribute) == "OverloadAttribute")
+                .find_map(
+                    |attribute| match self.reader.attribute_args(attribute).pop() {
+                        Some((_, metadata::Value::String(name))) => {
+                            let name: TokenStream = format!("{name:?}").into();
+                            Some(quote! { #[overload(#name)] })
+                        }
+                        _ => None,
+                    },
+                );
+
+            let event = if event_methods.contains(&method) {
+                quote! { #[event] }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                #overload
+                #event
+                fn #name(#(#params),*) #return_type;
+            }
+        });
+
         quote! {
-            struct #name {
+            #guid
+            interface #name #generic_names {
+                #(#methods)*
+            }
+        }
+    }
 
+    fn generic_names(&self, generics: &[metadata::Type]) -> TokenStream {
+        if generics.is_empty() {
+            quote! {}
+        } else {
+            let generics = generics.iter().map(|ty| self.ty(ty));
+            quote! { <#(#generics),*> }
+        }
+    }
+
+    fn return_type(&self, ty: &metadata::Type) -> TokenStream {
+        if *ty == metadata::Type::Void {
+            quote! {}
+        } else {
+            let ty = self.ty(ty);
+            quote! { -> #ty }
+        }
+    }
+
+    fn value(&self, value: &metadata::Value) -> TokenStream {
+        match value {
+            metadata::Value::I8(value) => format!("{value}").into(),
+            metadata::Value::U8(value) => format!("{value}").into(),
+            metadata::Value::I16(value) => format!("{value}").into(),
+            metadata::Value::U16(value) => format!("{value}").into(),
+            metadata::Value::I32(value) => format!("{value}").into(),
+            metadata::Value::U32(value) => format!("{value}").into(),
+            metadata::Value::I64(value) => format!("{value}").into(),
+            metadata::Value::U64(value) => format!("{value}").into(),
+            // Debug formatting keeps the decimal point so the literal is still a float.
+            metadata::Value::F32(value) => format!("{value:?}").into(),
+            metadata::Value::F64(value) => format!("{value:?}").into(),
+            metadata::Value::Bool(value) => format!("{value}").into(),
+            metadata::Value::String(value) => format!("{value:?}").into(),
+            rest => unimplemented!("{rest:?}"),
+        }
+    }
+
+    fn param_type(&self, param: &metadata::SignatureParam) -> TokenStream {
+        let is_output = self
+            .reader
+            .param_flags(param.def)
+            .contains(metadata::ParamAttributes::Out);
+
+        match &param.ty {
+            metadata::Type::WinrtArray(ty) => {
+                let ty = self.ty(ty);
+                if is_output {
+                    quote! { &mut [#ty] }
+                } else {
+                    quote! { &[#ty] }
+                }
             }
+            metadata::Type::ConstRef(ty) => {
+                let ty = self.ty(ty);
+                quote! { &#ty }
+            }
+            ty if is_output => {
+                let ty = self.ty(ty);
+                quote! { &mut #ty }
+            }
+            ty => self.ty(ty),
         }
     }
 
@@ -169,6 +483,31 @@ impl<'a> Writer<'a> {
             metadata::Type::F64 => quote! { f64 },
             metadata::Type::ISize => quote! { isize },
             metadata::Type::USize => quote! { usize },
+            metadata::Type::String => quote! { HSTRING },
+            metadata::Type::GUID => quote! { GUID },
+            metadata::Type::IInspectable => quote! { IInspectable },
+            metadata::Type::HRESULT => quote! { HRESULT },
+            metadata::Type::IUnknown => quote! { IUnknown },
+            metadata::Type::PSTR => quote! { PSTR },
+            metadata::Type::PWSTR => quote! { PWSTR },
+            metadata::Type::PCSTR => quote! { PCSTR },
+            metadata::Type::PCWSTR => quote! { PCWSTR },
+            metadata::Type::BSTR => quote! { BSTR },
+            metadata::Type::MutPtr(ty, pointers) => {
+                let pointers = (0..*pointers).map(|_| quote! { *mut });
+                let ty = self.ty(ty);
+                quote! { #(#pointers)* #ty }
+            }
+            metadata::Type::ConstPtr(ty, pointers) => {
+                let pointers = (0..*pointers).map(|_| quote! { *const });
+                let ty = self.ty(ty);
+                quote! { #(#pointers)* #ty }
+            }
+            metadata::Type::Win32Array(ty, len) => {
+                let ty = self.ty(ty);
+                let len: TokenStream = format!("{len}").into();
+                quote! { [#ty; #len] }
+            }
             metadata::Type::TypeDef(def, generics) => {
                 let namespace = self.namespace(self.reader.type_def_namespace(*def));
                 let name = to_ident(self.reader.type_def_name(*def));
@@ -179,6 +518,7 @@ impl<'a> Writer<'a> {
                     quote! { #namespace#name<#(#generics,)*> }
                 }
             }
+            metadata::Type::GenericParam(param) => to_ident(self.reader.generic_param_name(*param)),
             rest => unimplemented!("{rest:?}"),
         }
     }
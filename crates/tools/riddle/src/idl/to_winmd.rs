@@ -1,23 +1,15 @@
-use crate::{idl, winmd, Result};
+use crate::{idl, winmd, Error, Result};
 
 // TODO: store span in winmd so that errors resolving type references can be traced back to file/line/column
 use std::collections::HashMap;
-//use syn::spanned::Spanned;
+use std::convert::TryFrom;
+use syn::spanned::Spanned;
 
 // TODO: this creates a temporary in-memory winmd used to treat the IDL content uniformly as metadata.
 // The winmd_to_winmd does the harder job of validating and producing canonical winmd for public consumption.
 
 pub fn idl_to_winmd(file: &idl::File) -> Result<Vec<u8>> {
-    // Local-to-qualified type names found in use declaration - e.g. "IStringable" -> "Windows.Foundation.IStringable"
-    // This is just a convenience for the developer to shorten common references like this but would not support globs or renames.
-    // Note that none of these are verified to be real until much later when the winmd is validated since we don't
-    // know what other metadata may be combined
-    let mut _use_map = HashMap::<String, String>::new();
-
-    // TODO: read file and populate use_map
-
-    // Types are collected here in two passes - this allows us to figure out whether a local name points to a relative type
-    // or a type from a use declaration...?
+    // Types are collected by namespace so that each namespace's functions and constants can be gathered together.
     let mut collector = HashMap::<String, HashMap<&str, idl::ModuleMember>>::new();
 
     file.modules
@@ -30,13 +22,22 @@ pub fn idl_to_winmd(file: &idl::File) -> Result<Vec<u8>> {
     // this second walking of the collector is basically the "define" phase
 
     let mut writer = winmd::Writer::new("temp.winmd");
+    let mut errors = vec![];
 
-    collector.iter().for_each(|(namespace, members)| {
-        members
-            .iter()
-            .for_each(|(name, member)| write_member(&mut writer, namespace, name, member))
-    });
+    // Keep going after an error so that as many errors as possible are reported at once.
+    for (namespace, members) in &collector {
+        for (name, member) in members {
+            if let Err(error) = write_member(&mut writer, namespace, name, member) {
+                errors.push(error);
+            }
+        }
 
+        if let Err(error) = write_apis(&mut writer, namespace, members) {
+            errors.push(error);
+        }
+    }
+
+    Error::check(errors)?;
     Ok(writer.into_stream())
 }
 
@@ -75,38 +76,424 @@ fn write_member(
     namespace: &str,
     name: &str,
     member: &idl::ModuleMember,
-) {
+) -> Result<()> {
     match member {
-        idl::ModuleMember::Interface(member) => write_interface(writer, namespace, name, member),
-        idl::ModuleMember::Struct(member) => write_struct(writer, namespace, name, member),
-        idl::ModuleMember::Enum(member) => write_enum(writer, namespace, name, member),
-        idl::ModuleMember::Class(member) => write_class(writer, namespace, name, member),
+        idl::ModuleMember::Interface(member) => write_interface(writer, namespace, name, member)?,
+        idl::ModuleMember::Struct(member) => write_struct(writer, namespace, name, member)?,
+        idl::ModuleMember::Enum(member) => write_enum(writer, namespace, name, member)?,
+        idl::ModuleMember::Class(member) => write_class(writer, namespace, name, member)?,
+        idl::ModuleMember::Delegate(member) => write_delegate(writer, namespace, name, member)?,
+        // Functions and constants are gathered into the namespace's `Apis` class by `write_apis`.
+        idl::ModuleMember::Function(_) | idl::ModuleMember::Constant(_) => {}
         idl::ModuleMember::Module(_) => {} // modules have already been flattened but rustc doesn't know this
     }
+    Ok(())
 }
 
 fn write_interface(
     writer: &mut winmd::Writer,
     namespace: &str,
     name: &str,
-    _member: &idl::Interface,
-) {
+    member: &idl::Interface,
+) -> Result<()> {
     let flags = metadata::TypeAttributes::Public
         | metadata::TypeAttributes::Interface
         | metadata::TypeAttributes::WindowsRuntime
         | metadata::TypeAttributes::Abstract;
 
+    let def = writer.tables.TypeDef.len() as u32;
+
     writer.tables.TypeDef.push(winmd::TypeDef {
         Extends: 0,
-        FieldList: 0,
+        FieldList: writer.tables.Field.len() as _,
         Flags: flags.0,
         MethodList: writer.tables.MethodDef.len() as _,
-        TypeName: writer.strings.insert(name),
+        TypeName: writer
+            .strings
+            .insert(&generic_type_name(name, &member.generics)),
+        TypeNamespace: writer.strings.insert(namespace),
+    });
+
+    write_generics(writer, def, &member.generics);
+
+    for attribute in &member.attributes {
+        if attribute.path().is_ident("guid") {
+            let guid = syn_guid(attribute)?;
+            writer.insert_attribute(
+                winmd::HasAttribute::TypeDef(def),
+                "Windows.Foundation.Metadata",
+                "GuidAttribute",
+                &guid,
+            );
+        }
+    }
+
+    // Event methods are named `add_{event}` and `remove_{event}` as validated by `validate_interface`.
+    let mut adders = vec![];
+    let mut removers = HashMap::new();
+
+    for method in &member.methods {
+        let name = method.sig.ident.to_string();
+
+        let mut flags = metadata::MethodAttributes::Public
+            | metadata::MethodAttributes::Virtual
+            | metadata::MethodAttributes::HideBySig
+            | metadata::MethodAttributes::Abstract
+            | metadata::MethodAttributes::NewSlot;
+
+        let is_event = method
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path().is_ident("event"));
+
+        if is_event {
+            flags |= metadata::MethodAttributes::SpecialName;
+        }
+
+        let return_type = syn_return_type(namespace, &member.generics, &method.sig.output);
+        let params = syn_params(namespace, &member.generics, &method.sig, true)?;
+
+        let row = write_method(
+            writer,
+            &name,
+            flags,
+            metadata::MethodImplAttributes::default(),
+            metadata::MethodCallAttributes::HASTHIS,
+            &return_type,
+            &params,
+        );
+
+        for attribute in &method.attrs {
+            if attribute.path().is_ident("overload") {
+                let name: syn::LitStr = attribute.parse_args()?;
+                writer.insert_attribute(
+                    winmd::HasAttribute::MethodDef(row),
+                    "Windows.Foundation.Metadata",
+                    "OverloadAttribute",
+                    &[winmd::Value::String(name.value())],
+                );
+            }
+        }
+
+        if is_event {
+            if let Some(event) = name.strip_prefix("add_") {
+                adders.push((event.to_string(), row, params[0].2.clone()));
+            } else if let Some(event) = name.strip_prefix("remove_") {
+                removers.insert(event.to_string(), row);
+            }
+        }
+    }
+
+    if !adders.is_empty() {
+        writer.tables.EventMap.push(winmd::EventMap {
+            Parent: def,
+            EventList: writer.tables.Event.len() as _,
+        });
+    }
+
+    for (name, adder, ty) in adders {
+        let event_type = writer.insert_type_def_or_ref(&ty);
+        let event = writer.tables.Event.len() as u32;
+
+        writer.tables.Event.push(winmd::Event {
+            EventFlags: 0,
+            Name: writer.strings.insert(&name),
+            EventType: event_type,
+        });
+
+        for (semantics, method) in [
+            (metadata::MethodSemanticsAttributes::AddOn, adder),
+            (
+                metadata::MethodSemanticsAttributes::RemoveOn,
+                removers[&name],
+            ),
+        ] {
+            writer.tables.MethodSemantics.push(winmd::MethodSemantics {
+                Semantics: semantics.0,
+                Method: method,
+                Association: winmd::HasSemantics::Event(event).encode(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn write_delegate(
+    writer: &mut winmd::Writer,
+    namespace: &str,
+    name: &str,
+    member: &idl::Delegate,
+) -> Result<()> {
+    let mut guid = None;
+
+    for attribute in &member.attributes {
+        if attribute.path().is_ident("guid") {
+            guid = Some(syn_guid(attribute)?);
+        }
+    }
+
+    // Only WinRT delegates have a GUID, otherwise this is a Win32 callback.
+    let is_winrt = guid.is_some();
+
+    let mut flags = metadata::TypeAttributes::Public | metadata::TypeAttributes::Sealed;
+
+    if is_winrt {
+        flags |= metadata::TypeAttributes::WindowsRuntime;
+    }
+
+    let extends = writer.insert_type_ref("System", "MulticastDelegate");
+    let def = writer.tables.TypeDef.len() as u32;
+
+    writer.tables.TypeDef.push(winmd::TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as _,
+        Flags: flags.0,
+        MethodList: writer.tables.MethodDef.len() as _,
+        TypeName: writer
+            .strings
+            .insert(&generic_type_name(name, &member.generics)),
         TypeNamespace: writer.strings.insert(namespace),
     });
+
+    write_generics(writer, def, &member.generics);
+
+    if let Some(guid) = guid {
+        writer.insert_attribute(
+            winmd::HasAttribute::TypeDef(def),
+            "Windows.Foundation.Metadata",
+            "GuidAttribute",
+            &guid,
+        );
+    }
+
+    write_method(
+        writer,
+        ".ctor",
+        metadata::MethodAttributes::Private
+            | metadata::MethodAttributes::HideBySig
+            | metadata::MethodAttributes::SpecialName
+            | metadata::MethodAttributes::RTSpecialName,
+        metadata::MethodImplAttributes::Runtime,
+        metadata::MethodCallAttributes::HASTHIS,
+        &winmd::Type::Void,
+        &[
+            (
+                "object".to_string(),
+                metadata::ParamAttributes::In,
+                winmd::Type::IInspectable,
+            ),
+            (
+                "method".to_string(),
+                metadata::ParamAttributes::In,
+                winmd::Type::ISize,
+            ),
+        ],
+    );
+
+    let return_type = syn_return_type(namespace, &member.generics, &member.signature.output);
+    let params = syn_params(namespace, &member.generics, &member.signature, is_winrt)?;
+
+    write_method(
+        writer,
+        "Invoke",
+        metadata::MethodAttributes::Public
+            | metadata::MethodAttributes::Virtual
+            | metadata::MethodAttributes::HideBySig
+            | metadata::MethodAttributes::NewSlot,
+        metadata::MethodImplAttributes::Runtime,
+        metadata::MethodCallAttributes::HASTHIS,
+        &return_type,
+        &params,
+    );
+
+    Ok(())
 }
 
-fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member: &idl::Struct) {
+// Generic types are named by their arity in metadata, e.g. `IVector<T>` is named "IVector`1".
+fn generic_type_name(name: &str, generics: &[String]) -> String {
+    if generics.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}`{}", generics.len())
+    }
+}
+
+fn write_generics(writer: &mut winmd::Writer, def: u32, generics: &[String]) {
+    for (number, name) in generics.iter().enumerate() {
+        writer.tables.GenericParam.push(winmd::GenericParam {
+            Number: number as u16,
+            Flags: 0,
+            Owner: winmd::TypeOrMethodDef::TypeDef(def).encode(),
+            Name: writer.strings.insert(name),
+        });
+    }
+}
+
+// Functions and constants live in a per-namespace `Apis` class, as they do in the Win32 metadata.
+fn write_apis(
+    writer: &mut winmd::Writer,
+    namespace: &str,
+    members: &HashMap<&str, idl::ModuleMember>,
+) -> Result<()> {
+    let mut constants = vec![];
+    let mut functions = vec![];
+
+    for member in members.values() {
+        match member {
+            idl::ModuleMember::Constant(member) => constants.push(member),
+            idl::ModuleMember::Function(member) => functions.push(member),
+            _ => {}
+        }
+    }
+
+    if constants.is_empty() && functions.is_empty() {
+        return Ok(());
+    }
+
+    constants.sort_by(|a, b| a.name.cmp(&b.name));
+    functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let flags = metadata::TypeAttributes::Public
+        | metadata::TypeAttributes::Abstract
+        | metadata::TypeAttributes::Sealed;
+
+    let extends = writer.insert_type_ref("System", "Object");
+
+    writer.tables.TypeDef.push(winmd::TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as _,
+        Flags: flags.0,
+        MethodList: writer.tables.MethodDef.len() as _,
+        TypeName: writer.strings.insert("Apis"),
+        TypeNamespace: writer.strings.insert(namespace),
+    });
+
+    let flags = metadata::FieldAttributes::Public
+        | metadata::FieldAttributes::Static
+        | metadata::FieldAttributes::Literal
+        | metadata::FieldAttributes::HasDefault;
+
+    for constant in constants {
+        let ty = syn_constant_type(namespace, &constant.item.ty);
+        let value = syn_constant_value(&ty, &constant.item.expr)?;
+        let signature = writer.insert_field_sig(&ty);
+        let field = writer.tables.Field.len() as u32;
+
+        writer.tables.Field.push(winmd::Field {
+            Flags: flags.0,
+            Name: writer.strings.insert(&constant.name),
+            Signature: signature,
+        });
+
+        let blob = writer.insert_value_blob(&value);
+
+        writer.tables.Constant.push(winmd::Constant {
+            Type: value.to_code(),
+            Parent: winmd::HasConstant::Field(field).encode(),
+            Value: blob,
+        });
+    }
+
+    for function in functions {
+        let mut library = None;
+
+        for attribute in &function.attributes {
+            if attribute.path().is_ident("library") {
+                let lit: syn::LitStr = attribute.parse_args()?;
+                library = Some(lit.value());
+            }
+        }
+
+        let Some(library) = library else {
+            return Err(Error::new("expected `#[library]` attribute")
+                .with_span(function.signature.ident.span()));
+        };
+
+        let abi = function
+            .signature
+            .abi
+            .as_ref()
+            .and_then(|abi| abi.name.as_ref());
+
+        let call_conv = match abi.map(|abi| abi.value()).as_deref() {
+            Some("system") => metadata::PInvokeAttributes::CallConvPlatformapi,
+            Some("C") | Some("cdecl") => metadata::PInvokeAttributes::CallConvCdecl,
+            _ => {
+                return Err(Error::new("expected `extern \"system\"` or `extern \"C\"`")
+                    .with_span(function.signature.span()))
+            }
+        };
+
+        let return_type = syn_return_type(namespace, &[], &function.signature.output);
+        let params = syn_params(namespace, &[], &function.signature, false)?;
+
+        let method = write_method(
+            writer,
+            &function.name,
+            metadata::MethodAttributes::Public
+                | metadata::MethodAttributes::Static
+                | metadata::MethodAttributes::HideBySig
+                | metadata::MethodAttributes::PinvokeImpl,
+            metadata::MethodImplAttributes::PreserveSig,
+            metadata::MethodCallAttributes::default(),
+            &return_type,
+            &params,
+        );
+
+        let import_scope = writer.insert_module_ref(&library);
+
+        writer.tables.ImplMap.push(winmd::ImplMap {
+            MappingFlags: (metadata::PInvokeAttributes::NoMangle | call_conv).0 as _,
+            MemberForwarded: winmd::MemberForwarded::MethodDef(method).encode(),
+            ImportName: writer.strings.insert(&function.name),
+            ImportScope: import_scope,
+        });
+    }
+
+    Ok(())
+}
+
+// Writes a `MethodDef` and its `Param` rows, returning the `MethodDef` row.
+fn write_method(
+    writer: &mut winmd::Writer,
+    name: &str,
+    flags: metadata::MethodAttributes,
+    impl_flags: metadata::MethodImplAttributes,
+    call_flags: metadata::MethodCallAttributes,
+    return_type: &winmd::Type,
+    params: &[(String, metadata::ParamAttributes, winmd::Type)],
+) -> u32 {
+    let types: Vec<winmd::Type> = params.iter().map(|(_, _, ty)| ty.clone()).collect();
+    let signature = writer.insert_method_sig(call_flags, return_type, &types);
+    let method = writer.tables.MethodDef.len() as u32;
+
+    writer.tables.MethodDef.push(winmd::MethodDef {
+        RVA: 0,
+        ImplFlags: impl_flags.0 as _,
+        Flags: flags.0,
+        Name: writer.strings.insert(name),
+        Signature: signature,
+        ParamList: writer.tables.Param.len() as _,
+    });
+
+    for (sequence, (name, flags, _)) in params.iter().enumerate() {
+        writer.tables.Param.push(winmd::Param {
+            Flags: flags.0,
+            Sequence: (sequence + 1) as _,
+            Name: writer.strings.insert(name),
+        });
+    }
+
+    method
+}
+
+fn write_struct(
+    writer: &mut winmd::Writer,
+    namespace: &str,
+    name: &str,
+    member: &idl::Struct,
+) -> Result<()> {
     let flags = metadata::TypeAttributes::Public
         | metadata::TypeAttributes::WindowsRuntime
         | metadata::TypeAttributes::Sealed
@@ -119,13 +506,22 @@ fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member:
         Extends: extends,
         FieldList: writer.tables.Field.len() as _,
         Flags: flags.0,
-        MethodList: 0,
+        MethodList: writer.tables.MethodDef.len() as _,
         TypeName: writer.strings.insert(name),
         TypeNamespace: writer.strings.insert(namespace),
     });
 
+    let mut names = std::collections::HashSet::new();
+
     for field in &member.fields {
-        let ty = syn_type(namespace, &field.ty);
+        if !names.insert(&field.name) {
+            return Err(
+                Error::new(&format!("field `{}` is already declared", field.name))
+                    .with_span(field.span),
+            );
+        }
+
+        let ty = syn_type(namespace, &[], &field.ty);
         let signature = writer.insert_field_sig(&ty);
 
         writer.tables.Field.push(winmd::Field {
@@ -134,180 +530,525 @@ fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member:
             Signature: signature,
         });
     }
+
+    Ok(())
 }
 
-fn write_enum(_writer: &mut winmd::Writer, _namespace: &str, _name: &str, _member: &idl::Enum) {}
-
-fn write_class(_writer: &mut winmd::Writer, _namespace: &str, _name: &str, _member: &idl::Class) {}
-
-// fn idl_interface(writer:  &mut winmd::Writer, _file: &idl::File, ty: &idl::Interface, namespace: &str, phase: ReadPhase) -> Result<()> {
-//     let ident = ty.ident.to_string();
-
-//         match phase {
-//             ReadPhase::Index => {
-//                 self.insert(namespace, 0).types.entry(ident).or_default();
-//             }
-//             ReadPhase::Define => {
-//                 let flags = TypeAttributes::Public | TypeAttributes::Interface | TypeAttributes::WindowsRuntime | TypeAttributes::Abstract;
-//                 let mut def = TypeDef { flags, extends: None, ..Default::default() };
-
-//                 for method in &ty.methods {
-//                     let name = method.sig.ident.to_string();
-//                     let mut params = vec![];
-
-//                     for input in &method.sig.inputs {
-//                         let syn::FnArg::Typed(pat_type) = input else {
-//                     unimplemented!();
-//                 };
-
-//                         let syn::Pat::Ident(ref pat_ident) = *pat_type.pat else {
-//                     unimplemented!();
-//                 };
-
-//                         let name = pat_ident.ident.to_string();
-//                         let ty = self.read_ty(namespace, &pat_type.ty)?;
-//                         params.push(Param { name, ty, ..Default::default() });
-//                     }
-
-//                     let ty = if let syn::ReturnType::Type(_, ty) = &method.sig.output { self.read_ty(namespace, ty)? } else { Type::Void };
-//                     let return_type = Param { ty, ..Default::default() };
-//                     let flags = MethodAttributes::Public;
-
-//                     def.methods.push(Method { flags, name, params, return_type, ..Default::default() });
-//                 }
-
-//                 self.insert(namespace, 0).types.entry(ident).or_default().push(def);
-//             }
-//         }
-
-//     Ok(())
-// }
-
-// fn idl_struct(writer:  &mut winmd::Writer, _file: &idl::File, ty: &idl::Struct, namespace: &str, phase: ReadPhase) -> Result<()> {
-//     let ident = ty.item.ident.to_string();
-
-//         match phase {
-//             ReadPhase::Index => {
-//                 self.insert(namespace, 0).types.entry(ident).or_default();
-//             }
-//             ReadPhase::Define => {
-//                 let flags = TypeAttributes::Public | TypeAttributes::WindowsRuntime | TypeAttributes::Sealed | TypeAttributes::Import | TypeAttributes::SequentialLayout;
-//                 let mut def = TypeDef { flags, extends: Some(TypeRef { namespace: "System".to_string(), name: "ValueType".to_string(), ..Default::default() }), ..Default::default() };
-
-//                 let syn::Fields::Named(fields) = &ty.item.fields else {
-//                     unimplemented!();
-//                 };
-
-//                 for field in &fields.named {
-//                     let Some(ref ident) = field.ident else {
-//                        unimplemented!();
-//                     };
-
-//                     let flags = FieldAttributes::Public;
-//                     let name = ident.to_string();
-//                     let ty = self.read_ty(namespace, &field.ty)?;
-//                     def.fields.push(Field { flags, name, ty, ..Default::default() });
-//                 }
-
-//                 self.insert(namespace, 0).types.entry(ident).or_default().push(def);
-//             }
-//         }
-
-//     Ok(())
-// }
-
-// fn idl_enum(writer:  &mut winmd::Writer, _file: &idl::File, ty: &idl::Enum, namespace: &str, phase: ReadPhase) -> Result<()> {
-//     let ident = ty.item.ident.to_string();
-
-//         match phase {
-//             ReadPhase::Index => {
-//                 self.insert(namespace, 0).types.entry(ident).or_default();
-//             }
-//             ReadPhase::Define => {
-//                 let mut def = TypeDef { extends: Some(TypeRef { namespace: "System".to_string(), name: "Enum".to_string(), ..Default::default() }), ..Default::default() };
-//                 let enum_type = Type::TypeRef(TypeRef { namespace: namespace.to_string(), name: ident.clone(), ..Default::default() });
-
-//                 for variant in &ty.item.variants {
-//                     if let Some((_, expr)) = &variant.discriminant {
-//                         let flags = FieldAttributes::Public;
-//                         let name = variant.ident.to_string();
-//                         let value = self.read_expr(expr, false)?;
-
-//                         def.fields.push(Field { flags, name, ty: enum_type.clone(), value: Some(value) });
-//                     }
-//                 }
-
-//                 self.insert(namespace, 0).types.entry(ident).or_default().push(def);
-//             }
-//         }
-
-//     Ok(())
-// }
-
-// fn idl_class(writer:  &mut winmd::Writer, _file: &idl::File, ty: &idl::Class, namespace: &str, _phase: ReadPhase) -> Result<()> {
-//     let ident = ty.ident.to_string();
-//     self.insert(namespace, 0).types.entry(ident).or_default();
-//     Ok(())
-// }
-
-// fn syn_expr(writer:  &mut winmd::Writer, expr: &syn::Expr, neg: bool) -> Result<Value> {
-//     match expr {
-//         syn::Expr::Lit(lit) => self.read_expr_lit(lit, neg),
-//         syn::Expr::Unary(unary) => self.read_expr_unary(unary),
-//         rest => unimplemented!("{rest:?}"),
-//     }
-// }
-
-// fn syn_expr_unary(writer:  &mut winmd::Writer, unary: &syn::ExprUnary) -> Result<Value> {
-//     self.read_expr(&unary.expr, true)
-// }
-
-// fn syn_expr_lit(writer:  &mut winmd::Writer, expr: &syn::ExprLit, neg: bool) -> Result<Value> {
-//     self.read_lit(&expr.lit, neg)
-// }
-
-// fn syn_lit(writer:  &mut winmd::Writer, lit: &syn::Lit, neg: bool) -> Result<Value> {
-//     match lit {
-//         syn::Lit::Int(lit) => self.read_lit_int(lit, neg),
-//         syn::Lit::Str(lit) => self.read_lit_str(lit),
-//         rest => unimplemented!("{rest:?}"),
-//     }
-// }
-
-// fn syn_lit_str(writer:  &mut winmd::Writer, lit: &syn::LitStr) -> Result<Value> {
-//     Ok(Value::String(lit.value()))
-// }
-
-// fn syn_lit_int(writer:  &mut winmd::Writer, lit: &syn::LitInt, neg: bool) -> Result<Value> {
-//     fn parse<T: std::str::FromStr>(lit: &syn::LitInt, neg: bool) -> Result<T> {
-//         let raw = if neg { format!("-{}", lit.base10_digits()) } else { lit.base10_digits().to_string() };
-//         raw.parse().map_err(|_| Error::new("failed to parse literal").with_span(lit.span()))
-//     }
-
-//     match lit.suffix() {
-//         "i8" => Ok(Value::I8(parse(lit, neg)?)),
-//         "u8" => Ok(Value::U8(parse(lit, neg)?)),
-//         "i16" => Ok(Value::I16(parse(lit, neg)?)),
-//         "u16" => Ok(Value::U16(parse(lit, neg)?)),
-//         "i32" => Ok(Value::I32(parse(lit, neg)?)),
-//         "u32" => Ok(Value::U32(parse(lit, neg)?)),
-//         "i64" => Ok(Value::I64(parse(lit, neg)?)),
-//         "u64" => Ok(Value::U64(parse(lit, neg)?)),
-//         suffix => unimplemented!("suffix {:?}", suffix),
-//     }
-// }
-
-fn syn_type(namespace: &str, ty: &syn::Type) -> winmd::Type {
+fn write_enum(
+    writer: &mut winmd::Writer,
+    namespace: &str,
+    name: &str,
+    member: &idl::Enum,
+) -> Result<()> {
+    let flags = metadata::TypeAttributes::Public
+        | metadata::TypeAttributes::WindowsRuntime
+        | metadata::TypeAttributes::Sealed;
+
+    let mut is_flags = false;
+    let mut underlying_type = None;
+
+    for attribute in &member.item.attrs {
+        if attribute.path().is_ident("flags") {
+            is_flags = true;
+        } else if attribute.path().is_ident("repr") {
+            underlying_type = Some(syn_enum_repr(attribute)?);
+        }
+    }
+
+    // WinRT enums are signed unless they are flags.
+    let underlying_type = underlying_type.unwrap_or(if is_flags {
+        winmd::Type::U32
+    } else {
+        winmd::Type::I32
+    });
+
+    let extends = writer.insert_type_ref("System", "Enum");
+    let def = writer.tables.TypeDef.len() as u32;
+
+    writer.tables.TypeDef.push(winmd::TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as _,
+        Flags: flags.0,
+        MethodList: writer.tables.MethodDef.len() as _,
+        TypeName: writer.strings.insert(name),
+        TypeNamespace: writer.strings.insert(namespace),
+    });
+
+    if is_flags {
+        writer.insert_attribute(
+            winmd::HasAttribute::TypeDef(def),
+            "System",
+            "FlagsAttribute",
+            &[],
+        );
+    }
+
+    let flags = metadata::FieldAttributes::Public
+        | metadata::FieldAttributes::SpecialName
+        | metadata::FieldAttributes::RTSpecialName;
+
+    let signature = writer.insert_field_sig(&underlying_type);
+
+    writer.tables.Field.push(winmd::Field {
+        Flags: flags.0,
+        Name: writer.strings.insert("value__"),
+        Signature: signature,
+    });
+
+    let flags = metadata::FieldAttributes::Public
+        | metadata::FieldAttributes::Static
+        | metadata::FieldAttributes::Literal
+        | metadata::FieldAttributes::HasDefault;
+
+    let enum_type = winmd::Type::TypeRef(winmd::TypeName {
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+        generics: vec![],
+    });
+
+    let signature = writer.insert_field_sig(&enum_type);
+
+    // Variants without an explicit discriminant follow on from the previous variant, as in Rust.
+    let mut discriminant = 0;
+
+    let mut names = std::collections::HashSet::new();
+
+    for variant in &member.item.variants {
+        if !names.insert(&variant.ident) {
+            return Err(
+                Error::new(&format!("variant `{}` is already declared", variant.ident))
+                    .with_span(variant.ident.span()),
+            );
+        }
+
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(Error::new("enum variants cannot have fields").with_span(variant.span()));
+        }
+
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = syn_integer(expr)?;
+        }
+
+        let Some(value) = integer_value(&underlying_type, discriminant) else {
+            return Err(Error::new("enum discriminant out of range").with_span(variant.span()));
+        };
+
+        let field = writer.tables.Field.len() as u32;
+
+        writer.tables.Field.push(winmd::Field {
+            Flags: flags.0,
+            Name: writer.strings.insert(&variant.ident.to_string()),
+            Signature: signature,
+        });
+
+        let blob = writer.insert_value_blob(&value);
+
+        writer.tables.Constant.push(winmd::Constant {
+            Type: value.to_code(),
+            Parent: winmd::HasConstant::Field(field).encode(),
+            Value: blob,
+        });
+
+        discriminant += 1;
+    }
+
+    Ok(())
+}
+
+fn write_class(
+    writer: &mut winmd::Writer,
+    namespace: &str,
+    name: &str,
+    member: &idl::Class,
+) -> Result<()> {
+    let mut flags = metadata::TypeAttributes::Public | metadata::TypeAttributes::WindowsRuntime;
+    let mut base = ("System".to_string(), "Object".to_string());
+    let mut attributes = vec![];
+    let mut is_composable = false;
+
+    // IDL has no notion of contract versions so all factories are unversioned.
+    let version = winmd::Value::U32(0);
+
+    for attribute in &member.attributes {
+        if attribute.path().is_ident("base") {
+            // The base class is given explicitly since a class defined in another winmd file can't be told apart from
+            // an interface by name alone.
+            let winmd::Type::TypeRef(ty) = syn_path(namespace, &[], &attribute.parse_args()?)
+            else {
+                return Err(Error::new("expected base class").with_span(attribute.span()));
+            };
+
+            base = (ty.namespace, ty.name);
+        } else if attribute.path().is_ident("activatable") {
+            if let syn::Meta::Path(_) = attribute.meta {
+                attributes.push(("ActivatableAttribute", vec![version.clone()]));
+            } else {
+                let factory = syn_type_name(namespace, &attribute.parse_args()?)?;
+                attributes.push(("ActivatableAttribute", vec![factory, version.clone()]));
+            }
+        } else if attribute.path().is_ident("static") {
+            let statics = syn_type_name(namespace, &attribute.parse_args()?)?;
+            attributes.push(("StaticAttribute", vec![statics, version.clone()]));
+        } else if attribute.path().is_ident("composable") {
+            let args = attribute.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )?;
+
+            let mut args = args.iter();
+
+            let Some(factory) = args.next() else {
+                return Err(Error::new("expected factory interface").with_span(attribute.span()));
+            };
+
+            // See `Windows.Foundation.Metadata.CompositionType` for these values.
+            let composition = match args.next() {
+                None => 2,
+                Some(path) if path.is_ident("public") => 2,
+                Some(path) if path.is_ident("protected") => 1,
+                Some(path) => {
+                    return Err(
+                        Error::new("expected `public` or `protected`").with_span(path.span())
+                    )
+                }
+            };
+
+            let composition = winmd::Value::EnumRef(
+                winmd::TypeName {
+                    namespace: "Windows.Foundation.Metadata".to_string(),
+                    name: "CompositionType".to_string(),
+                    generics: vec![],
+                },
+                composition,
+            );
+
+            let factory = syn_type_name(namespace, factory)?;
+            attributes.push((
+                "ComposableAttribute",
+                vec![factory, composition, version.clone()],
+            ));
+            is_composable = true;
+        }
+    }
+
+    if !is_composable {
+        flags |= metadata::TypeAttributes::Sealed;
+    }
+
+    let extends = writer.insert_type_ref(&base.0, &base.1);
+    let def = writer.tables.TypeDef.len() as u32;
+
+    writer.tables.TypeDef.push(winmd::TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as _,
+        Flags: flags.0,
+        MethodList: writer.tables.MethodDef.len() as _,
+        TypeName: writer.strings.insert(name),
+        TypeNamespace: writer.strings.insert(namespace),
+    });
+
+    for (name, args) in attributes {
+        writer.insert_attribute(
+            winmd::HasAttribute::TypeDef(def),
+            "Windows.Foundation.Metadata",
+            name,
+            &args,
+        );
+    }
+
+    // The first interface is the default interface.
+    for (position, path) in member.extends.iter().enumerate() {
+        let ty = syn_path(namespace, &[], path);
+
+        if !matches!(ty, winmd::Type::TypeRef(_)) {
+            return Err(Error::new("expected interface").with_span(path.span()));
+        }
+
+        let interface = writer.tables.InterfaceImpl.len() as u32;
+
+        // Generic interfaces, such as `IVector<HSTRING>`, are referenced by a TypeSpec.
+        let reference = writer.insert_type_def_or_ref(&ty);
+
+        writer.tables.InterfaceImpl.push(winmd::InterfaceImpl {
+            Class: def,
+            Interface: reference,
+        });
+
+        if position == 0 {
+            writer.insert_attribute(
+                winmd::HasAttribute::InterfaceImpl(interface),
+                "Windows.Foundation.Metadata",
+                "DefaultAttribute",
+                &[],
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn syn_type_name(namespace: &str, path: &syn::Path) -> Result<winmd::Value> {
+    if let winmd::Type::TypeRef(ty) = syn_path(namespace, &[], path) {
+        Ok(winmd::Value::TypeName(format!(
+            "{}.{}",
+            ty.namespace, ty.name
+        )))
+    } else {
+        Err(Error::new("expected type name").with_span(path.span()))
+    }
+}
+
+fn syn_enum_repr(attribute: &syn::Attribute) -> Result<winmd::Type> {
+    let ident: syn::Ident = attribute.parse_args()?;
+
+    match ident.to_string().as_str() {
+        "i8" => Ok(winmd::Type::I8),
+        "u8" => Ok(winmd::Type::U8),
+        "i16" => Ok(winmd::Type::I16),
+        "u16" => Ok(winmd::Type::U16),
+        "i32" => Ok(winmd::Type::I32),
+        "u32" => Ok(winmd::Type::U32),
+        "i64" => Ok(winmd::Type::I64),
+        "u64" => Ok(winmd::Type::U64),
+        _ => Err(Error::new("enum repr must be an integer type").with_span(ident.span())),
+    }
+}
+
+fn syn_integer(expr: &syn::Expr) -> Result<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit
+            .base10_parse()
+            .map_err(|_| Error::new("failed to parse literal").with_span(lit.span())),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(-syn_integer(expr)?),
+        rest => Err(Error::new("expected integer literal").with_span(rest.span())),
+    }
+}
+
+fn integer_value(ty: &winmd::Type, value: i128) -> Option<winmd::Value> {
     match ty {
-        syn::Type::Path(ty) => syn_type_path(namespace, ty),
-        syn::Type::Ptr(ptr) => syn_type_ptr(namespace, ptr),
-        syn::Type::Array(array) => syn_type_array(namespace, array),
+        winmd::Type::I8 => i8::try_from(value).ok().map(winmd::Value::I8),
+        winmd::Type::U8 => u8::try_from(value).ok().map(winmd::Value::U8),
+        winmd::Type::I16 => i16::try_from(value).ok().map(winmd::Value::I16),
+        winmd::Type::U16 => u16::try_from(value).ok().map(winmd::Value::U16),
+        winmd::Type::I32 => i32::try_from(value).ok().map(winmd::Value::I32),
+        winmd::Type::U32 => u32::try_from(value).ok().map(winmd::Value::U32),
+        winmd::Type::I64 => i64::try_from(value).ok().map(winmd::Value::I64),
+        winmd::Type::U64 => u64::try_from(value).ok().map(winmd::Value::U64),
+        _ => None,
+    }
+}
+
+fn syn_guid(attribute: &syn::Attribute) -> Result<Vec<winmd::Value>> {
+    let lit: syn::LitStr = attribute.parse_args()?;
+    let value = lit.value();
+    let invalid = || Error::new("invalid GUID").with_span(lit.span());
+
+    if value.len() != 36 || value.split('-').map(str::len).ne([8, 4, 4, 4, 12]) {
+        return Err(invalid());
+    }
+
+    let value = u128::from_str_radix(&value.replace('-', ""), 16).map_err(|_| invalid())?;
+    let mut args = vec![
+        winmd::Value::U32((value >> 96) as u32),
+        winmd::Value::U16((value >> 80 & 0xFFFF) as u16),
+        winmd::Value::U16((value >> 64 & 0xFFFF) as u16),
+    ];
+    args.extend(
+        value.to_be_bytes()[8..]
+            .iter()
+            .map(|byte| winmd::Value::U8(*byte)),
+    );
+    Ok(args)
+}
+
+fn syn_return_type(namespace: &str, generics: &[String], output: &syn::ReturnType) -> winmd::Type {
+    if let syn::ReturnType::Type(_, ty) = output {
+        syn_type(namespace, generics, ty)
+    } else {
+        winmd::Type::Void
+    }
+}
+
+// WinRT parameters use references and slices whereas Win32 parameters use raw pointers.
+fn syn_params(
+    namespace: &str,
+    generics: &[String],
+    signature: &syn::Signature,
+    is_winrt: bool,
+) -> Result<Vec<(String, metadata::ParamAttributes, winmd::Type)>> {
+    let mut params = vec![];
+
+    for input in &signature.inputs {
+        let syn::FnArg::Typed(pat_type) = input else {
+            return Err(Error::new("receivers not supported").with_span(input.span()));
+        };
+
+        let syn::Pat::Ident(ref pat_ident) = *pat_type.pat else {
+            return Err(
+                Error::new("parameter names must be identifiers").with_span(pat_type.span())
+            );
+        };
+
+        let (flags, ty) = if is_winrt {
+            syn_param(namespace, generics, &pat_type.ty)
+        } else {
+            syn_win32_param(namespace, generics, &pat_type.ty)
+        };
+
+        params.push((pat_ident.ident.to_string(), flags, ty));
+    }
+
+    Ok(params)
+}
+
+fn syn_win32_param(
+    namespace: &str,
+    generics: &[String],
+    ty: &syn::Type,
+) -> (metadata::ParamAttributes, winmd::Type) {
+    let flags = match ty {
+        syn::Type::Ptr(ptr) if ptr.mutability.is_some() => metadata::ParamAttributes::Out,
+        _ => metadata::ParamAttributes::In,
+    };
+
+    (flags, syn_type(namespace, generics, ty))
+}
+
+// String constants are written as `&str` since there is no string type that fits both WinRT and Win32.
+fn syn_constant_type(namespace: &str, ty: &syn::Type) -> winmd::Type {
+    if let syn::Type::Reference(reference) = ty {
+        if let syn::Type::Path(path) = &*reference.elem {
+            if path.path.is_ident("str") {
+                return winmd::Type::String;
+            }
+        }
+    }
+
+    syn_type(namespace, &[], ty)
+}
+
+fn syn_constant_value(ty: &winmd::Type, expr: &syn::Expr) -> Result<winmd::Value> {
+    let value = match ty {
+        winmd::Type::Bool => {
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Bool(lit),
+                ..
+            }) = expr
+            {
+                Some(winmd::Value::Bool(lit.value))
+            } else {
+                None
+            }
+        }
+        winmd::Type::String => {
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) = expr
+            {
+                Some(winmd::Value::String(lit.value()))
+            } else {
+                None
+            }
+        }
+        winmd::Type::F32 => Some(winmd::Value::F32(syn_float(expr)? as f32)),
+        winmd::Type::F64 => Some(winmd::Value::F64(syn_float(expr)?)),
+        // HRESULT values are commonly written in hex so both signed and unsigned values are accepted.
+        winmd::Type::HRESULT => {
+            let value = syn_integer(expr)?;
+            i32::try_from(value)
+                .or_else(|_| u32::try_from(value).map(|value| value as i32))
+                .ok()
+                .map(winmd::Value::I32)
+        }
+        winmd::Type::I8
+        | winmd::Type::U8
+        | winmd::Type::I16
+        | winmd::Type::U16
+        | winmd::Type::I32
+        | winmd::Type::U32
+        | winmd::Type::I64
+        | winmd::Type::U64 => {
+            let Some(value) = integer_value(ty, syn_integer(expr)?) else {
+                return Err(Error::new("constant out of range").with_span(expr.span()));
+            };
+            Some(value)
+        }
+        _ => return Err(Error::new("unsupported constant type").with_span(expr.span())),
+    };
+
+    value.ok_or_else(|| Error::new("invalid constant value").with_span(expr.span()))
+}
+
+fn syn_float(expr: &syn::Expr) -> Result<f64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(lit),
+            ..
+        }) => lit
+            .base10_parse()
+            .map_err(|_| Error::new("failed to parse literal").with_span(lit.span())),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit
+            .base10_parse()
+            .map_err(|_| Error::new("failed to parse literal").with_span(lit.span())),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(-syn_float(expr)?),
+        rest => Err(Error::new("expected float literal").with_span(rest.span())),
+    }
+}
+
+fn syn_param(
+    namespace: &str,
+    generics: &[String],
+    ty: &syn::Type,
+) -> (metadata::ParamAttributes, winmd::Type) {
+    let syn::Type::Reference(reference) = ty else {
+        return (
+            metadata::ParamAttributes::In,
+            syn_type(namespace, generics, ty),
+        );
+    };
+
+    let flags = if reference.mutability.is_some() {
+        metadata::ParamAttributes::Out
+    } else {
+        metadata::ParamAttributes::In
+    };
+
+    let ty = if let syn::Type::Slice(slice) = &*reference.elem {
+        winmd::Type::WinrtArray(Box::new(syn_type(namespace, generics, &slice.elem)))
+    } else if reference.mutability.is_some() {
+        winmd::Type::RefMut(Box::new(syn_type(namespace, generics, &reference.elem)))
+    } else {
+        winmd::Type::ConstRef(Box::new(syn_type(namespace, generics, &reference.elem)))
+    };
+
+    (flags, ty)
+}
+
+fn syn_type(namespace: &str, generics: &[String], ty: &syn::Type) -> winmd::Type {
+    match ty {
+        syn::Type::Path(ty) => syn_type_path(namespace, generics, ty),
+        syn::Type::Ptr(ptr) => syn_type_ptr(namespace, generics, ptr),
+        syn::Type::Array(array) => syn_type_array(namespace, generics, array),
         rest => unimplemented!("{rest:?}"),
     }
 }
 
-fn syn_type_array(namespace: &str, array: &syn::TypeArray) -> winmd::Type {
-    let ty = syn_type(namespace, &array.elem);
+fn syn_type_array(namespace: &str, generics: &[String], array: &syn::TypeArray) -> winmd::Type {
+    let ty = syn_type(namespace, generics, &array.elem);
 
     if let syn::Expr::Lit(lit) = &array.len {
         if let syn::Lit::Int(lit) = &lit.lit {
@@ -320,8 +1061,8 @@ fn syn_type_array(namespace: &str, array: &syn::TypeArray) -> winmd::Type {
     unimplemented!()
 }
 
-fn syn_type_ptr(namespace: &str, ptr: &syn::TypePtr) -> winmd::Type {
-    let ty = syn_type(namespace, &ptr.elem);
+fn syn_type_ptr(namespace: &str, generics: &[String], ptr: &syn::TypePtr) -> winmd::Type {
+    let ty = syn_type(namespace, generics, &ptr.elem);
     if ptr.mutability.is_some() {
         ty.into_mut_ptr()
     } else {
@@ -329,19 +1070,23 @@ fn syn_type_ptr(namespace: &str, ptr: &syn::TypePtr) -> winmd::Type {
     }
 }
 
-fn syn_type_path(namespace: &str, ty: &syn::TypePath) -> winmd::Type {
+fn syn_type_path(namespace: &str, generics: &[String], ty: &syn::TypePath) -> winmd::Type {
     if ty.qself.is_none() {
-        return syn_path(namespace, &ty.path);
+        return syn_path(namespace, generics, &ty.path);
     }
 
     unimplemented!()
 }
 
-fn syn_path(namespace: &str, path: &syn::Path) -> winmd::Type {
+fn syn_path(namespace: &str, generics: &[String], path: &syn::Path) -> winmd::Type {
     if let Some(segment) = path.segments.first() {
         if path.segments.len() == 1 {
             let name = segment.ident.to_string();
 
+            if let Some(number) = generics.iter().position(|generic| *generic == name) {
+                return winmd::Type::GenericParam(number as u16);
+            }
+
             return match name.as_str() {
                 "void" => winmd::Type::Void,
                 "bool" => winmd::Type::Bool,
@@ -377,8 +1122,7 @@ fn syn_path(namespace: &str, path: &syn::Path) -> winmd::Type {
         }
     }
 
-    // TODO: Here we assume that paths are absolute since there's no way to disambiguate between nested and absolute paths
-    // The canonicalize function preprocesses the IDL to make this work
+    // Paths are absolute since the canonicalize function has already resolved any relative paths and use declarations.
 
     let mut builder = vec![];
 
@@ -387,13 +1131,37 @@ fn syn_path(namespace: &str, path: &syn::Path) -> winmd::Type {
         builder.push(segment);
     }
 
+    // This is how `void` is written when converting from winmd so that the IDL also reads as valid Rust.
+    if builder == ["core", "ffi", "c_void"] {
+        return winmd::Type::Void;
+    }
+
+    // Generic types are named by their arity in metadata, e.g. `IVector<T>` is named "IVector`1".
+    let mut arguments = vec![];
+
+    if let Some(syn::PathArguments::AngleBracketed(args)) =
+        path.segments.last().map(|segment| &segment.arguments)
+    {
+        for arg in &args.args {
+            if let syn::GenericArgument::Type(ty) = arg {
+                arguments.push(syn_type(namespace, generics, ty));
+            }
+        }
+    }
+
     // Unwrapping as there are more one segments
     let (name, namespace) = builder.split_last().unwrap();
     let namespace = namespace.join(".");
 
+    let name = if arguments.is_empty() {
+        name.to_string()
+    } else {
+        format!("{name}`{}", arguments.len())
+    };
+
     winmd::Type::TypeRef(winmd::TypeName {
         namespace,
-        name: name.to_string(),
-        generics: vec![],
+        name,
+        generics: arguments,
     })
 }
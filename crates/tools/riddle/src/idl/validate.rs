@@ -0,0 +1,283 @@
+use crate::{idl, Error, Result};
+use std::collections::HashMap;
+use syn::spanned::Spanned;
+
+/// Checks the IDL file against the constraints of the WinRT type system before any metadata is written. This assumes
+/// that the file has already been canonicalized so that type references are fully-qualified.
+pub fn validate(file: &idl::File) -> Result<()> {
+    let mut errors = vec![];
+
+    for module in &file.modules {
+        validate_module(module, &mut errors);
+    }
+
+    // Modules may be declared more than once so names are compared once fully-qualified.
+    let mut names = HashMap::<String, proc_macro2::Span>::new();
+
+    for (name, span) in file.type_names() {
+        if let Some(other) = names.get(&name) {
+            let other = other.start();
+            errors.push(
+                Error::new(&format!(
+                    "the name `{name}` is also defined at line {}, column {}",
+                    other.line, other.column
+                ))
+                .with_span(span),
+            );
+        } else {
+            names.insert(name, span);
+        }
+    }
+
+    Error::check(errors)
+}
+
+fn validate_module(module: &idl::Module, errors: &mut Vec<Error>) {
+    for member in &module.members {
+        match member {
+            idl::ModuleMember::Module(module) => validate_module(module, errors),
+            idl::ModuleMember::Interface(member) => validate_interface(member, errors),
+            idl::ModuleMember::Struct(member) => validate_struct(member, errors),
+            idl::ModuleMember::Enum(member) => validate_enum(member, errors),
+            idl::ModuleMember::Delegate(member) => validate_delegate(member, errors),
+            idl::ModuleMember::Function(member) => validate_function(member, errors),
+            _ => {}
+        }
+    }
+}
+
+fn validate_interface(member: &idl::Interface, errors: &mut Vec<Error>) {
+    if !member
+        .attributes
+        .iter()
+        .any(|attribute| attribute.path().is_ident("guid"))
+    {
+        errors.push(
+            Error::new(&format!(
+                "interface `{}` requires a `#[guid]` attribute",
+                member.name
+            ))
+            .with_span(member.span),
+        );
+    }
+
+    // WinRT only supports overloading by arity and each overload other than the first must be given a unique name
+    // so that languages without overloading can still call it.
+    let mut arities = HashMap::<String, Vec<usize>>::new();
+
+    for method in &member.methods {
+        let name = method.sig.ident.to_string();
+        let arity = method.sig.inputs.len();
+        let arities = arities.entry(name.clone()).or_default();
+
+        if arities.contains(&arity) {
+            errors.push(
+                Error::new(&format!(
+                    "method `{name}` of interface `{}` is already declared with the same number of parameters",
+                    member.name
+                ))
+                .with_span(method.sig.ident.span()),
+            );
+        } else if !arities.is_empty() && overload_name(method).is_none() {
+            errors.push(
+                Error::new(&format!(
+                    "method `{name}` of interface `{}` overloads by arity and requires an `#[overload]` name",
+                    member.name
+                ))
+                .with_span(method.sig.ident.span()),
+            );
+        }
+
+        arities.push(arity);
+    }
+
+    // Overload names must not collide with each other or with the names of other methods.
+    let mut names: Vec<String> = arities.into_keys().collect();
+
+    for method in &member.methods {
+        if let Some(overload) = overload_name(method) {
+            if names.contains(&overload.value()) {
+                errors.push(
+                    Error::new(&format!(
+                        "overload name `{}` of interface `{}` is already used",
+                        overload.value(),
+                        member.name
+                    ))
+                    .with_span(overload.span()),
+                );
+            } else {
+                names.push(overload.value());
+            }
+        }
+    }
+
+    // Events are declared by an `add_{event}` method taking the handler and a `remove_{event}` method taking the token
+    // returned by the former, both marked with `#[event]`.
+    let events: Vec<&syn::TraitItemFn> = member
+        .methods
+        .iter()
+        .filter(|method| {
+            method
+                .attrs
+                .iter()
+                .any(|attribute| attribute.path().is_ident("event"))
+        })
+        .collect();
+
+    let is_event = |name: String| events.iter().any(|method| method.sig.ident == name);
+
+    for method in &events {
+        let name = method.sig.ident.to_string();
+
+        let error = if let Some(event) = name.strip_prefix("add_") {
+            if method.sig.inputs.len() != 1 {
+                Some("must have a single handler parameter")
+            } else if !is_event(format!("remove_{event}")) {
+                Some("requires a matching `#[event]` remove method")
+            } else {
+                None
+            }
+        } else if let Some(event) = name.strip_prefix("remove_") {
+            if method.sig.inputs.len() != 1 {
+                Some("must have a single token parameter")
+            } else if !is_event(format!("add_{event}")) {
+                Some("requires a matching `#[event]` add method")
+            } else {
+                None
+            }
+        } else {
+            Some("must be named `add_` or `remove_` followed by the event name")
+        };
+
+        if let Some(error) = error {
+            errors.push(
+                Error::new(&format!(
+                    "event method `{name}` of interface `{}` {error}",
+                    member.name
+                ))
+                .with_span(method.sig.ident.span()),
+            );
+        }
+    }
+}
+
+// Only WinRT delegates may be generic since their instantiations are identified by a GUID derived from the
+// generic delegate's GUID.
+fn validate_delegate(member: &idl::Delegate, errors: &mut Vec<Error>) {
+    if !member.generics.is_empty()
+        && !member
+            .attributes
+            .iter()
+            .any(|attribute| attribute.path().is_ident("guid"))
+    {
+        errors.push(
+            Error::new(&format!(
+                "generic delegate `{}` requires a `#[guid]` attribute",
+                member.name
+            ))
+            .with_span(member.signature.ident.span()),
+        );
+    }
+}
+
+fn validate_function(member: &idl::Function, errors: &mut Vec<Error>) {
+    if !member.signature.generics.params.is_empty() {
+        errors.push(
+            Error::new(&format!("function `{}` cannot be generic", member.name))
+                .with_span(member.signature.generics.span()),
+        );
+    }
+}
+
+fn overload_name(method: &syn::TraitItemFn) -> Option<syn::LitStr> {
+    method
+        .attrs
+        .iter()
+        .find(|attribute| attribute.path().is_ident("overload"))
+        .and_then(|attribute| attribute.parse_args().ok())
+}
+
+fn validate_struct(member: &idl::Struct, errors: &mut Vec<Error>) {
+    for field in &member.fields {
+        if let Some(kind) = invalid_field_kind(&field.ty) {
+            errors.push(
+                Error::new(&format!(
+                    "field `{}` of WinRT struct `{}` cannot be {kind}",
+                    field.name, member.name
+                ))
+                .with_span(field.ty.span()),
+            );
+        }
+    }
+}
+
+// WinRT structs may only contain fixed-size value types that are the same size on every architecture.
+fn invalid_field_kind(ty: &syn::Type) -> Option<&'static str> {
+    match ty {
+        syn::Type::Ptr(_) => Some("a pointer"),
+        syn::Type::Reference(_) => Some("a reference"),
+        syn::Type::Array(_) | syn::Type::Slice(_) => Some("an array"),
+        syn::Type::Path(ty) if ty.path.is_ident("isize") || ty.path.is_ident("usize") => {
+            Some("a pointer-sized integer")
+        }
+        _ => None,
+    }
+}
+
+fn validate_enum(member: &idl::Enum, errors: &mut Vec<Error>) {
+    for attribute in &member.item.attrs {
+        if !attribute.path().is_ident("repr") {
+            continue;
+        }
+
+        let is_integral = matches!(
+            attribute.parse_args::<syn::Ident>(),
+            Ok(ident) if ["i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64"].contains(&ident.to_string().as_str())
+        );
+
+        if !is_integral {
+            errors.push(
+                Error::new(&format!(
+                    "enum `{}` must have an integral underlying type",
+                    member.name
+                ))
+                .with_span(attribute.span()),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn errors(source: &str) -> String {
+        crate::idl::File::parse_str(source)
+            .and_then(|file| file.into_winmd())
+            .map(|_| ())
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn test_validate() {
+        let errors = errors(
+            "mod Test { interface IFoo { fn Go(); fn Go(a: i32); #[event] fn add_Done(a: i32); #[event] fn Changed(); } struct S { p: *mut u8, n: usize } #[repr(f32)] enum E { A = 1 } delegate fn D<T>(t: T); extern fn F<T>(); struct S { a: i32 } } mod Test { enum S { A = 1 } }",
+        );
+
+        assert!(errors.contains("interface `IFoo` requires a `#[guid]` attribute"));
+        assert!(errors.contains(
+            "method `Go` of interface `IFoo` overloads by arity and requires an `#[overload]` name"
+        ));
+        assert!(errors.contains(
+            "event method `add_Done` of interface `IFoo` requires a matching `#[event]` remove method"
+        ));
+        assert!(errors.contains(
+            "event method `Changed` of interface `IFoo` must be named `add_` or `remove_` followed by the event name"
+        ));
+        assert!(errors.contains("field `p` of WinRT struct `S` cannot be a pointer"));
+        assert!(errors.contains("field `n` of WinRT struct `S` cannot be a pointer-sized integer"));
+        assert!(errors.contains("enum `E` must have an integral underlying type"));
+        assert!(errors.contains("generic delegate `D` requires a `#[guid]` attribute"));
+        assert!(errors.contains("function `F` cannot be generic"));
+        assert!(errors.contains("the name `Test.S` is also defined at line 1, column 114"));
+    }
+}
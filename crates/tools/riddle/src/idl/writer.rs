@@ -68,6 +68,9 @@ impl Writer {
             idl::ModuleMember::Struct(member) => self.idl_struct(member),
             idl::ModuleMember::Enum(member) => self.idl_enum(member),
             idl::ModuleMember::Class(member) => self.idl_class(member),
+            idl::ModuleMember::Delegate(member) => self.idl_delegate(member),
+            idl::ModuleMember::Function(member) => self.idl_function(member),
+            idl::ModuleMember::Constant(member) => self.idl_constant(member),
         }
     }
 
@@ -75,6 +78,7 @@ impl Writer {
         self.attrs(&member.attributes);
         self.word("interface ");
         self.word(&member.name);
+        self.generics(&member.generics);
         self.word(" {");
         self.newline();
         self.indent += 1;
@@ -90,6 +94,14 @@ impl Writer {
         self.word("}");
     }
 
+    fn generics(&mut self, generics: &[String]) {
+        if !generics.is_empty() {
+            self.word("<");
+            self.word(&generics.join(", "));
+            self.word(">");
+        }
+    }
+
     fn attrs(&mut self, attrs: &[syn::Attribute]) {
         for attr in attrs {
             self.attr(attr);
@@ -114,7 +126,23 @@ impl Writer {
     fn meta_list(&mut self, meta_list: &syn::MetaList) {
         self.path(&meta_list.path);
         self.word("(");
-        self.word(&meta_list.tokens.to_string());
+
+        if let Ok(args) = meta_list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
+        ) {
+            let mut first = true;
+            for arg in &args {
+                if first {
+                    first = false;
+                } else {
+                    self.word(", ");
+                }
+                self.expr(arg);
+            }
+        } else {
+            self.word(&meta_list.tokens.to_string());
+        }
+
         self.word(")");
     }
 
@@ -164,7 +192,52 @@ impl Writer {
         self.word("}");
     }
 
-    fn idl_class(&mut self, _member: &idl::Class) {}
+    fn idl_class(&mut self, member: &idl::Class) {
+        self.attrs(&member.attributes);
+
+        self.word("class ");
+        self.word(&member.name);
+
+        if !member.extends.is_empty() {
+            self.word(" : ");
+
+            let mut first = true;
+            for path in &member.extends {
+                if first {
+                    first = false;
+                } else {
+                    self.word(", ");
+                }
+                self.path(path);
+            }
+        }
+
+        self.word(";");
+    }
+
+    fn idl_delegate(&mut self, member: &idl::Delegate) {
+        self.attrs(&member.attributes);
+        self.word("delegate ");
+        self.signature(&member.signature);
+        self.word(";");
+    }
+
+    fn idl_function(&mut self, member: &idl::Function) {
+        self.attrs(&member.attributes);
+        self.signature(&member.signature);
+        self.word(";");
+    }
+
+    fn idl_constant(&mut self, member: &idl::Constant) {
+        self.attrs(&member.item.attrs);
+        self.word("const ");
+        self.ident(&member.item.ident);
+        self.word(": ");
+        self.ty(&member.item.ty);
+        self.word(" = ");
+        self.expr(&member.item.expr);
+        self.word(";");
+    }
 
     fn trait_item_fn(&mut self, method: &syn::TraitItemFn) {
         self.attrs(&method.attrs);
@@ -172,8 +245,24 @@ impl Writer {
     }
 
     fn signature(&mut self, signature: &syn::Signature) {
+        if let Some(abi) = &signature.abi {
+            self.word("extern ");
+            if let Some(name) = &abi.name {
+                self.lit_str(name);
+                self.word(" ");
+            }
+        }
+
         self.word("fn ");
         self.ident(&signature.ident);
+
+        let generics: Vec<String> = signature
+            .generics
+            .type_params()
+            .map(|param| param.ident.to_string())
+            .collect();
+
+        self.generics(&generics);
         self.word("(");
 
         let mut first = true;
@@ -222,10 +311,27 @@ impl Writer {
             syn::Type::Path(ty) => self.type_path(ty),
             syn::Type::Ptr(ptr) => self.type_ptr(ptr),
             syn::Type::Array(array) => self.type_array(array),
+            syn::Type::Reference(reference) => self.type_reference(reference),
+            syn::Type::Slice(slice) => self.type_slice(slice),
             rest => unimplemented!("{rest:?}"),
         }
     }
 
+    fn type_reference(&mut self, reference: &syn::TypeReference) {
+        if reference.mutability.is_some() {
+            self.word("&mut ");
+        } else {
+            self.word("&");
+        }
+        self.ty(&reference.elem);
+    }
+
+    fn type_slice(&mut self, slice: &syn::TypeSlice) {
+        self.word("[");
+        self.ty(&slice.elem);
+        self.word("]");
+    }
+
     fn type_array(&mut self, array: &syn::TypeArray) {
         self.word("[");
         self.ty(&array.elem);
@@ -238,6 +344,7 @@ impl Writer {
         match expr {
             syn::Expr::Lit(lit) => self.expr_lit(lit),
             syn::Expr::Unary(unary) => self.expr_unary(unary),
+            syn::Expr::Path(path) => self.path(&path.path),
             rest => unimplemented!("{rest:?}"),
         }
     }
@@ -255,14 +362,23 @@ impl Writer {
         match lit {
             syn::Lit::Int(lit) => self.lit_int(lit),
             syn::Lit::Str(lit) => self.lit_str(lit),
+            syn::Lit::Float(lit) => self.lit_float(lit),
+            syn::Lit::Bool(lit) => self.lit_bool(lit),
             _ => _ = dbg!(lit),
         }
     }
 
     fn lit_str(&mut self, lit: &syn::LitStr) {
-        self.word("\"");
-        self.word(&lit.value());
-        self.word("\"");
+        // The original token preserves any escape sequences.
+        self.word(&lit.token().to_string());
+    }
+
+    fn lit_float(&mut self, lit: &syn::LitFloat) {
+        self.word(&lit.token().to_string());
+    }
+
+    fn lit_bool(&mut self, lit: &syn::LitBool) {
+        self.word(if lit.value { "true" } else { "false" });
     }
 
     fn lit_int(&mut self, lit: &syn::LitInt) {
@@ -283,6 +399,10 @@ impl Writer {
     }
 
     fn path(&mut self, path: &syn::Path) {
+        if path.leading_colon.is_some() {
+            self.word("::");
+        }
+
         let mut first = true;
         for segment in &path.segments {
             if first {
@@ -296,6 +416,24 @@ impl Writer {
 
     pub fn path_segment(&mut self, segment: &syn::PathSegment) {
         self.ident(&segment.ident);
+
+        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+            self.word("<");
+
+            let mut first = true;
+            for arg in &args.args {
+                if first {
+                    first = false;
+                } else {
+                    self.word(", ");
+                }
+                if let syn::GenericArgument::Type(ty) = arg {
+                    self.ty(ty);
+                }
+            }
+
+            self.word(">");
+        }
     }
 
     fn item_use(&mut self, item: &syn::ItemUse) {
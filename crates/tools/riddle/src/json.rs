@@ -0,0 +1,14 @@
+use crate::{Error, Result};
+
+pub fn from_reader(
+    reader: &metadata::Reader,
+    filter: &metadata::Filter,
+    config: std::collections::BTreeMap<&str, &str>,
+    output: &str,
+) -> Result<()> {
+    if let Some((key, _)) = config.first_key_value() {
+        return Err(Error::new(&format!("invalid configuration value: `{key}`")));
+    }
+
+    crate::write_to_file(output, metadata::to_json(reader, filter))
+}
@@ -1,6 +1,8 @@
 mod args;
 mod error;
 mod idl;
+mod json;
+mod manifest;
 mod rust;
 mod tokens;
 mod tree;
@@ -15,6 +17,7 @@ enum ArgKind {
     Output,
     Filter,
     Config,
+    Manifest,
 }
 
 fn main() {
@@ -25,7 +28,6 @@ fn main() {
 }
 
 fn run() -> Result<()> {
-    let time = std::time::Instant::now();
     let args = args::from_process()?;
 
     if args.is_empty() {
@@ -34,11 +36,13 @@ fn run() -> Result<()> {
 
 Options:
   -in     <path>       Path to files and directories containing .winmd and .idl files
-  -out    <path>       Path to .winmd or .idl file to generate
+  -out    <path>       Path to .winmd, .idl, .rs or .json file to generate, or directory when splitting
   -filter <namespace>  Namespaces to include or !exclude in output
   -format              Format .idl files only
+  -validate            Check inputs for consistency before writing any output
   -config <key=value>  Override a configuration value
   -etc    <path>       File containing command line options
+  -manifest <path>     JSON file describing any number of outputs to generate
 "#
         );
         return Ok(());
@@ -50,7 +54,9 @@ Options:
     let mut include = Vec::<&str>::new();
     let mut exclude = Vec::<&str>::new();
     let mut config = std::collections::BTreeMap::<&str, &str>::new();
+    let mut manifest = None;
     let mut format = false;
+    let mut validate = false;
 
     for arg in &args {
         if arg.starts_with('-') {
@@ -63,7 +69,9 @@ Options:
                 "-out" => kind = ArgKind::Output,
                 "-filter" => kind = ArgKind::Filter,
                 "-config" => kind = ArgKind::Config,
+                "-manifest" => kind = ArgKind::Manifest,
                 "-format" => format = true,
+                "-validate" => validate = true,
                 _ => return Err(Error::new(&format!("invalid option: `{arg}`"))),
             },
             ArgKind::Output => {
@@ -73,6 +81,13 @@ Options:
                     return Err(Error::new("too many outputs"));
                 }
             }
+            ArgKind::Manifest => {
+                if manifest.is_none() {
+                    manifest = Some(arg.as_str());
+                } else {
+                    return Err(Error::new("too many manifests"));
+                }
+            }
             ArgKind::Input => input.push(arg.as_str()),
             ArgKind::Filter => {
                 if let Some(rest) = arg.strip_prefix('!') {
@@ -92,9 +107,14 @@ Options:
     }
 
     if format {
-        if output.is_some() || !include.is_empty() || !exclude.is_empty() {
+        if output.is_some()
+            || !include.is_empty()
+            || !exclude.is_empty()
+            || validate
+            || manifest.is_some()
+        {
             return Err(Error::new(
-                "-format cannot be combined with -output, -include, or -exclude",
+                "-format cannot be combined with -output, -include, -exclude, -validate, or -manifest",
             ));
         }
 
@@ -114,27 +134,112 @@ Options:
         return Ok(());
     }
 
+    if let Some(manifest) = manifest {
+        if output.is_some()
+            || !input.is_empty()
+            || !include.is_empty()
+            || !exclude.is_empty()
+            || !config.is_empty()
+        {
+            return Err(Error::new(
+                "-manifest cannot be combined with -in, -out, -filter, or -config",
+            ));
+        }
+
+        for target in manifest::read(manifest)? {
+            write_target(&target, validate)?;
+        }
+
+        return Ok(());
+    }
+
+    if validate && output.is_none() {
+        let (paths, input) = read_input(&input)?;
+        validate_input(&metadata::Reader::new(&input), &paths)?;
+        println!("  Finished validating {} inputs", paths.len());
+        return Ok(());
+    }
+
     let Some(output) = output else {
         return Err(Error::new("no output"));
     };
 
+    let target = manifest::Target {
+        input: input.iter().map(|input| input.to_string()).collect(),
+        output: output.to_string(),
+        include: include.iter().map(|include| include.to_string()).collect(),
+        exclude: exclude.iter().map(|exclude| exclude.to_string()).collect(),
+        config: config
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        rename: Default::default(),
+    };
+
+    write_target(&target, validate)
+}
+
+fn write_target(target: &manifest::Target, validate: bool) -> Result<()> {
+    let time = std::time::Instant::now();
+    let input: Vec<&str> = target.input.iter().map(|input| input.as_str()).collect();
+    let include: Vec<&str> = target
+        .include
+        .iter()
+        .map(|include| include.as_str())
+        .collect();
+    let exclude: Vec<&str> = target
+        .exclude
+        .iter()
+        .map(|exclude| exclude.as_str())
+        .collect();
+
+    let config: std::collections::BTreeMap<&str, &str> = target
+        .config
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+
     // This isn't strictly necessary but avoids a common newbie pitfall where all metadata
     // would be generated when building a component for a specific API.
     if include.is_empty() {
         return Err(Error::new("at least one filter must be specified"));
     }
 
-    let output = canonicalize(output)?;
+    // Splitting writes a number of .winmd files to the output directory.
+    let split = config.contains_key("SPLIT");
+
+    if split {
+        std::fs::create_dir_all(&target.output)
+            .map_err(|_| Error::new("failed to create directory").with_path(&target.output))?;
+    }
+
+    let output = canonicalize(&target.output)?;
+
+    let (paths, mut input) = read_input(&input)?;
+
+    if validate {
+        validate_input(&metadata::Reader::new(&input), &paths)?;
+    }
+
+    if !target.rename.is_empty() {
+        let renamed = rename_types(&metadata::Reader::new(&input), &target.rename)?;
+        input = vec![renamed];
+    }
 
-    let input = read_input(&input)?;
     let reader = metadata::Reader::new(&input);
     let filter = metadata::Filter::new(&include, &exclude);
 
     match extension(&output) {
+        _ if split => winmd::from_reader(&reader, &filter, config, &output)?,
         "idl" => idl::from_reader(&reader, &filter, config, &output)?,
         "winmd" => winmd::from_reader(&reader, &filter, config, &output)?,
         "rs" => rust::from_reader(&reader, &filter, config, &output)?,
-        _ => return Err(Error::new("output extension must be one of winmd/idl/rs")),
+        "json" => json::from_reader(&reader, &filter, config, &output)?,
+        _ => {
+            return Err(Error::new(
+                "output extension must be one of winmd/idl/rs/json",
+            ))
+        }
     }
 
     let elapsed = time.elapsed().as_secs_f32();
@@ -152,6 +257,34 @@ Options:
     Ok(())
 }
 
+// Renames types by rewriting all of the inputs as a single file, so that every output format and every reference to
+// a renamed type sees the new name.
+fn rename_types(
+    reader: &metadata::Reader,
+    rename: &std::collections::BTreeMap<String, String>,
+) -> Result<metadata::File> {
+    let mut model = metadata::model::Model::from_reader(reader, &metadata::Filter::default());
+    let mut errors = vec![];
+
+    for (name, new_name) in rename {
+        let renamed = match name.rsplit_once('.') {
+            Some((namespace, name)) => model.rename_type(namespace, name, new_name),
+            None => false,
+        };
+
+        if !renamed {
+            errors.push(Error::new(&format!(
+                "the type `{name}` could not be found to rename"
+            )));
+        }
+    }
+
+    Error::check(errors)?;
+
+    // Unwrapping here is fine since the model should have produced a valid winmd
+    Ok(metadata::File::new(model.write("renamed.winmd")).unwrap())
+}
+
 fn filter_input(input: &[&str], extensions: &[&str]) -> Result<Vec<String>> {
     fn try_push(path: &str, extensions: &[&str], results: &mut Vec<String>) -> Result<()> {
         // First canonicalize input so that the extension check below will match the case of the path.
@@ -192,7 +325,8 @@ fn filter_input(input: &[&str], extensions: &[&str]) -> Result<Vec<String>> {
     Ok(results)
 }
 
-fn read_input(input: &[&str]) -> Result<Vec<metadata::File>> {
+// Returns the paths of the inputs along with the files read from them, in the same order.
+fn read_input(input: &[&str]) -> Result<(Vec<String>, Vec<metadata::File>)> {
     let input = filter_input(input, &["winmd", "idl"])?;
 
     if input.is_empty() {
@@ -200,26 +334,48 @@ fn read_input(input: &[&str]) -> Result<Vec<metadata::File>> {
     }
 
     let mut results = vec![];
+    let mut errors = vec![];
 
+    // Maps the types defined by .idl inputs to the path of the file defining them.
+    let mut names = std::collections::HashMap::new();
+
+    // Keep going so that errors in all of the inputs are reported at once.
     for input in &input {
         let file = if extension(input) == "winmd" {
-            read_winmd_file(input)?
+            read_winmd_file(input)
         } else {
-            read_idl_file(input)?
+            read_idl_file(input, &mut names)
         };
 
-        results.push(file);
+        match file {
+            Ok(file) => results.push(file),
+            Err(error) => errors.push(error),
+        }
     }
 
-    Ok(results)
+    Error::check(errors)?;
+    Ok((input, results))
+}
+
+fn validate_input(reader: &metadata::Reader, paths: &[String]) -> Result<()> {
+    let errors = metadata::validate(reader)
+        .into_iter()
+        .map(|diagnostic| Error::new(&diagnostic.message).with_path(&paths[diagnostic.file]))
+        .collect();
+
+    Error::check(errors)
 }
 
 fn read_file_text(path: &str) -> Result<String> {
     std::fs::read_to_string(path).map_err(|_| Error::new("failed to read text file"))
 }
 
-fn read_file_bytes(path: &str) -> Result<Vec<u8>> {
-    std::fs::read(path).map_err(|_| Error::new("failed to read binary file"))
+fn map_file_bytes(path: &str) -> Result<memmap2::Mmap> {
+    let file = std::fs::File::open(path).map_err(|_| Error::new("failed to read binary file"))?;
+
+    // Mapping the file avoids reading large .winmd files into memory when only a few types are needed. This is only
+    // unsound if the file is modified while riddle is running.
+    unsafe { memmap2::Mmap::map(&file) }.map_err(|_| Error::new("failed to read binary file"))
 }
 
 fn read_file_lines(path: &str) -> Result<Vec<String>> {
@@ -235,10 +391,34 @@ fn read_file_lines(path: &str) -> Result<Vec<String>> {
     Ok(lines)
 }
 
-fn read_idl_file(path: &str) -> Result<metadata::File> {
-    read_file_text(path)
-        .and_then(|source| idl::File::parse_str(&source))
-        .and_then(|file| file.into_winmd())
+fn read_idl_file(
+    path: &str,
+    names: &mut std::collections::HashMap<String, String>,
+) -> Result<metadata::File> {
+    let source = read_file_text(path).map_err(|err| err.with_path(path))?;
+
+    idl::File::parse_str(&source)
+        .and_then(|file| {
+            let mut errors = vec![];
+
+            for (name, span) in file.type_names() {
+                // Duplicates within the same file are reported by `validate`.
+                match names.get(&name) {
+                    Some(other) if other != path => errors.push(
+                        Error::new(&format!("the name `{name}` is also defined in `{other}`"))
+                            .with_span(span),
+                    ),
+                    Some(_) => {}
+                    None => _ = names.insert(name, path.to_string()),
+                }
+            }
+
+            match (Error::check(errors), file.into_winmd()) {
+                (Ok(()), result) => result,
+                (Err(error), Ok(_)) => Err(error),
+                (Err(error), Err(other)) => Err(Error::combine(vec![error, other])),
+            }
+        })
         .map(|bytes| {
             // TODO: Write bytes to file if you need to debug the intermediate .winmd file like so:
             // _ = write_to_file("temp.winmd", &bytes);
@@ -246,13 +426,14 @@ fn read_idl_file(path: &str) -> Result<metadata::File> {
             // Unwrapping here is fine since `idl_to_winmd` should have produced a valid winmd
             metadata::File::new(bytes).unwrap()
         })
-        .map_err(|err| err.with_path(path))
+        .map_err(|err| err.with_path(path).with_source(&source))
 }
 
 fn read_winmd_file(path: &str) -> Result<metadata::File> {
-    read_file_bytes(path).and_then(|bytes| {
-        metadata::File::new(bytes)
-            .ok_or_else(|| Error::new("failed to read .winmd format").with_path(path))
+    map_file_bytes(path).and_then(|bytes| {
+        metadata::File::from_source(bytes).map_err(|error| {
+            Error::new(&format!("failed to read .winmd format: {error}")).with_path(path)
+        })
     })
 }
 
@@ -0,0 +1,480 @@
+//! A bindings manifest describes any number of outputs in a single JSON file, where each target has the same options
+//! as a single riddle invocation along with type-level `skip` and `rename` lists:
+//!
+//! ```json
+//! {
+//!     "in": ["crates/libs/metadata/default"],
+//!     "targets": [
+//!         {
+//!             "out": "crates/libs/sys/src/lib.rs",
+//!             "config": ["PACKAGE", "SYS"],
+//!             "filter": ["Windows.Win32", "!Windows.Win32.UI.Xaml"],
+//!             "skip": ["Windows.Win32.Foundation.BOOLEAN"],
+//!             "rename": { "Windows.Win32.Foundation.BOOL": "WIN32_BOOL" }
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! A target's `in` replaces the top-level `in`, which is otherwise shared by all targets. Paths are relative to the
+//! current directory, just like command line options.
+
+use super::*;
+use std::collections::BTreeMap;
+
+/// The options for a single output.
+#[derive(Debug, Default, PartialEq)]
+pub struct Target {
+    pub input: Vec<String>,
+    pub output: String,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub config: BTreeMap<String, String>,
+    /// Maps the full names of types to their new names, within the same namespace.
+    pub rename: BTreeMap<String, String>,
+}
+
+pub fn read(path: &str) -> Result<Vec<Target>> {
+    let source = read_file_text(path).map_err(|error| error.with_path(path))?;
+    parse_str(&source).map_err(|error| error.with_path(path).with_source(&source))
+}
+
+pub fn parse_str(source: &str) -> Result<Vec<Target>> {
+    let mut parser = Parser::new(source);
+    let value = parser.value()?;
+    parser.skip_whitespace();
+
+    if parser.peek().is_some() {
+        return Err(parser.error("expected end of file"));
+    }
+
+    let mut input = vec![];
+    let mut targets = None;
+
+    for (key, value) in value.into_object()? {
+        match key.as_str() {
+            "in" => input = value.into_strings()?,
+            "targets" => targets = Some((value.line, value.column, value.into_array()?)),
+            _ => return Err(value.error(&format!("unknown manifest key `{key}`"))),
+        }
+    }
+
+    let Some((line, column, targets)) = targets else {
+        return Err(Error::new("expected `targets`").with_position(1, 0));
+    };
+
+    if targets.is_empty() {
+        return Err(Error::new("expected at least one target").with_position(line, column));
+    }
+
+    targets
+        .into_iter()
+        .map(|value| target(value, &input))
+        .collect()
+}
+
+fn target(value: Value, input: &[String]) -> Result<Target> {
+    let (line, column) = (value.line, value.column);
+
+    let mut target = Target {
+        input: input.to_vec(),
+        ..Default::default()
+    };
+
+    let mut output = None;
+
+    for (key, value) in value.into_object()? {
+        match key.as_str() {
+            "in" => target.input = value.into_strings()?,
+            "out" => output = Some(value.into_string()?),
+            "filter" => {
+                for filter in value.into_strings()? {
+                    if let Some(rest) = filter.strip_prefix('!') {
+                        target.exclude.push(rest.to_string());
+                    } else {
+                        target.include.push(filter);
+                    }
+                }
+            }
+            "skip" => target.exclude.extend(value.into_strings()?),
+            "config" => {
+                for config in value.into_strings()? {
+                    if let Some((key, value)) = config.split_once('=') {
+                        target.config.insert(key.to_string(), value.to_string());
+                    } else {
+                        target.config.insert(config, String::new());
+                    }
+                }
+            }
+            "rename" => {
+                for (name, value) in value.into_object()? {
+                    if !name.contains('.') {
+                        return Err(value.error(&format!(
+                            "`{name}` must be the full name of a type, including its namespace"
+                        )));
+                    }
+
+                    target.rename.insert(name, value.into_string()?);
+                }
+            }
+            _ => return Err(value.error(&format!("unknown target key `{key}`"))),
+        }
+    }
+
+    let Some(output) = output else {
+        return Err(Error::new("expected `out`").with_position(line, column));
+    };
+
+    target.output = output;
+    Ok(target)
+}
+
+struct Value {
+    kind: Kind,
+    line: usize,
+    column: usize,
+}
+
+enum Kind {
+    Null,
+    Bool,
+    Number,
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn error(&self, message: &str) -> Error {
+        Error::new(message).with_position(self.line, self.column)
+    }
+
+    fn expected(&self, expected: &str) -> Error {
+        let found = match self.kind {
+            Kind::Null => "null",
+            Kind::Bool => "boolean",
+            Kind::Number => "number",
+            Kind::String(_) => "string",
+            Kind::Array(_) => "array",
+            Kind::Object(_) => "object",
+        };
+
+        self.error(&format!("expected {expected}, found {found}"))
+    }
+
+    fn into_string(self) -> Result<String> {
+        match self.kind {
+            Kind::String(value) => Ok(value),
+            _ => Err(self.expected("string")),
+        }
+    }
+
+    fn into_array(self) -> Result<Vec<Value>> {
+        match self.kind {
+            Kind::Array(values) => Ok(values),
+            _ => Err(self.expected("array")),
+        }
+    }
+
+    fn into_object(self) -> Result<Vec<(String, Value)>> {
+        match self.kind {
+            Kind::Object(members) => Ok(members),
+            _ => Err(self.expected("object")),
+        }
+    }
+
+    fn into_strings(self) -> Result<Vec<String>> {
+        self.into_array()?
+            .into_iter()
+            .map(|value| value.into_string())
+            .collect()
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    // One-based line number.
+    line: usize,
+    // Zero-based column.
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            chars: source.chars().peekable(),
+            line: 1,
+            column: 0,
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::new(message).with_position(self.line, self.column)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let next = self.chars.next()?;
+
+        if next == '\n' {
+            self.line += 1;
+            self.column = 0;
+        } else {
+            self.column += 1;
+        }
+
+        Some(next)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\r' | '\n')) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{expected}`")))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+        let (line, column) = (self.line, self.column);
+
+        let kind = match self.peek() {
+            Some('{') => self.object()?,
+            Some('[') => self.array()?,
+            Some('"') => Kind::String(self.string()?),
+            Some('-' | '0'..='9') => self.number(),
+            Some('a'..='z') => match self.word().as_str() {
+                "null" => Kind::Null,
+                "true" | "false" => Kind::Bool,
+                _ => return Err(Error::new("expected a value").with_position(line, column)),
+            },
+            _ => return Err(self.error("expected a value")),
+        };
+
+        Ok(Value { kind, line, column })
+    }
+
+    fn object(&mut self) -> Result<Kind> {
+        self.expect('{')?;
+        let mut members = vec![];
+        self.skip_whitespace();
+
+        if self.peek() == Some('}') {
+            self.next();
+            return Ok(Kind::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a string key"));
+            }
+
+            let key = self.string()?;
+            self.expect(':')?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => {}
+                Some('}') => return Ok(Kind::Object(members)),
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Kind> {
+        self.expect('[')?;
+        let mut values = vec![];
+        self.skip_whitespace();
+
+        if self.peek() == Some(']') {
+            self.next();
+            return Ok(Kind::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.next() {
+                Some(',') => {}
+                Some(']') => return Ok(Kind::Array(values)),
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        self.next();
+        let mut value = String::new();
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.unicode()?,
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+
+                    value.push(escaped);
+                }
+                Some(c) if c >= ' ' => value.push(c),
+                _ => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    // Parses the digits following `\u`, along with the low half of a surrogate pair.
+    fn unicode(&mut self) -> Result<char> {
+        let high = self.hex()?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if self.next() != Some('\\') || self.next() != Some('u') {
+                return Err(self.error("expected low surrogate"));
+            }
+
+            let low = self.hex()?;
+
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("expected low surrogate"));
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+
+    fn hex(&mut self) -> Result<u32> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("expected four hex digits"))?;
+
+            code = code * 16 + digit;
+        }
+
+        Ok(code)
+    }
+
+    // Numbers aren't used by manifests so they're only parsed far enough to report them as unexpected.
+    fn number(&mut self) -> Kind {
+        while let Some('-' | '+' | '.' | 'e' | 'E' | '0'..='9') = self.peek() {
+            self.next();
+        }
+
+        Kind::Number
+    }
+
+    fn word(&mut self) -> String {
+        let mut value = String::new();
+
+        while let Some(c @ 'a'..='z') = self.peek() {
+            value.push(c);
+            self.next();
+        }
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let targets = parse_str(
+            r#"{
+                "in": ["default"],
+                "targets": [
+                    {
+                        "out": "bindings.rs",
+                        "config": ["FLATTEN", "SYS", "SPLIT=2"],
+                        "filter": ["Windows.Win32.Foundation", "!Windows.Win32.Foundation.Metadata"],
+                        "skip": ["Windows.Win32.Foundation.BOOLEAN"],
+                        "rename": { "Windows.Win32.Foundation.BOOL": "WIN32_BOOL" }
+                    },
+                    { "in": ["other\\path"], "out": "other.winmd", "filter": ["Windows.Foundation"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].input, ["default"]);
+        assert_eq!(targets[0].output, "bindings.rs");
+        assert_eq!(targets[0].include, ["Windows.Win32.Foundation"]);
+        assert_eq!(
+            targets[0].exclude,
+            [
+                "Windows.Win32.Foundation.Metadata",
+                "Windows.Win32.Foundation.BOOLEAN"
+            ]
+        );
+        assert_eq!(targets[0].config["SYS"], "");
+        assert_eq!(targets[0].config["SPLIT"], "2");
+        assert_eq!(
+            targets[0].rename["Windows.Win32.Foundation.BOOL"],
+            "WIN32_BOOL"
+        );
+        assert_eq!(targets[1].input, ["other\\path"]);
+        assert!(targets[1].config.is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let source = "{\n    \"targets\": [\n        { \"out\": 1 }\n    ]\n}";
+
+        let error = parse_str(source)
+            .unwrap_err()
+            .with_path("bindings.json")
+            .with_source(source);
+
+        assert_eq!(
+            error.to_string(),
+            r#"error: expected string, found number
+  --> bindings.json:3:18
+  |
+3 |         { "out": 1 }
+  |                  ^
+"#
+        );
+
+        let error = parse_str(r#"{ "targets": [{ "filter": [] }] }"#).unwrap_err();
+        assert!(error.to_string().starts_with("error: expected `out`"));
+
+        let error = parse_str(r#"{ "targets": [{ "out": "a.rs" }], }"#).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("error: expected a string key"));
+    }
+}
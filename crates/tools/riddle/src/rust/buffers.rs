@@ -0,0 +1,258 @@
+use super::*;
+
+// Win32 metadata doesn't annotate params receiving the required length of a buffer, so these are found by name.
+const REQUIRED_LEN_NAMES: &[&str] = &[
+    "needed",
+    "required",
+    "returnlength",
+    "returnedlength",
+    "resultlength",
+];
+
+/// How a function reports the length of the buffer it fills.
+enum BufferLen {
+    /// The len param is a pointer holding the capacity on input and the written or required length on output.
+    InOut,
+    /// The len param holds the capacity and a separate param receives the written or required length.
+    Required(usize),
+    /// The len param holds the capacity and the function returns the written or required length.
+    Returned,
+}
+
+struct Buffer {
+    data: usize,
+    len: usize,
+    kind: BufferLen,
+    element: Type,
+}
+
+/// Generates a helper that allocates the buffer for functions following the "call twice to get the size" pattern,
+/// when the `BUFFERS` configuration value is set.
+pub fn gen(gen: &Gen, def: MethodDef) -> TokenStream {
+    if !gen.buffers {
+        return quote! {};
+    }
+
+    let signature = &gen.reader.method_def_signature(def, &[]);
+    let kind = gen.reader.signature_kind(signature);
+
+    let Some(buffer) = find_buffer(gen, def, signature, kind) else {
+        return quote! {};
+    };
+
+    let name = gen.reader.method_def_name(def);
+    let ident = to_ident(name);
+    let is_string = signature.params[buffer.data].ty == Type::PWSTR;
+    let helper = if is_string {
+        to_ident(&format!("{name}_string"))
+    } else {
+        to_ident(&format!("{name}_vec"))
+    };
+    let element = gen.type_default_name(&buffer.element);
+    let return_type = if is_string {
+        quote! { ::std::string::String }
+    } else {
+        quote! { ::std::vec::Vec<#element> }
+    };
+    let generics = gen.constraint_generics(&signature.params);
+    let where_clause = gen.where_clause(&signature.params);
+    let cfg = gen.reader.signature_cfg(signature);
+    let doc = gen.method_def_doc(def, &cfg);
+    let features = gen.cfg_features(&cfg);
+    let link = functions::gen_link(gen, signature, &cfg);
+
+    let mut skip = vec![buffer.data, buffer.len];
+    if let BufferLen::Required(required) = buffer.kind {
+        skip.push(required);
+    }
+    let params = gen.win32_params_except(&signature.params, kind, &skip);
+
+    // Params are converted once up front since the function may be called more than once.
+    let mut conversions = quote! {};
+    let mut args = quote! {};
+
+    for (position, param) in signature.params.iter().enumerate() {
+        let name = gen.param_name(param.def);
+
+        let arg = if position == buffer.data {
+            quote! { ::core::mem::transmute(data__), }
+        } else if position == buffer.len {
+            match buffer.kind {
+                BufferLen::InOut => quote! { len__, },
+                _ => quote! { capacity__, },
+            }
+        } else if matches!(buffer.kind, BufferLen::Required(required) if required == position) {
+            quote! { len__, }
+        } else {
+            match param.kind {
+                SignatureParamKind::TryInto => {
+                    conversions.combine(&quote! { let #name = #name.try_into_param()?; });
+                    quote! { #name.abi(), }
+                }
+                SignatureParamKind::IntoParam => {
+                    conversions.combine(&quote! { let #name = #name.into_param(); });
+                    quote! { #name.abi(), }
+                }
+                _ => gen.win32_arg(&signature.params, position),
+            }
+        };
+
+        args.combine(&arg);
+    }
+
+    let read = match buffer.kind {
+        BufferLen::InOut => quote! {
+            ::windows_core::imp::read_sized_buffer(|data__: *mut #element, capacity__, len__| {
+                *len__ = capacity__;
+                #ident(#args).ok()
+            })?
+        },
+        BufferLen::Required(_) => quote! {
+            ::windows_core::imp::read_sized_buffer(|data__: *mut #element, capacity__, len__| {
+                #ident(#args).ok()
+            })?
+        },
+        BufferLen::Returned => quote! {
+            ::windows_core::imp::read_truncated_buffer(|data__: *mut #element, capacity__| {
+                #ident(#args)
+            })?
+        },
+    };
+
+    let result = if is_string {
+        quote! { Ok(::windows_core::imp::wide_to_string(&buffer__)) }
+    } else {
+        quote! { Ok(buffer__) }
+    };
+
+    quote! {
+        #doc
+        #features
+        pub unsafe fn #helper<#generics>(#params) -> ::windows_core::Result<#return_type> #where_clause {
+            #link
+            #conversions
+            let buffer__ = #read;
+            #result
+        }
+    }
+}
+
+fn find_buffer(
+    gen: &Gen,
+    def: MethodDef,
+    signature: &Signature,
+    kind: SignatureKind,
+) -> Option<Buffer> {
+    let params = &signature.params;
+    let mut buffers = params.iter().enumerate().filter(|(_, param)| {
+        gen.reader
+            .param_flags(param.def)
+            .contains(ParamAttributes::Out)
+            && relative_len(gen, param).is_some()
+    });
+
+    // Functions filling more than one buffer aren't supported.
+    let (data, _) = buffers.next()?;
+    if buffers.next().is_some() {
+        return None;
+    }
+
+    let (len, is_bytes) = relative_len(gen, &params[data])?;
+    if len == data || len >= params.len() {
+        return None;
+    }
+
+    // The len param must not describe any other array.
+    if params.iter().enumerate().any(|(position, param)| {
+        position != data
+            && matches!(relative_len(gen, param), Some((relative, _)) if relative == len)
+    }) {
+        return None;
+    }
+
+    // Strings are returned as a `String` so their length must be in characters rather than bytes.
+    if params[data].ty == Type::PWSTR {
+        if is_bytes {
+            return None;
+        }
+    } else if !params[data].ty.is_pointer() {
+        return None;
+    }
+
+    let element = if is_bytes {
+        Type::U8
+    } else {
+        params[data].ty.deref()
+    };
+
+    if !is_buffer_element(gen, &element) {
+        return None;
+    }
+
+    let is_u32_ptr = |ty: &Type| matches!(ty, Type::MutPtr(ty, 1) if **ty == Type::U32);
+
+    let kind = if is_u32_ptr(&params[len].ty)
+        && gen
+            .reader
+            .param_flags(params[len].def)
+            .contains(ParamAttributes::Out)
+    {
+        if kind != SignatureKind::ResultVoid {
+            return None;
+        }
+        BufferLen::InOut
+    } else if params[len].ty == Type::U32 {
+        if kind == SignatureKind::ResultVoid {
+            let required = params.iter().position(|param| {
+                let name = gen.reader.param_name(param.def).to_lowercase();
+                is_u32_ptr(&param.ty)
+                    && gen
+                        .reader
+                        .param_flags(param.def)
+                        .contains(ParamAttributes::Out)
+                    && REQUIRED_LEN_NAMES
+                        .iter()
+                        .any(|required| name.contains(required))
+            })?;
+            BufferLen::Required(required)
+        } else if kind == SignatureKind::PreserveSig
+            && signature.return_type == Type::U32
+            && gen.reader.method_def_last_error(def)
+        {
+            BufferLen::Returned
+        } else {
+            return None;
+        }
+    } else {
+        return None;
+    };
+
+    Some(Buffer {
+        data,
+        len,
+        kind,
+        element,
+    })
+}
+
+/// The position of the param holding the length of an array param, and whether the length is in bytes.
+fn relative_len(gen: &Gen, param: &SignatureParam) -> Option<(usize, bool)> {
+    match gen.reader.param_kind(param.def) {
+        SignatureParamKind::ArrayRelativeLen(len) => Some((len, false)),
+        SignatureParamKind::ArrayRelativeByteLen(len) => Some((len, true)),
+        _ => None,
+    }
+}
+
+// The element type must be `Clone` and `Default` so that the buffer can be allocated.
+fn is_buffer_element(gen: &Gen, ty: &Type) -> bool {
+    match ty {
+        Type::TypeDef(def, _) => {
+            matches!(
+                gen.reader.type_def_kind(*def),
+                TypeKind::Struct | TypeKind::Enum
+            ) && gen.reader.type_def_is_copyable(*def)
+        }
+        _ => !ty.is_pointer() && gen.reader.type_is_primitive(ty),
+    }
+}
@@ -26,7 +26,7 @@ fn gen_class(gen: &Gen, def: TypeDef) -> TokenStream {
     let mut method_names = MethodNames::new();
 
     let cfg = gen.reader.type_def_cfg(def, &[]);
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
 
     for interface in &interfaces {
@@ -49,7 +49,10 @@ pub fn gen(gen: &Gen, def: Field) -> TokenStream {
                 quote! { #value as _ }
             };
 
-            if !gen.sys && gen.reader.type_has_replacement(&ty) {
+            let is_typed_enum = gen.sys_typed_enums
+                && matches!(&ty, Type::TypeDef(def, _) if gen.reader.type_def_kind(*def) == TypeKind::Enum);
+
+            if (!gen.sys || is_typed_enum) && gen.reader.type_has_replacement(&ty) {
                 quote! {
                     #doc
                     #features
@@ -19,7 +19,7 @@ fn gen_callback(gen: &Gen, def: TypeDef) -> TokenStream {
     let signature = gen.reader.method_def_signature(method, &[]);
     let return_type = gen.return_sig(&signature);
     let cfg = gen.reader.type_def_cfg(def, &[]);
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
 
     let params = signature.params.iter().map(|p| {
@@ -64,7 +64,7 @@ fn gen_win_delegate(gen: &Gen, def: TypeDef) -> TokenStream {
     let fn_constraint = gen_fn_constraint(gen, def, &signature);
 
     let cfg = gen.reader.type_def_cfg(def, generics);
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
 
     let vtbl_signature = gen.vtbl_signature(def, generics, &signature);
@@ -174,7 +174,7 @@ fn gen_win_delegate(gen: &Gen, def: TypeDef) -> TokenStream {
     tokens
 }
 
-fn gen_fn_constraint(gen: &Gen, def: TypeDef, signature: &Signature) -> TokenStream {
+pub fn gen_fn_constraint(gen: &Gen, def: TypeDef, signature: &Signature) -> TokenStream {
     let signature = gen.impl_signature(def, signature);
 
     quote! { F: FnMut #signature + ::core::marker::Send + 'static }
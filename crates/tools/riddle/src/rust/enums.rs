@@ -6,8 +6,10 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
     let underlying_type = gen.reader.type_def_underlying_type(def);
     let underlying_type = gen.type_name(&underlying_type);
     let is_scoped = gen.reader.type_def_is_scoped(def);
+    let is_typed = !gen.sys || gen.sys_typed_enums;
+    let is_flags = gen.reader.type_def_is_flags(def);
     let cfg = gen.reader.type_def_cfg(def, &[]);
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
 
     let fields: Vec<(TokenStream, TokenStream)> = gen
@@ -30,7 +32,7 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
         })
         .collect();
 
-    let eq = if gen.sys {
+    let eq = if !is_typed {
         quote! {}
     } else {
         quote! {
@@ -39,7 +41,7 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
         }
     };
 
-    let mut tokens = if is_scoped || !gen.sys {
+    let mut tokens = if is_scoped || is_typed {
         quote! {
             #doc
             #features
@@ -69,7 +71,7 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
             }
         });
     } else if !gen.minimal {
-        if gen.sys {
+        if !is_typed {
             let fields = fields.iter().map(|(field_name, value)| {
                 quote! {
                     #doc
@@ -96,7 +98,7 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
         }
     }
 
-    if is_scoped || !gen.sys {
+    if is_scoped || is_typed {
         tokens.combine(&quote! {
             #features
             impl ::core::marker::Copy for #ident {}
@@ -109,7 +111,7 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
         });
     }
 
-    if !gen.sys {
+    if is_typed {
         tokens.combine(&quote! {
             #features
             impl ::core::default::Default for #ident {
@@ -121,83 +123,189 @@ pub fn gen(gen: &Gen, def: TypeDef) -> TokenStream {
     }
 
     if !gen.sys {
-        let name = type_name.name;
         tokens.combine(&quote! {
             #features
             impl ::windows_core::TypeKind for #ident {
                 type TypeKind = ::windows_core::CopyType;
             }
-            #features
-            impl ::core::fmt::Debug for #ident {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    f.debug_tuple(#name).field(&self.0).finish()
-                }
-            }
         });
+    }
 
-        if gen.reader.type_def_is_flags(def) {
-            tokens.combine(&quote! {
-                #features
-                impl #ident {
-                    pub const fn contains(&self, other: Self) -> bool {
-                        self.0 & other.0 == other.0
-                    }
+    if is_typed {
+        tokens.combine(&gen_debug(gen, def, &features, &fields, is_flags));
+    }
+
+    if gen.error_names && type_name == TypeName::WIN32_ERROR {
+        tokens.combine(&error_names::gen_name(gen, def, &features));
+    }
+
+    if is_typed && is_flags {
+        tokens.combine(&quote! {
+            #features
+            impl #ident {
+                pub const fn contains(&self, other: Self) -> bool {
+                    self.0 & other.0 == other.0
                 }
-                #features
-                impl ::core::ops::BitOr for #ident {
-                    type Output = Self;
+            }
+            #features
+            impl ::core::ops::BitOr for #ident {
+                type Output = Self;
 
-                    fn bitor(self, other: Self) -> Self {
-                        Self(self.0 | other.0)
-                    }
+                fn bitor(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
                 }
-                #features
-                impl ::core::ops::BitAnd for #ident {
-                    type Output = Self;
+            }
+            #features
+            impl ::core::ops::BitAnd for #ident {
+                type Output = Self;
 
-                    fn bitand(self, other: Self) -> Self {
-                        Self(self.0 & other.0)
-                    }
+                fn bitand(self, other: Self) -> Self {
+                    Self(self.0 & other.0)
                 }
-                #features
-                impl ::core::ops::BitOrAssign for #ident {
-                    fn bitor_assign(&mut self, other: Self) {
-                        self.0.bitor_assign(other.0)
-                    }
+            }
+            #features
+            impl ::core::ops::BitOrAssign for #ident {
+                fn bitor_assign(&mut self, other: Self) {
+                    self.0.bitor_assign(other.0)
                 }
-                #features
-                impl ::core::ops::BitAndAssign for #ident {
-                    fn bitand_assign(&mut self, other: Self) {
-                        self.0.bitand_assign(other.0)
-                    }
+            }
+            #features
+            impl ::core::ops::BitAndAssign for #ident {
+                fn bitand_assign(&mut self, other: Self) {
+                    self.0.bitand_assign(other.0)
                 }
-                #features
-                impl ::core::ops::Not for #ident {
-                    type Output = Self;
+            }
+            #features
+            impl ::core::ops::Not for #ident {
+                type Output = Self;
 
-                    fn not(self) -> Self {
-                        Self(self.0.not())
-                    }
+                fn not(self) -> Self {
+                    Self(self.0.not())
                 }
-            });
-        }
+            }
+        });
+    }
 
-        if gen
+    if !gen.sys
+        && gen
             .reader
             .type_def_flags(def)
             .contains(TypeAttributes::WindowsRuntime)
-        {
-            let signature =
-                Literal::byte_string(gen.reader.type_def_signature(def, &[]).as_bytes());
+    {
+        let signature = Literal::byte_string(gen.reader.type_def_signature(def, &[]).as_bytes());
 
-            tokens.combine(&quote! {
-                #features
-                impl ::windows_core::RuntimeType for #ident {
-                    const SIGNATURE: ::windows_core::imp::ConstBuffer = ::windows_core::imp::ConstBuffer::from_slice(#signature);
+        tokens.combine(&quote! {
+            #features
+            impl ::windows_core::RuntimeType for #ident {
+                const SIGNATURE: ::windows_core::imp::ConstBuffer = ::windows_core::imp::ConstBuffer::from_slice(#signature);
+            }
+        });
+    }
+
+    tokens
+}
+
+// Values are printed by name, by both `Debug` and `Display`, falling back to hex for unknown values. Flags that aren't a
+// named value name each set bit in declaration order, followed by any remaining bits in hex. No helpers are used since
+// the sys crate has no `windows_core` dependency.
+fn gen_debug(
+    gen: &Gen,
+    def: TypeDef,
+    features: &TokenStream,
+    fields: &[(TokenStream, TokenStream)],
+    is_flags: bool,
+) -> TokenStream {
+    let ident = to_ident(gen.reader.type_def_name(def));
+    let name = gen.reader.type_def_name(def);
+    let fallback = format!("{name}({{:#x}})");
+
+    // `WIN32_ERROR` has thousands of values so they are only named with the `ERROR_NAMES` configuration value, using the
+    // `name` method generated along with it.
+    if gen.reader.type_def_type_name(def) == TypeName::WIN32_ERROR {
+        if gen.error_names {
+            return error_names::gen_fmt(gen, def, features);
+        }
+
+        return quote! {
+            #features
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(#name).field(&self.0).finish()
                 }
-            });
+            }
+        };
+    }
+
+    // Values are displayed just as they're debugged since both print the symbolic name.
+    let display = quote! {
+        #features
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(self, f)
+            }
         }
+    };
+
+    if !is_flags {
+        let mut values = BTreeSet::new();
+
+        // Only the first of several fields with the same value is matched.
+        let arms = fields.iter().filter_map(|(field_name, value)| {
+            if !values.insert(value.as_str()) {
+                return None;
+            }
+            let field_name = field_name.as_str().trim_start_matches("r#");
+            Some(quote! { #value => f.write_str(#field_name), })
+        });
+
+        return quote! {
+            #features
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self.0 {
+                        #(#arms)*
+                        _ => ::core::write!(f, #fallback, self.0),
+                    }
+                }
+            }
+            #display
+        };
     }
 
-    tokens
+    let names = fields.iter().map(|(field_name, value)| {
+        let name = field_name.as_str().trim_start_matches("r#");
+        quote! { (#name, #ident(#value)), }
+    });
+
+    quote! {
+        #features
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                const NAMES: &[(&str, #ident)] = &[#(#names)*];
+                if let Some((name, _)) = NAMES.iter().find(|(_, value)| value.0 == self.0) {
+                    return f.write_str(name);
+                }
+                let mut remaining = self.0;
+                let mut first = true;
+                for (name, value) in NAMES {
+                    if value.0 != 0 && remaining & value.0 == value.0 {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        f.write_str(name)?;
+                        remaining &= !value.0;
+                        first = false;
+                    }
+                }
+                if first {
+                    ::core::write!(f, #fallback, self.0)
+                } else if remaining != 0 {
+                    ::core::write!(f, " | {:#x}", remaining)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        #display
+    }
 }
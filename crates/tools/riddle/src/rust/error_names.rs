@@ -0,0 +1,120 @@
+use super::*;
+
+// Error codes are mapped back to the names of the constants included by the filter, which are all defined in this
+// namespace, when the `ERROR_NAMES` configuration value is set. Only the first name of each value is used.
+const NAMESPACE: &str = "Windows.Win32.Foundation";
+
+/// Generates the `name` method of `WIN32_ERROR` and `NTSTATUS`, whose values are named by the fields of the type as well
+/// as the constants of that type.
+pub fn gen_name(gen: &Gen, def: TypeDef, features: &TokenStream) -> TokenStream {
+    let ident = to_ident(gen.reader.type_def_name(def));
+    let signed = !gen.reader.type_def_underlying_type(def).is_unsigned();
+    let ty = Type::TypeDef(def, Vec::new());
+    let fields = gen.reader.type_def_fields(def).chain(constants(gen, &ty));
+    let arms = gen_arms(gen, fields, signed);
+
+    quote! {
+        #features
+        impl #ident {
+            pub const fn name(&self) -> ::core::option::Option<&'static str> {
+                match self.0 {
+                    #arms
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `Debug` and `Display` impls of `WIN32_ERROR` and `NTSTATUS`, which print the name of the value if it has
+/// one and fall back to hex otherwise.
+pub fn gen_fmt(gen: &Gen, def: TypeDef, features: &TokenStream) -> TokenStream {
+    let name = gen.reader.type_def_name(def);
+    let ident = to_ident(name);
+    let fallback = format!("{name}({{:#x}})");
+
+    quote! {
+        #features
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.name() {
+                    ::core::option::Option::Some(name) => f.write_str(name),
+                    ::core::option::Option::None => ::core::write!(f, #fallback, self.0),
+                }
+            }
+        }
+        #features
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.name() {
+                    ::core::option::Option::Some(name) => f.write_str(name),
+                    ::core::option::Option::None => ::core::write!(f, "{:#x}", self.0),
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `hresult_name` function. `HRESULT` is defined by `windows-core`, which has no access to metadata, and
+/// inherent methods can only be added by the defining crate, so its names are provided by a free function instead.
+pub fn gen_hresult_name(gen: &Gen) -> TokenStream {
+    let arms = gen_arms(gen, constants(gen, &Type::HRESULT), true);
+
+    quote! {
+        pub const fn hresult_name(value: ::windows_core::HRESULT) -> ::core::option::Option<&'static str> {
+            match value.0 {
+                #arms
+                _ => None,
+            }
+        }
+    }
+}
+
+fn constants<'a>(gen: &'a Gen, ty: &'a Type) -> impl Iterator<Item = Field> + 'a {
+    gen.reader
+        .namespace_constants(NAMESPACE)
+        .filter(move |field| gen.reader.field_type(*field, None) == *ty)
+}
+
+fn gen_arms(gen: &Gen, fields: impl Iterator<Item = Field>, signed: bool) -> TokenStream {
+    let mut names = BTreeMap::<i64, &str>::new();
+
+    for field in fields {
+        let name = gen.reader.field_name(field);
+
+        if !gen
+            .filter
+            .includes_type_name(TypeName::new(NAMESPACE, name))
+        {
+            continue;
+        }
+
+        let Some(constant) = gen.reader.field_constant(field) else {
+            continue;
+        };
+
+        // Error codes are stored as either signed or unsigned 32-bit values regardless of the type.
+        let value = match gen.reader.constant_value(constant) {
+            Value::U32(value) => value,
+            Value::I32(value) => value as u32,
+            _ => continue,
+        };
+
+        let value = if signed {
+            value as i32 as i64
+        } else {
+            value as i64
+        };
+
+        names.entry(value).or_insert(name);
+    }
+
+    let mut tokens = TokenStream::new();
+
+    for (value, name) in names {
+        let value = Literal::i64_unsuffixed(value);
+        tokens.combine(&quote! { #value => Some(#name), });
+    }
+
+    tokens
+}
@@ -9,7 +9,7 @@ pub fn gen_mod(gen: &Gen, namespace: &str) -> TokenStream {
         return "".into();
     }
 
-    match namespace {
+    let mut tokens: TokenStream = match namespace {
         "Windows.Foundation.Numerics" => concat!(
             include_str!("mod/Foundation/Numerics/Matrix3x2.rs"),
             include_str!("mod/Foundation/Numerics/Matrix4x4.rs"),
@@ -37,7 +37,13 @@ pub fn gen_mod(gen: &Gen, namespace: &str) -> TokenStream {
         }
         _ => "",
     }
-    .into()
+    .into();
+
+    if namespace == "Windows.Win32.Foundation" && gen.error_names {
+        tokens.combine(&error_names::gen_hresult_name(gen));
+    }
+
+    tokens
 }
 
 pub fn gen_impl(namespace: &str) -> TokenStream {
@@ -4,7 +4,9 @@ pub fn gen(gen: &Gen, def: MethodDef) -> TokenStream {
     if gen.sys {
         gen_sys_function(gen, def)
     } else {
-        gen_win_function(gen, def)
+        let mut tokens = gen_win_function(gen, def);
+        tokens.combine(&buffers::gen(gen, def));
+        tokens
     }
 }
 
@@ -22,12 +24,17 @@ fn gen_win_function(gen: &Gen, def: MethodDef) -> TokenStream {
     let generics = gen.constraint_generics(&signature.params);
     let where_clause = gen.where_clause(&signature.params);
     let abi_return_type = gen.return_sig(&signature);
+    let kind = gen.reader.signature_kind(&signature);
+    let owned = owned_return_type(gen, def, &signature, kind);
     let cfg = gen.reader.signature_cfg(&signature);
-    let doc = gen.cfg_doc(&cfg);
+    let cfg = match &owned {
+        Some((_, owned_cfg)) => cfg.union(owned_cfg),
+        None => cfg,
+    };
+    let doc = gen.method_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
     let link = gen_link(gen, &signature, &cfg);
 
-    let kind = gen.reader.signature_kind(&signature);
     match kind {
         SignatureKind::Query(_) => {
             let args = gen.win32_args(&signature.params, kind);
@@ -67,6 +74,20 @@ fn gen_win_function(gen: &Gen, def: MethodDef) -> TokenStream {
         SignatureKind::ResultValue => {
             let args = gen.win32_args(&signature.params, kind);
             let params = gen.win32_params(&signature.params, kind);
+
+            if let Some((owned_type, _)) = owned {
+                return quote! {
+                    #doc
+                    #features
+                    #[inline]
+                    pub unsafe fn #name<#generics>(#params) -> ::windows_core::Result<#owned_type> #where_clause {
+                        #link
+                        let mut result__ = ::std::mem::zeroed();
+                        #name(#args).from_abi(result__).map(|handle| #owned_type::from_raw(handle))
+                    }
+                };
+            }
+
             let return_type = signature.params[signature.params.len() - 1].ty.deref();
             let return_type = gen.type_name(&return_type);
 
@@ -129,7 +150,21 @@ fn gen_win_function(gen: &Gen, def: MethodDef) -> TokenStream {
             }
         }
         SignatureKind::ReturnStruct | SignatureKind::PreserveSig => {
-            if handle_last_error(gen, def, &signature) {
+            if let Some((owned_type, _)) = owned {
+                let args = gen.win32_args(&signature.params, kind);
+                let params = gen.win32_params(&signature.params, kind);
+
+                quote! {
+                    #doc
+                    #features
+                    #[inline]
+                    pub unsafe fn #name<#generics>(#params) -> ::windows_core::Result<#owned_type> #where_clause {
+                        #link
+                        let result__ = #name(#args);
+                        ::windows_core::imp::then(!result__.is_invalid(), ||#owned_type::from_raw(result__)).ok_or_else(::windows_core::Error::from_win32)
+                    }
+                }
+            } else if handle_last_error(gen, def, &signature) {
                 let args = gen.win32_args(&signature.params, kind);
                 let params = gen.win32_params(&signature.params, kind);
                 let return_type = gen.type_name(&signature.return_type);
@@ -177,7 +212,7 @@ fn gen_win_function(gen: &Gen, def: MethodDef) -> TokenStream {
     }
 }
 
-fn gen_link(gen: &Gen, signature: &Signature, cfg: &Cfg) -> TokenStream {
+pub fn gen_link(gen: &Gen, signature: &Signature, cfg: &Cfg) -> TokenStream {
     let name = gen.reader.method_def_name(signature.def);
     let ident = to_ident(name);
     let library = gen.reader.method_def_module_name(signature.def);
@@ -290,3 +325,33 @@ fn handle_last_error(gen: &Gen, def: MethodDef, signature: &Signature) -> bool {
     }
     false
 }
+
+/// Returns the owned wrapper, and the `cfg` it requires, for functions returning a handle that can be freed when the
+/// `OWNED` configuration value is set.
+fn owned_return_type<'a>(
+    gen: &Gen<'a>,
+    def: MethodDef,
+    signature: &Signature,
+    kind: SignatureKind,
+) -> Option<(TokenStream, Cfg<'a>)> {
+    if !gen.owned {
+        return None;
+    }
+
+    let return_type = match kind {
+        SignatureKind::ResultValue => signature.params[signature.params.len() - 1].ty.deref(),
+        SignatureKind::ReturnStruct | SignatureKind::PreserveSig
+            if handle_last_error(gen, def, signature) =>
+        {
+            signature.return_type.clone()
+        }
+        _ => return None,
+    };
+
+    let Type::TypeDef(handle, _) = return_type else {
+        return None;
+    };
+
+    let (_, _, cfg) = handles::owned_handle(gen, handle)?;
+    Some((handles::owned_handle_name(gen, handle), cfg))
+}
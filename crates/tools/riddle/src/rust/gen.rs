@@ -14,11 +14,16 @@ pub struct Gen<'a> {
     //
     // Maybe this macro is the embedable version of the IDL format?! like a more intelligient
     // version of the existing interface macro...
-    pub std: bool,     // tweaks for internal std library support
-    pub sys: bool,     // gen sys-style bindings
-    pub flatten: bool, // strips out namespaces - implies !package
-    pub package: bool, // default is single file with no cfg - implies !flatten
-    pub minimal: bool, // strips out enumerators - in future possibly other helpers as well
+    pub std: bool,             // tweaks for internal std library support
+    pub sys: bool,             // gen sys-style bindings
+    pub flatten: bool,         // strips out namespaces - implies !package
+    pub package: bool,         // default is single file with no cfg - implies !flatten
+    pub minimal: bool,         // strips out enumerators - in future possibly other helpers as well
+    pub owned: bool, // functions returning handles with a known free function return owned wrappers
+    pub sys_typed_enums: bool, // sys-style enums are newtypes with flag operators and symbolic debug output
+    pub event_revokers: bool, // events get closure-accepting `on_` methods returning revoker guards
+    pub buffers: bool, // functions filling caller-allocated buffers get helpers returning `Vec<T>` or `String`
+    pub error_names: bool, // error codes get name lookups and print the names of the constants included by the filter
 }
 
 impl<'a> Gen<'a> {
@@ -34,6 +39,11 @@ impl<'a> Gen<'a> {
             flatten: false,
             package: false,
             minimal: false,
+            owned: false,
+            sys_typed_enums: false,
+            event_revokers: false,
+            buffers: false,
+            error_names: false,
         }
     }
 
@@ -368,6 +378,29 @@ impl<'a> Gen<'a> {
         }
     }
 
+    /// Generates doc comments for types, linking to their official documentation when the metadata provides it.
+    pub(crate) fn type_def_doc(&self, def: TypeDef, cfg: &Cfg) -> TokenStream {
+        let mut tokens = self.link_doc(self.reader.type_def_documentation(def));
+        tokens.combine(&self.cfg_doc(cfg));
+        tokens
+    }
+
+    /// Generates doc comments for free functions, linking to their official documentation when the metadata provides it.
+    pub(crate) fn method_def_doc(&self, def: MethodDef, cfg: &Cfg) -> TokenStream {
+        let mut tokens = self.link_doc(self.reader.method_def_documentation(def));
+        tokens.combine(&self.cfg_doc(cfg));
+        tokens
+    }
+
+    fn link_doc(&self, documentation: Option<String>) -> TokenStream {
+        if let Some(url) = documentation {
+            let doc = format!("[Official documentation]({url})");
+            quote! { #[doc = #doc] }
+        } else {
+            quote! {}
+        }
+    }
+
     /// Generates doc comments for member functions (methods) and avoids redundantly declaring the
     /// enclosing module feature required by the method's type.
     pub(crate) fn cfg_method_doc(&self, cfg: &Cfg) -> TokenStream {
@@ -1021,7 +1054,7 @@ impl<'a> Gen<'a> {
     pub fn win32_args(&self, params: &[SignatureParam], kind: SignatureKind) -> TokenStream {
         let mut tokens = quote! {};
 
-        for (position, param) in params.iter().enumerate() {
+        for position in 0..params.len() {
             let new = match kind {
                 SignatureKind::Query(query) if query.object == position => {
                     quote! { &mut result__, }
@@ -1039,60 +1072,72 @@ impl<'a> Gen<'a> {
                 {
                     quote! { &<T as ::windows_core::ComInterface>::IID, }
                 }
-                _ => {
-                    let name = self.param_name(param.def);
-                    let flags = self.reader.param_flags(param.def);
-                    match param.kind {
-                        SignatureParamKind::ArrayFixed(_)
-                        | SignatureParamKind::ArrayRelativeLen(_)
-                        | SignatureParamKind::ArrayRelativeByteLen(_) => {
-                            let map = if flags.contains(ParamAttributes::Optional) {
-                                quote! { #name.as_deref().map_or(::core::ptr::null(), |slice|slice.as_ptr()) }
-                            } else {
-                                quote! { #name.as_ptr() }
-                            };
-                            quote! { ::core::mem::transmute(#map), }
-                        }
-                        SignatureParamKind::ArrayRelativePtr(relative) => {
-                            let name = self.param_name(params[relative].def);
-                            let flags = self.reader.param_flags(params[relative].def);
-                            if flags.contains(ParamAttributes::Optional) {
-                                quote! { #name.as_deref().map_or(0, |slice|slice.len() as _), }
-                            } else {
-                                quote! { #name.len() as _, }
-                            }
-                        }
-                        SignatureParamKind::TryInto => {
-                            quote! { #name.try_into_param()?.abi(), }
-                        }
-                        SignatureParamKind::IntoParam => {
-                            quote! { #name.into_param().abi(), }
-                        }
-                        SignatureParamKind::OptionalPointer => {
-                            if flags.contains(ParamAttributes::Out) {
-                                quote! { ::core::mem::transmute(#name.unwrap_or(::std::ptr::null_mut())), }
-                            } else {
-                                quote! { ::core::mem::transmute(#name.unwrap_or(::std::ptr::null())), }
-                            }
-                        }
-                        SignatureParamKind::ValueType => {
-                            quote! { #name, }
-                        }
-                        SignatureParamKind::Blittable => {
-                            quote! { ::core::mem::transmute(#name), }
-                        }
-                        SignatureParamKind::Other => {
-                            quote! { ::core::mem::transmute_copy(#name), }
-                        }
-                    }
-                }
+                _ => self.win32_arg(params, position),
             };
             tokens.combine(&new)
         }
 
         tokens
     }
+    /// The argument passed to the raw function for a param that isn't part of the return value
+    pub fn win32_arg(&self, params: &[SignatureParam], position: usize) -> TokenStream {
+        let param = &params[position];
+        let name = self.param_name(param.def);
+        let flags = self.reader.param_flags(param.def);
+        match param.kind {
+            SignatureParamKind::ArrayFixed(_)
+            | SignatureParamKind::ArrayRelativeLen(_)
+            | SignatureParamKind::ArrayRelativeByteLen(_) => {
+                let map = if flags.contains(ParamAttributes::Optional) {
+                    quote! { #name.as_deref().map_or(::core::ptr::null(), |slice|slice.as_ptr()) }
+                } else {
+                    quote! { #name.as_ptr() }
+                };
+                quote! { ::core::mem::transmute(#map), }
+            }
+            SignatureParamKind::ArrayRelativePtr(relative) => {
+                let name = self.param_name(params[relative].def);
+                let flags = self.reader.param_flags(params[relative].def);
+                if flags.contains(ParamAttributes::Optional) {
+                    quote! { #name.as_deref().map_or(0, |slice|slice.len() as _), }
+                } else {
+                    quote! { #name.len() as _, }
+                }
+            }
+            SignatureParamKind::TryInto => {
+                quote! { #name.try_into_param()?.abi(), }
+            }
+            SignatureParamKind::IntoParam => {
+                quote! { #name.into_param().abi(), }
+            }
+            SignatureParamKind::OptionalPointer => {
+                if flags.contains(ParamAttributes::Out) {
+                    quote! { ::core::mem::transmute(#name.unwrap_or(::std::ptr::null_mut())), }
+                } else {
+                    quote! { ::core::mem::transmute(#name.unwrap_or(::std::ptr::null())), }
+                }
+            }
+            SignatureParamKind::ValueType => {
+                quote! { #name, }
+            }
+            SignatureParamKind::Blittable => {
+                quote! { ::core::mem::transmute(#name), }
+            }
+            SignatureParamKind::Other => {
+                quote! { ::core::mem::transmute_copy(#name), }
+            }
+        }
+    }
     pub fn win32_params(&self, params: &[SignatureParam], kind: SignatureKind) -> TokenStream {
+        self.win32_params_except(params, kind, &[])
+    }
+    /// The wrapper params, leaving out those at the `skip` positions
+    pub fn win32_params_except(
+        &self,
+        params: &[SignatureParam],
+        kind: SignatureKind,
+        skip: &[usize],
+    ) -> TokenStream {
         let mut tokens = quote! {};
 
         let mut generic_params = self.generic_params(params);
@@ -1111,6 +1156,10 @@ impl<'a> Gen<'a> {
                 _ => {}
             }
 
+            if skip.contains(&position) {
+                continue;
+            }
+
             let name = self.param_name(param.def);
 
             match param.kind {
@@ -1391,4 +1440,68 @@ mod tests {
             "Windows.Win32.Graphics.Direct3D11"
         ));
     }
+
+    #[test]
+    fn test_documentation() {
+        use metadata::model::*;
+        use metadata::writer;
+
+        let url = "https://learn.microsoft.com/windows/win32/api/test/nf-test-getdata";
+        let documentation = || {
+            Attribute::new(
+                "Windows.Win32.Foundation.Metadata",
+                "DocumentationAttribute",
+                vec![writer::Value::String(url.to_string())],
+            )
+        };
+
+        let mut model = Model::default();
+
+        let mut def = metadata::model::TypeDef::new(
+            "Apis",
+            TypeAttributes::Public | TypeAttributes::Abstract | TypeAttributes::Sealed,
+        );
+        let mut method = Method::new(
+            "GetData",
+            MethodAttributes::Public | MethodAttributes::Static | MethodAttributes::PinvokeImpl,
+            writer::Type::I32,
+        );
+        method.import = Some(Import {
+            module: "test.dll".to_string(),
+            name: "GetData".to_string(),
+            flags: PInvokeAttributes::CallConvPlatformapi,
+        });
+        method.attributes.push(documentation());
+        def.methods.push(method);
+        model.namespace_mut("Test").types.push(def);
+
+        let mut def =
+            metadata::model::TypeDef::new("DATA", TypeAttributes::Public | TypeAttributes::Sealed);
+        def.extends = Some(writer::TypeName {
+            namespace: "System".to_string(),
+            name: "ValueType".to_string(),
+            generics: vec![],
+        });
+        def.fields.push(metadata::model::Field::new(
+            "Value",
+            FieldAttributes::Public,
+            writer::Type::I32,
+        ));
+        def.attributes.push(documentation());
+        model.namespace_mut("Test").types.push(def);
+
+        let files = [metadata::File::new(model.write("Test.winmd")).unwrap()];
+        let reader = &Reader::new(&files);
+        let filter = &metadata::Filter::new(&["Test"], &[]);
+        let gen = &Gen::new(reader, filter, "test.rs");
+        let doc = format!("[Official documentation]({url})");
+        let doc = quote! { #[doc = #doc] };
+
+        let apis = reader.get(TypeName::new("Test", "Apis")).next().unwrap();
+        let method = reader.type_def_methods(apis).next().unwrap();
+        assert!(functions::gen(gen, method).as_str().contains(doc.as_str()));
+
+        let def = reader.get(TypeName::new("Test", "DATA")).next().unwrap();
+        assert!(structs::gen(gen, def).as_str().contains(doc.as_str()));
+    }
 }
@@ -68,6 +68,22 @@ pub fn gen_win_handle(gen: &Gen, def: TypeDef) -> TokenStream {
         }
     };
 
+    // `NTSTATUS` values are printed by name with the `ERROR_NAMES` configuration value, using the `name` method generated
+    // along with it.
+    let is_named = gen.error_names && gen.reader.type_def_type_name(def) == TypeName::NTSTATUS;
+
+    let debug = if is_named {
+        error_names::gen_fmt(gen, def, &TokenStream::new())
+    } else {
+        quote! {
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_tuple(#name).field(&self.0).finish()
+                }
+            }
+        }
+    };
+
     let mut tokens = quote! {
         #[repr(transparent)]
         // Unfortunately, Rust requires these to be derived to allow constant patterns.
@@ -85,16 +101,20 @@ pub fn gen_win_handle(gen: &Gen, def: TypeDef) -> TokenStream {
             }
         }
         impl ::core::marker::Copy for #ident {}
-        impl ::core::fmt::Debug for #ident {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_tuple(#name).field(&self.0).finish()
-            }
-        }
+        #debug
         impl ::windows_core::TypeKind for #ident {
             type TypeKind = ::windows_core::CopyType;
         }
     };
 
+    if let Some((namespace, free, cfg)) = owned_handle(gen, def) {
+        tokens.combine(&gen_owned_handle(gen, def, namespace, free, &cfg));
+    }
+
+    if is_named {
+        tokens.combine(&error_names::gen_name(gen, def, &TokenStream::new()));
+    }
+
     if let Some(dependency) = gen.reader.type_def_usable_for(def) {
         let type_name = gen.reader.type_def_type_name(dependency);
         let mut dependency = gen.namespace(type_name.namespace);
@@ -107,3 +127,111 @@ pub fn gen_win_handle(gen: &Gen, def: TypeDef) -> TokenStream {
 
     tokens
 }
+
+/// Returns the function used to free handles of the given type, along with the `cfg` needed to call it, if the handle
+/// type gets an owned wrapper. The free function must take the handle directly and its namespace must be generated.
+pub fn owned_handle<'a>(gen: &Gen<'a>, def: TypeDef) -> Option<(&'a str, MethodDef, Cfg<'a>)> {
+    if gen.sys {
+        return None;
+    }
+
+    let (namespace, free) = gen.reader.type_def_free_function(def)?;
+
+    if !gen.filter.includes_namespace(namespace) {
+        return None;
+    }
+
+    let signature = gen.reader.method_def_signature(free, &[]);
+
+    match signature.params.as_slice() {
+        [param] if param.ty == Type::TypeDef(def, Vec::new()) => {}
+        _ => return None,
+    }
+
+    let mut cfg = gen.reader.signature_cfg(&signature);
+    cfg.add_feature(namespace);
+    Some((namespace, free, cfg))
+}
+
+/// Returns the name of the owned wrapper for the given handle type.
+pub fn owned_handle_name(gen: &Gen, def: TypeDef) -> TokenStream {
+    let type_name = gen.reader.type_def_type_name(def);
+    let mut name = gen.namespace(type_name.namespace);
+    name.push_str(&format!("Owned{}", type_name.name));
+    name
+}
+
+fn gen_owned_handle(
+    gen: &Gen,
+    def: TypeDef,
+    namespace: &str,
+    free: MethodDef,
+    cfg: &Cfg,
+) -> TokenStream {
+    let name = gen.reader.type_def_name(def);
+    let ident = to_ident(name);
+    let owned_name = format!("Owned{name}");
+    let owned_ident = to_ident(&owned_name);
+    let features = gen.cfg_features(cfg);
+
+    let free_name = gen.reader.method_def_name(free);
+    let mut free_path = gen.namespace(namespace);
+    free_path.push_str(free_name);
+
+    let free_doc = format!(
+        "Takes ownership of the handle, which is freed with `{free_name}` when the wrapper is dropped."
+    );
+
+    let underlying_type = gen.reader.type_def_underlying_type(def);
+
+    // Handles without known invalid values are always freed.
+    let free_call =
+        if underlying_type.is_pointer() || !gen.reader.type_def_invalid_values(def).is_empty() {
+            quote! {
+                if !self.0.is_invalid() {
+                    unsafe {
+                        let _ = #free_path(self.0);
+                    }
+                }
+            }
+        } else {
+            quote! {
+                unsafe {
+                    let _ = #free_path(self.0);
+                }
+            }
+        };
+
+    quote! {
+        #features
+        #[repr(transparent)]
+        pub struct #owned_ident(#ident);
+        #features
+        impl #owned_ident {
+            #[doc = #free_doc]
+            pub unsafe fn from_raw(handle: #ident) -> Self {
+                Self(handle)
+            }
+            pub fn as_raw(&self) -> #ident {
+                self.0
+            }
+            pub fn into_raw(self) -> #ident {
+                let handle = self.0;
+                ::core::mem::forget(self);
+                handle
+            }
+        }
+        #features
+        impl ::core::ops::Drop for #owned_ident {
+            fn drop(&mut self) {
+                #free_call
+            }
+        }
+        #features
+        impl ::core::fmt::Debug for #owned_ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(#owned_name).field(&self.0).finish()
+            }
+        }
+    }
+}
@@ -28,7 +28,7 @@ fn gen_win_interface(gen: &Gen, def: TypeDef) -> TokenStream {
     let phantoms = gen.generic_phantoms(generics);
     let constraints = gen.generic_constraints(generics);
     let cfg = gen.reader.type_def_cfg(def, &[]);
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
     let interfaces = gen
         .reader
@@ -1,8 +1,10 @@
+mod buffers;
 mod classes;
 mod com_methods;
 mod constants;
 mod delegates;
 mod enums;
+mod error_names;
 mod extensions;
 mod functions;
 mod gen;
@@ -31,6 +33,11 @@ pub fn from_reader(
     gen.sys = gen.std || config.remove("SYS").is_some();
     gen.implement = config.remove("IMPLEMENT").is_some();
     gen.minimal = config.remove("MINIMAL").is_some();
+    gen.owned = config.remove("OWNED").is_some();
+    gen.sys_typed_enums = config.remove("SYS_TYPED_ENUMS").is_some();
+    gen.event_revokers = config.remove("EVENT_REVOKERS").is_some();
+    gen.buffers = config.remove("BUFFERS").is_some();
+    gen.error_names = config.remove("ERROR_NAMES").is_some();
 
     // TODO: get rid of this hack so it can work with any metadata
     if gen.flatten {
@@ -49,6 +56,36 @@ pub fn from_reader(
         ));
     }
 
+    if gen.owned && gen.sys {
+        return Err(Error::new(
+            "cannot combine OWNED and SYS configuration values",
+        ));
+    }
+
+    if gen.sys_typed_enums && !gen.sys {
+        return Err(Error::new(
+            "the SYS_TYPED_ENUMS configuration value requires SYS",
+        ));
+    }
+
+    if gen.event_revokers && gen.sys {
+        return Err(Error::new(
+            "cannot combine EVENT_REVOKERS and SYS configuration values",
+        ));
+    }
+
+    if gen.buffers && gen.sys {
+        return Err(Error::new(
+            "cannot combine BUFFERS and SYS configuration values",
+        ));
+    }
+
+    if gen.error_names && gen.sys {
+        return Err(Error::new(
+            "cannot combine ERROR_NAMES and SYS configuration values",
+        ));
+    }
+
     if let Some((key, _)) = config.first_key_value() {
         return Err(Error::new(&format!("invalid configuration value: `{key}`")));
     }
@@ -95,6 +95,9 @@ pub fn standalone_imp<'a, I: Iterator<Item = &'a str>>(gen: &Gen, names: I) -> S
             Type::HRESULT if gen.sys => {
                 sorted.insert("HRESULT", quote! { pub type HRESULT = i32; })
             }
+            Type::HRESULT if gen.error_names => {
+                sorted.insert("hresult_name", error_names::gen_hresult_name(gen))
+            }
             Type::String if gen.sys => sorted.insert(
                 "HSTRING",
                 quote! { pub type HSTRING = *mut ::core::ffi::c_void; },
@@ -81,7 +81,7 @@ fn gen_struct_with_name(gen: &Gen, def: TypeDef, struct_name: &str, cfg: &Cfg) -
         quote! { struct }
     };
 
-    let doc = gen.cfg_doc(&cfg);
+    let doc = gen.type_def_doc(def, &cfg);
     let features = gen.cfg_features(&cfg);
 
     let mut tokens = quote! {
@@ -71,7 +71,7 @@ pub fn gen(
         }
     };
 
-    match kind {
+    let mut tokens = match kind {
         InterfaceKind::Default => quote! {
             #doc
             #features
@@ -103,9 +103,116 @@ pub fn gen(
                 }
             }
         }
+    };
+
+    if gen.event_revokers {
+        if let Some(remove) = event_remover(gen, def, method) {
+            // Overloaded adders and removers are numbered alike so the remover takes the adder's suffix.
+            let suffix = &name.as_str()[gen.reader.method_def_special_name(method).len()..];
+            let remove_name: TokenStream =
+                format!("{}{suffix}", gen.reader.method_def_special_name(remove)).into();
+
+            tokens.combine(&gen_event_revoker(
+                gen,
+                &signature,
+                kind,
+                &name,
+                &remove_name,
+                &doc,
+                &features,
+            ));
+        }
+    }
+
+    tokens
+}
+
+// Returns the method removing handlers from the event whose handlers are added by the given method, if any.
+fn event_remover(gen: &Gen, def: TypeDef, method: MethodDef) -> Option<MethodDef> {
+    let event = gen
+        .reader
+        .type_def_events(def)
+        .find(|event| gen.reader.event_add(*event) == Some(method))?;
+
+    gen.reader.event_remove(event)
+}
+
+// Adds an `on_` method for the event that accepts a closure, creates the delegate, and returns a revoker that removes
+// the handler when dropped.
+fn gen_event_revoker(
+    gen: &Gen,
+    signature: &Signature,
+    kind: InterfaceKind,
+    name: &TokenStream,
+    remove_name: &TokenStream,
+    doc: &TokenStream,
+    features: &TokenStream,
+) -> TokenStream {
+    let [param] = signature.params.as_slice() else {
+        return quote! {};
+    };
+    let Type::TypeDef(delegate, generics) = &param.ty else {
+        return quote! {};
+    };
+
+    let delegate_type = gen.type_name(&param.ty);
+    let invoke = gen.reader.type_def_invoke_method(*delegate);
+    let invoke = gen.reader.method_def_signature(invoke, generics);
+    let fn_constraint = delegates::gen_fn_constraint(gen, *delegate, &invoke);
+
+    let on_name: TokenStream = format!("on_{}", to_snake(name.as_str())).into();
+
+    if kind == InterfaceKind::Static {
+        quote! {
+            #doc
+            #features
+            pub fn #on_name<#fn_constraint>(handler: F) -> ::windows_core::Result<::windows_core::EventRevoker> {
+                let token = Self::#name(&<#delegate_type>::new(handler))?;
+                Ok(::windows_core::EventRevoker::new(move || {
+                    let _ = Self::#remove_name(token);
+                }))
+            }
+        }
+    } else {
+        quote! {
+            #doc
+            #features
+            pub fn #on_name<#fn_constraint>(&self, handler: F) -> ::windows_core::Result<::windows_core::EventRevoker> {
+                let token = self.#name(&<#delegate_type>::new(handler))?;
+                let this = self.clone();
+                Ok(::windows_core::EventRevoker::new(move || {
+                    let _ = this.#remove_name(token);
+                }))
+            }
+        }
     }
 }
 
+fn to_snake(name: &str) -> String {
+    let mut snake = String::new();
+    let chars: Vec<char> = name.chars().collect();
+
+    for (pos, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && pos > 0 {
+            let previous = chars[pos - 1];
+            let next_is_lower = chars
+                .get(pos + 1)
+                .is_some_and(|next| next.is_ascii_lowercase());
+
+            // Split before the last capital of an acronym, as in `DPIChanged` to `dpi_changed`.
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+
+    snake
+}
+
 fn gen_winrt_params(gen: &Gen, params: &[SignatureParam]) -> TokenStream {
     let mut result = quote! {};
 
@@ -1,4 +1,4 @@
 mod to_winmd;
+pub use metadata::writer;
 pub use to_winmd::from_reader;
-pub mod writer;
 pub use writer::*;
@@ -1,79 +1,307 @@
-use crate::winmd::{self, writer};
+use metadata::model::{Model, Namespace};
+use std::collections::{BTreeMap, HashMap};
 
 pub fn from_reader(
     reader: &metadata::Reader,
     filter: &metadata::Filter,
-    _config: std::collections::BTreeMap<&str, &str>,
+    config: std::collections::BTreeMap<&str, &str>,
     output: &str,
 ) -> crate::Result<()> {
-    let mut writer = winmd::Writer::new(output);
+    let model = Model::from_reader(reader, filter);
 
-    for def in reader.types(filter) {
-        let extends = if let Some(extends) = reader.type_def_extends(def) {
-            writer.insert_type_ref(extends.namespace, extends.name)
+    // When merging, the same type may be defined by more than one input winmd file.
+    let mut names = HashMap::<(&str, &str), usize>::new();
+
+    for namespace in &model.namespaces {
+        for def in &namespace.types {
+            *names.entry((&namespace.name, &def.name)).or_default() += 1;
+        }
+    }
+
+    let mut errors: Vec<crate::Error> = names
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|((namespace, name), _)| {
+            crate::Error::new(&format!(
+                "the type `{namespace}.{name}` is defined by more than one input"
+            ))
+        })
+        .collect();
+
+    if !errors.is_empty() {
+        errors.sort_by_key(|error| error.to_string());
+        return crate::Error::check(errors);
+    }
+
+    // Namespaces are grouped by the name of the winmd file they're written to. By default, all types are written to the
+    // output file. Much like mdmerge, `SPLIT=<depth>` instead writes one winmd file per namespace prefix of the given
+    // depth into the output directory.
+    let mut files = BTreeMap::<String, Vec<Namespace>>::new();
+
+    if let Some(depth) = config.get("SPLIT") {
+        let depth = match depth.parse::<usize>() {
+            Ok(depth) if depth > 0 => depth,
+            _ => return Err(crate::Error::new("`SPLIT` must be a positive integer")),
+        };
+
+        for namespace in model.namespaces {
+            let prefix: Vec<&str> = namespace.name.split('.').take(depth).collect();
+            files.entry(prefix.join(".")).or_default().push(namespace);
+        }
+    } else {
+        files.insert(String::new(), model.namespaces);
+    }
+
+    // References to types in another split file are resolved by that file's assembly rather than by namespace.
+    let assemblies: BTreeMap<String, String> = files
+        .iter()
+        .flat_map(|(assembly, namespaces)| {
+            namespaces
+                .iter()
+                .map(move |namespace| (namespace.name.clone(), assembly.clone()))
+        })
+        .collect();
+
+    for (assembly, namespaces) in files {
+        let path = if assembly.is_empty() {
+            output.to_string()
         } else {
-            0
+            format!("{output}/{assembly}.winmd")
+        };
+
+        let references = assemblies
+            .iter()
+            .filter(|(_, other)| **other != assembly)
+            .map(|(namespace, other)| (namespace.clone(), other.clone()))
+            .collect();
+
+        let model = Model {
+            namespaces,
+            references,
         };
 
-        writer.tables.TypeDef.push(writer::TypeDef {
-            Extends: extends,
-            FieldList: writer.tables.Field.len() as _,
-            Flags: reader.type_def_flags(def).0,
-            MethodList: writer.tables.MethodDef.len() as _,
-            TypeName: writer.strings.insert(reader.type_def_name(def)),
-            TypeNamespace: writer.strings.insert(reader.type_def_namespace(def)),
+        crate::write_to_file(&path, model.write(&path)).map_err(|err| err.with_path(&path))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    // Builds `Test.IVector`1` with a generic method signature and an attribute since the default metadata has no
+    // generic types.
+    fn generic_winmd() -> Vec<u8> {
+        let mut def = metadata::model::TypeDef::new(
+            "IVector`1",
+            metadata::TypeAttributes::Public
+                | metadata::TypeAttributes::Interface
+                | metadata::TypeAttributes::Abstract
+                | metadata::TypeAttributes::WindowsRuntime,
+        );
+
+        def.generics.push("T".to_string());
+
+        def.methods.push(metadata::model::Method {
+            name: "GetAt".to_string(),
+            flags: metadata::MethodAttributes::Public
+                | metadata::MethodAttributes::Abstract
+                | metadata::MethodAttributes::Virtual,
+            impl_flags: metadata::MethodImplAttributes::default(),
+            call_flags: metadata::MethodCallAttributes::HASTHIS,
+            return_type: metadata::writer::Type::GenericParam(0),
+            return_param: None,
+            params: vec![metadata::model::Param {
+                name: "index".to_string(),
+                flags: metadata::ParamAttributes::In,
+                ty: metadata::writer::Type::U32,
+                attributes: vec![],
+            }],
+            import: None,
+            attributes: vec![],
         });
 
+        def.attributes.push(metadata::model::Attribute {
+            namespace: "Windows.Foundation.Metadata".to_string(),
+            name: "GuidAttribute".to_string(),
+            args: vec![
+                metadata::writer::Value::U32(1),
+                metadata::writer::Value::U16(2),
+            ],
+            named_args: vec![],
+        });
+
+        let mut model = Model::default();
+        model.namespace_mut("Test").types.push(def);
+        model.write("Test.winmd")
+    }
+
+    fn describe_type(reader: &metadata::Reader, ty: &metadata::Type) -> String {
+        match ty {
+            metadata::Type::TypeDef(def, generics) => {
+                let mut name = format!(
+                    "{}.{}",
+                    reader.type_def_namespace(*def),
+                    reader.type_def_name(*def)
+                );
+                if !generics.is_empty() {
+                    let generics: Vec<String> = generics
+                        .iter()
+                        .map(|ty| describe_type(reader, ty))
+                        .collect();
+                    write!(name, "<{}>", generics.join(", ")).unwrap();
+                }
+                name
+            }
+            metadata::Type::TypeRef(code) => {
+                let name = reader.type_def_or_ref(*code);
+                format!("{}.{}", name.namespace, name.name)
+            }
+            metadata::Type::GenericParam(param) => reader.generic_param_name(*param).to_string(),
+            metadata::Type::MutPtr(ty, pointers) => {
+                format!("*mut{pointers} {}", describe_type(reader, ty))
+            }
+            metadata::Type::ConstPtr(ty, pointers) => {
+                format!("*const{pointers} {}", describe_type(reader, ty))
+            }
+            metadata::Type::Win32Array(ty, len) => {
+                format!("[{}; {len}]", describe_type(reader, ty))
+            }
+            metadata::Type::WinrtArray(ty) => format!("[{}]", describe_type(reader, ty)),
+            metadata::Type::WinrtArrayRef(ty) => format!("&[{}]", describe_type(reader, ty)),
+            metadata::Type::ConstRef(ty) => format!("&{}", describe_type(reader, ty)),
+            rest => format!("{rest:?}"),
+        }
+    }
+
+    fn describe_attributes(
+        reader: &metadata::Reader,
+        attributes: impl Iterator<Item = metadata::Attribute>,
+        text: &mut String,
+    ) {
+        for attribute in attributes {
+            let args: Vec<String> = reader
+                .attribute_args(attribute)
+                .iter()
+                .map(|(name, value)| match value {
+                    metadata::Value::TypeDef(def) => {
+                        format!("{name}={}", reader.type_def_name(*def))
+                    }
+                    metadata::Value::TypeRef(code) => {
+                        format!("{name}={}", reader.type_def_or_ref(*code).name)
+                    }
+                    metadata::Value::EnumDef(_, value) | metadata::Value::EnumRef(_, value) => {
+                        format!("{name}={value:?}")
+                    }
+                    value => format!("{name}={value:?}"),
+                })
+                .collect();
+            writeln!(
+                text,
+                "  [{}({})]",
+                reader.attribute_name(attribute),
+                args.join(", ")
+            )
+            .unwrap();
+        }
+    }
+
+    fn describe(reader: &metadata::Reader, def: metadata::TypeDef, text: &mut String) {
+        let generics: Vec<metadata::Type> = reader.type_def_generics(def).collect();
+        writeln!(
+            text,
+            "{}.{} {:?} {:?}",
+            reader.type_def_namespace(def),
+            reader.type_def_name(def),
+            reader.type_def_flags(def),
+            generics
+                .iter()
+                .map(|ty| describe_type(reader, ty))
+                .collect::<Vec<_>>()
+        )
+        .unwrap();
+        describe_attributes(reader, reader.type_def_attributes(def), text);
+
         for field in reader.type_def_fields(def) {
-            let ty = writer_type(reader, &reader.field_type(field, Some(def)));
-            let signature = writer.insert_field_sig(&ty);
-
-            writer.tables.Field.push(writer::Field {
-                Flags: reader.field_flags(field).0,
-                Name: writer.strings.insert(reader.field_name(field)),
-                Signature: signature,
-            });
+            writeln!(
+                text,
+                " field {} {:?} {}",
+                reader.field_name(field),
+                reader.field_flags(field),
+                describe_type(reader, &reader.field_type(field, Some(def)))
+            )
+            .unwrap();
+            describe_attributes(reader, reader.field_attributes(field), text);
+        }
+
+        for method in reader.type_def_methods(def) {
+            let signature = reader.method_def_signature(method, &generics);
+            writeln!(
+                text,
+                " method {} {:?} -> {}",
+                reader.method_def_name(method),
+                reader.method_def_flags(method),
+                describe_type(reader, &signature.return_type)
+            )
+            .unwrap();
+            describe_attributes(reader, reader.method_def_attributes(method), text);
+
+            for param in &signature.params {
+                writeln!(
+                    text,
+                    "  param {} {:?} {}",
+                    reader.param_name(param.def),
+                    reader.param_flags(param.def),
+                    describe_type(reader, &param.ty)
+                )
+                .unwrap();
+                describe_attributes(reader, reader.param_attributes(param.def), text);
+            }
+        }
+
+        for nested in reader.nested_types(def) {
+            text.push_str("nested ");
+            describe(reader, nested, text);
         }
     }
 
-    // TODO: In theory, `config` could instruct this function to balance the types across a number of winmd files
-    // like mdmerge supports for namespace-splitting.
-    crate::write_to_file(output, writer.into_stream()).map_err(|err| err.with_path(output))
-}
+    fn describe_all(reader: &metadata::Reader, filter: &metadata::Filter) -> String {
+        let mut text = String::new();
+
+        for def in reader.types(filter) {
+            describe(reader, def, &mut text);
+        }
+
+        text
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let files = [
+            metadata::File::new(
+                std::fs::read("../../libs/metadata/default/Windows.Wdk.winmd").unwrap(),
+            )
+            .unwrap(),
+            metadata::File::new(generic_winmd()).unwrap(),
+        ];
+        let reader = &metadata::Reader::new(&files);
+        let filter = &metadata::Filter::new(&["Test", "Windows.Wdk.Storage.FileSystem"], &[]);
+        let before = describe_all(reader, filter);
+
+        let output = std::env::temp_dir().join("riddle_test_round_trip.winmd");
+        let output = output.to_str().unwrap();
+        from_reader(reader, filter, Default::default(), output).unwrap();
+
+        let files = [metadata::File::new(std::fs::read(output).unwrap()).unwrap()];
+        let reader = &metadata::Reader::new(&files);
+        let after = describe_all(reader, filter);
 
-fn writer_type(reader: &metadata::Reader, ty: &metadata::Type) -> winmd::Type {
-    match ty {
-        metadata::Type::Void => winmd::Type::Void,
-        metadata::Type::Bool => winmd::Type::Bool,
-        metadata::Type::Char => winmd::Type::Char,
-        metadata::Type::I8 => winmd::Type::I8,
-        metadata::Type::U8 => winmd::Type::U8,
-        metadata::Type::I16 => winmd::Type::I16,
-        metadata::Type::U16 => winmd::Type::U16,
-        metadata::Type::I32 => winmd::Type::I32,
-        metadata::Type::U32 => winmd::Type::U32,
-        metadata::Type::I64 => winmd::Type::I64,
-        metadata::Type::U64 => winmd::Type::U64,
-        metadata::Type::F32 => winmd::Type::F32,
-        metadata::Type::F64 => winmd::Type::F64,
-        metadata::Type::ISize => winmd::Type::ISize,
-        metadata::Type::USize => winmd::Type::USize,
-        metadata::Type::String => winmd::Type::String,
-        metadata::Type::GUID => winmd::Type::GUID,
-        metadata::Type::IUnknown => winmd::Type::IUnknown,
-        metadata::Type::IInspectable => winmd::Type::IInspectable,
-        metadata::Type::HRESULT => winmd::Type::HRESULT,
-        metadata::Type::PSTR => winmd::Type::PSTR,
-        metadata::Type::PWSTR => winmd::Type::PWSTR,
-        metadata::Type::PCSTR => winmd::Type::PCSTR,
-        metadata::Type::PCWSTR => winmd::Type::PCWSTR,
-        metadata::Type::BSTR => winmd::Type::BSTR,
-        metadata::Type::TypeName => winmd::Type::TypeName,
-        metadata::Type::TypeDef(def, generics) => winmd::Type::TypeRef(winmd::TypeName {
-            namespace: reader.type_def_namespace(*def).to_string(),
-            name: reader.type_def_name(*def).to_string(),
-            generics: generics.iter().map(|ty| writer_type(reader, ty)).collect(),
-        }),
-        rest => unimplemented!("{rest:?}"),
+        assert!(before.contains("Test.IVector`1"));
+        assert!(before.contains(" method GetAt "));
+        assert!(before.contains("nested "));
+        assert!(before.contains("[NativeArrayInfoAttribute("));
+        assert_eq!(before, after);
     }
 }